@@ -29,5 +29,10 @@ if [[ ! -x "$COMMAND" ]]; then
   rm $DOWNLOAD_FILE
 fi
 
-echo "Run '$COMMAND_PATH $INPUT_ARGS'"
-$COMMAND_PATH $INPUT_ARGS
+if [[ -n "$INPUT_SARIF_FILE" ]]; then
+  echo "Run '$COMMAND_PATH $INPUT_ARGS --output-format sarif > $INPUT_SARIF_FILE'"
+  $COMMAND_PATH $INPUT_ARGS --output-format sarif > "$INPUT_SARIF_FILE"
+else
+  echo "Run '$COMMAND_PATH $INPUT_ARGS'"
+  $COMMAND_PATH $INPUT_ARGS
+fi
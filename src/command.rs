@@ -6,17 +6,25 @@ use globset::Glob;
 use crate::output::Format;
 
 pub mod check;
+pub mod clean;
 pub mod generate_shell_completion;
+pub mod init;
+pub mod rules;
+pub mod server;
 
 #[derive(Subcommand)]
 #[allow(clippy::exhaustive_enums)]
 pub enum Command {
     /// Check markdown on the given files or directories
     Check {
-        /// List of files or directories to check
+        /// List of files or directories to check. Use "-" to read from stdin
         #[arg(default_value = ".", value_hint = ValueHint::AnyPath)]
         files: Vec<PathBuf>,
 
+        /// Path of stdin input used for outputs, `lint.exclude` and per-path configuration
+        #[arg(long, value_name = "PATH", value_hint = ValueHint::FilePath)]
+        stdin_filename: Option<PathBuf>,
+
         /// Output format for violations. The default format is "concise"
         #[arg(value_enum, long = "output-format")]
         output_format: Option<Format>,
@@ -25,10 +33,72 @@ pub enum Command {
         #[arg(long, default_value_t = false)]
         quiet: bool,
 
+        /// Apply fixes to resolve violations where possible
+        #[arg(long, default_value_t = false)]
+        fix: bool,
+
         /// List of file patterns to exclude from linting
         #[arg(long, value_delimiter = ',')]
         exclude: Option<Vec<Glob>>,
+
+        /// Fail if the number of warnings exceeds the given number
+        #[arg(long, value_name = "N")]
+        max_warnings: Option<usize>,
+
+        /// Re-run on file changes until interrupted
+        #[arg(long, default_value_t = false)]
+        watch: bool,
+
+        /// Disable reading and writing the cache
+        #[arg(long, default_value_t = false)]
+        no_cache: bool,
+
+        /// Only check files and lines changed since the given git ref
+        #[arg(long, value_name = "REF")]
+        diff_base: Option<String>,
+
+        /// Only report violations not in the given baseline file
+        #[arg(long, value_name = "PATH", value_hint = ValueHint::FilePath)]
+        baseline: Option<PathBuf>,
+
+        /// Write current violations to the given baseline file
+        #[arg(
+            long,
+            value_name = "PATH",
+            value_hint = ValueHint::FilePath,
+            conflicts_with = "baseline"
+        )]
+        write_baseline: Option<PathBuf>,
+    },
+    /// Create a config file
+    Init {
+        /// List of files or directories to infer styles from
+        #[arg(default_value = ".", value_hint = ValueHint::AnyPath)]
+        files: Vec<PathBuf>,
+
+        /// Infer styles from existing Markdown files
+        #[arg(long, default_value_t = false)]
+        infer: bool,
+    },
+    /// Remove the cache directory
+    Clean,
+    /// List all rules with their configuration
+    Rules {
+        /// Output format. Only "json" and "json-lines" differ from the default text
+        #[arg(value_enum, long = "output-format")]
+        output_format: Option<Format>,
+    },
+    /// Explain a rule with examples
+    Rule {
+        /// Name or alias of the rule such as MD013 or line-length
+        name: String,
+
+        /// Output format. Only "json" and "json-lines" differ from the default text
+        #[arg(value_enum, long = "output-format")]
+        output_format: Option<Format>,
     },
+    /// Start a language server over stdio
+    Server,
     /// Generate shell completion
     GenerateShellCompletion {
         /// Shell to generate a completion script
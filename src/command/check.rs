@@ -1,125 +1,477 @@
+extern crate alloc;
+
+use alloc::sync::Arc;
 use std::io::Read as _;
-use std::io::{self, BufWriter, IsTerminal as _, Write as _};
+use std::io::{self, BufWriter, Write as _};
 use std::path::PathBuf;
 use std::process::ExitCode;
 
 use globset::Glob;
+use miette::miette;
 use miette::IntoDiagnostic as _;
 use miette::Result;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::output::{
+    Concise, Format, Full, Github, Json, JsonLine, JsonReport, Markdownlint, Mdl, Sarif, Summary,
+};
+use crate::service::cache::Cache;
+use crate::service::diff::Diff;
+use crate::service::filter::FileFilter;
+use crate::service::fixer::Fixer;
+use crate::service::runner::{
+    LintRunner, ParallelLintRunner, Report, RunOptions, StringLintRunner,
+};
+use crate::{Config, Severity, Source, Violation};
 
-use crate::output::{Concise, Format, Markdownlint, Mdl};
-use crate::service::runner::{LintRunner, ParallelLintRunner, StringLintRunner};
-use crate::Config;
+mod baseline;
+mod watcher;
+
+pub use baseline::Baseline;
+pub use watcher::Watcher;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[allow(clippy::exhaustive_structs)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Options {
     pub config_path: Option<PathBuf>,
     pub output_format: Option<Format>,
     pub quiet: bool,
+    pub fix: bool,
     pub exclude: Option<Vec<Glob>>,
+    pub max_warnings: Option<usize>,
+    pub no_cache: bool,
+    pub diff_base: Option<String>,
+    pub baseline: Option<PathBuf>,
+    pub write_baseline: Option<PathBuf>,
+    pub stdin_filename: Option<PathBuf>,
+    /// Whether running on GitHub Actions
+    pub github_actions: bool,
 }
 
 impl Options {
+    /// Returns the config with the options applied and warnings of the config file
     #[inline]
-    pub fn to_config(self) -> Result<Config> {
-        let mut config = match self.config_path {
-            Some(config_path) => Config::load(&config_path)?,
+    pub fn to_config(&self) -> Result<(Config, Vec<String>)> {
+        let (mut config, warnings) = match &self.config_path {
+            Some(config_path) => Config::load(config_path)?,
             None => Config::resolve()?,
         };
 
-        if let Some(format) = self.output_format {
-            config.lint.output_format = format;
+        if let Some(format) = &self.output_format {
+            config.lint.output_format = Some(format.clone());
+        } else if self.github_actions && config.lint.output_format.is_none() {
+            // Annotate violations on GitHub unless a format is configured
+            config.lint.output_format = Some(Format::Github);
         }
 
         // Respect config
         config.lint.quiet |= self.quiet;
+        config.lint.fix |= self.fix;
 
-        if let Some(exclude) = self.exclude {
-            config.lint.exclude = exclude;
+        if let Some(exclude) = &self.exclude {
+            config.lint.exclude.clone_from(exclude);
         }
 
-        Ok(config)
+        if let Some(max_warnings) = self.max_warnings {
+            config.lint.max_warnings = Some(max_warnings);
+        }
+
+        Ok((config, warnings))
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn to_run_options(&self, config: &Config) -> RunOptions {
+        RunOptions {
+            // Config files are discovered for each file unless a config file is specified
+            config_path: self.config_path.clone(),
+            cache_dir: if self.no_cache { None } else { Cache::root() },
+            diff_base: self.diff_base.clone(),
+            baseline: self.baseline.clone(),
+            write_baseline: self.write_baseline.clone(),
+            stdin_filename: self.stdin_filename.clone(),
+            // Sources are used to render snippets in the full format and to match baselines
+            keep_sources: *config.lint.output_format() == Format::Full
+                || self.baseline.is_some()
+                || self.write_baseline.is_some(),
+        }
     }
 }
 
 pub struct Checker {
-    runner: LintRunner,
+    /// None if no files are changed since `--diff-base`
+    runner: Option<LintRunner>,
     config: Config,
+    options: RunOptions,
+    /// Changes since `--diff-base` to filter violations
+    diff: Option<Diff>,
+    /// Warnings of config files to print with warnings of config files found while linting
+    warnings: Vec<String>,
 }
 
-fn stdin_input() -> Option<String> {
-    let stdin = io::stdin();
-    if stdin.is_terminal() {
-        return None;
+// Reads stdin if "-" is given as the file
+fn stdin_input(patterns: &[PathBuf], options: &RunOptions) -> Result<Option<String>> {
+    let is_stdin = patterns
+        .iter()
+        .any(|pattern| pattern.as_os_str() == Checker::STDIN);
+    if !is_stdin {
+        if options.stdin_filename.is_some() {
+            return Err(miette!("--stdin-filename requires \"-\" as the file"));
+        }
+        return Ok(None);
+    }
+    if patterns.len() > 1 {
+        return Err(miette!("\"-\" cannot be used with other files"));
     }
 
     let mut buffer = String::new();
-    io::stdin().lock().read_to_string(&mut buffer).ok()?;
-
-    if buffer.is_empty() {
-        None
-    } else {
-        Some(buffer)
-    }
+    io::stdin()
+        .lock()
+        .read_to_string(&mut buffer)
+        .into_diagnostic()?;
+    Ok(Some(buffer))
 }
 
 impl Checker {
+    /// File name to read from stdin
+    pub const STDIN: &str = "-";
+    const MAX_FIX_ITERATIONS: usize = 10;
+
     #[inline]
-    pub fn new(patterns: &[PathBuf], config: Config) -> Result<Self> {
-        let runner = match stdin_input() {
-            Some(input) => {
-                LintRunner::String(Box::new(StringLintRunner::new(input, config.clone())))
+    pub fn new(patterns: &[PathBuf], config: Config, options: RunOptions) -> Result<Self> {
+        let input = stdin_input(patterns, &options)?;
+        let diff = options
+            .diff_base
+            .as_deref()
+            .map(Diff::from_git)
+            .transpose()?;
+        let runner = match (input, &diff) {
+            (Some(_), _) if config.lint.fix => {
+                return Err(miette!("--fix cannot be used with stdin"));
+            }
+            (Some(_), Some(_)) => {
+                return Err(miette!("--diff-base cannot be used with stdin"));
+            }
+            (Some(input), None) => Some(LintRunner::String(Box::new(StringLintRunner::new(
+                input,
+                config.clone(),
+                options.clone(),
+            )))),
+            (None, Some(diff)) => {
+                // Changed files under directories are selected as walked files are,
+                // while files given explicitly are linted regardless of extensions
+                let filter = FileFilter::new(&config.lint)?;
+                let paths: Vec<_> = diff
+                    .paths(patterns)
+                    .into_iter()
+                    .filter(|path| {
+                        if patterns.contains(path) {
+                            !filter.is_excluded(path)
+                        } else {
+                            filter.is_match(path)
+                        }
+                    })
+                    .collect();
+                if paths.is_empty() {
+                    None
+                } else {
+                    Some(LintRunner::Parallel(Box::new(ParallelLintRunner::new(
+                        &paths,
+                        config.clone(),
+                        options.clone(),
+                        100,
+                    )?)))
+                }
             }
-            None => LintRunner::Parallel(Box::new(ParallelLintRunner::new(
+            (None, None) => Some(LintRunner::Parallel(Box::new(ParallelLintRunner::new(
                 patterns,
                 config.clone(),
+                options.clone(),
                 100,
-            )?)),
+            )?))),
         };
 
-        Ok(Self { runner, config })
+        Ok(Self {
+            runner,
+            config,
+            options,
+            diff,
+            warnings: vec![],
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_warnings(mut self, warnings: Vec<String>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+
+    // Fixes violations in place and re-lints fixed files until no more fixes can be applied.
+    // Violations of re-linted files are filtered by the diff computed before fixing.
+    fn fix(
+        config: &Config,
+        options: &RunOptions,
+        diff: Option<&Diff>,
+        mut report: Report,
+    ) -> Result<(Report, usize)> {
+        let mut num_fixed = 0;
+        for _ in 0..Self::MAX_FIX_ITERATIONS {
+            let (paths, num_fixed_in_iteration) = Fixer::fix_files(&report.violations)?;
+            if num_fixed_in_iteration == 0 {
+                break;
+            }
+
+            num_fixed += num_fixed_in_iteration;
+            let fixed_paths: FxHashSet<_> = paths.iter().collect();
+            report
+                .violations
+                .retain(|violation| !fixed_paths.contains(violation.path()));
+            report.sources.retain(|path, _| !fixed_paths.contains(path));
+            let runner = ParallelLintRunner::new(&paths, config.clone(), options.clone(), 100)?;
+            report.extend(Self::filter(diff, runner.run()?));
+        }
+
+        Ok((report, num_fixed))
     }
 
     #[inline]
     pub fn check(self) -> Result<ExitCode> {
-        let mut violations = self.runner.run()?;
-        violations.sort_by(self.config.lint.output_format.sorter());
+        let report = match self.runner {
+            Some(runner) => Self::filter(self.diff.as_ref(), runner.run()?),
+            None => Report::default(),
+        };
+        let (report, num_fixed) = if self.config.lint.fix {
+            Self::fix(&self.config, &self.options, self.diff.as_ref(), report)?
+        } else {
+            (report, 0)
+        };
+        let Report {
+            mut violations,
+            sources,
+            paths,
+            warnings,
+        } = report;
+        violations.sort_by(self.config.lint.output_format().sorter());
 
-        if violations.is_empty() {
+        // Printed to stderr not to break JSON outputs
+        let mut all_warnings = self.warnings;
+        for warning in warnings {
+            if !all_warnings.contains(&warning) {
+                all_warnings.push(warning);
+            }
+        }
+        for warning in &all_warnings {
+            eprintln!("warning: {warning}");
+        }
+
+        if let Some(path) = &self.options.write_baseline {
+            let baseline = Baseline::new(&violations, &sources);
+            baseline.save(path)?;
             if !self.config.lint.quiet {
-                println!("All checks passed!");
+                let noun = if baseline.len() == 1 {
+                    "violation"
+                } else {
+                    "violations"
+                };
+                println!("Wrote {} {noun} to {}.", baseline.len(), path.display());
+            }
+            return Ok(ExitCode::SUCCESS);
+        }
+
+        if let Some(path) = &self.options.baseline {
+            let (new_violations, fixed_entries) =
+                Baseline::load(path)?.filter(violations, &sources, &paths);
+            violations = new_violations;
+            // Printed to stderr not to break JSON outputs
+            if !fixed_entries.is_empty() && !self.config.lint.quiet {
+                eprintln!("Fixed violations in the baseline:");
+                for entry in &fixed_entries {
+                    eprintln!("  {entry}");
+                }
+                eprintln!(
+                    "Update the baseline with `--write-baseline {}`.",
+                    path.display()
+                );
+            }
+        }
+
+        Self::report(&self.config, &sources, &violations, num_fixed)
+    }
+
+    // Keeps violations on lines changed since `--diff-base`
+    fn filter(diff: Option<&Diff>, mut report: Report) -> Report {
+        if let Some(diff) = diff {
+            report
+                .violations
+                .retain(|violation| diff.contains(violation));
+        }
+        report
+    }
+
+    // Prints sorted violations and a summary, and returns the exit code
+    fn report(
+        config: &Config,
+        sources: &FxHashMap<PathBuf, Arc<Source>>,
+        violations: &[Violation],
+        num_fixed: usize,
+    ) -> Result<ExitCode> {
+        if matches!(
+            config.lint.output_format(),
+            Format::Json | Format::JsonLines | Format::Sarif
+        ) {
+            return Self::report_json(config, violations, num_fixed);
+        }
+
+        if violations.is_empty() {
+            if !config.lint.quiet {
+                if num_fixed == 0 {
+                    println!("All checks passed!");
+                } else {
+                    println!("{}", Self::summary(num_fixed, &[]));
+                }
             }
 
             return Ok(ExitCode::SUCCESS);
         }
 
         let mut output = BufWriter::new(io::stdout().lock());
-        let num_violations = violations.len();
+        let mut separator = "";
         for violation in violations {
-            match self.config.lint.output_format {
+            match config.lint.output_format() {
                 Format::Concise => {
-                    writeln!(output, "{}", Concise::new(&violation)).into_diagnostic()?;
+                    writeln!(output, "{}", Concise::new(violation)).into_diagnostic()?;
                 }
-                Format::Mdl => writeln!(output, "{}", Mdl::new(&violation)).into_diagnostic()?,
+                Format::Full => {
+                    let source = sources.get(violation.path()).ok_or_else(|| {
+                        miette!("Source of {} is not available", violation.path().display())
+                    })?;
+                    writeln!(output, "{separator}{}", Full::new(violation, source))
+                        .into_diagnostic()?;
+                    separator = "\n";
+                }
+                Format::Mdl => writeln!(output, "{}", Mdl::new(violation)).into_diagnostic()?,
                 Format::Markdownlint => {
-                    writeln!(output, "{}", Markdownlint::new(&violation)).into_diagnostic()?;
+                    writeln!(output, "{}", Markdownlint::new(violation)).into_diagnostic()?;
+                }
+                Format::Github => {
+                    writeln!(output, "{}", Github::new(violation)).into_diagnostic()?;
                 }
+                // Reported by report_json
+                Format::Json | Format::JsonLines | Format::Sarif => {}
             }
         }
 
-        if num_violations == 1 {
-            writeln!(output, "\nFound 1 error.").into_diagnostic()?;
+        if config.lint.fix {
+            writeln!(output, "\n{}", Self::summary(num_fixed, violations)).into_diagnostic()?;
         } else {
-            writeln!(output, "\nFound {num_violations} errors.").into_diagnostic()?;
+            writeln!(output, "\nFound {}.", Self::count(violations)).into_diagnostic()?;
+        }
+
+        let exit_code = Self::exit_code(config, violations);
+        if let Some(max_warnings) = config.lint.max_warnings {
+            if exit_code == ExitCode::FAILURE && Self::num_errors(violations) == 0 {
+                writeln!(output, "Too many warnings (max: {max_warnings}).").into_diagnostic()?;
+            }
         }
 
-        Ok(ExitCode::FAILURE)
+        Ok(exit_code)
+    }
+
+    fn num_errors(violations: &[Violation]) -> usize {
+        violations
+            .iter()
+            .filter(|violation| violation.severity() == Severity::Error)
+            .count()
+    }
+
+    // Fails on any errors, or on warnings more than `lint.max-warnings`
+    fn exit_code(config: &Config, violations: &[Violation]) -> ExitCode {
+        let num_warnings = violations
+            .iter()
+            .filter(|violation| violation.severity() == Severity::Warning)
+            .count();
+        let too_many_warnings = config
+            .lint
+            .max_warnings
+            .is_some_and(|max_warnings| num_warnings > max_warnings);
+        if Self::num_errors(violations) > 0 || too_many_warnings {
+            ExitCode::FAILURE
+        } else {
+            ExitCode::SUCCESS
+        }
+    }
+
+    // Counts violations by severity such as "1 error and 2 warnings"
+    fn count(violations: &[Violation]) -> String {
+        let counts: Vec<_> = [Severity::Error, Severity::Warning, Severity::Info]
+            .into_iter()
+            .filter_map(|severity| {
+                let num = violations
+                    .iter()
+                    .filter(|violation| violation.severity() == severity)
+                    .count();
+                let noun = match (severity, num) {
+                    (Severity::Info, _) => "info",
+                    (_, 1) => severity.as_str(),
+                    (Severity::Warning, _) => "warnings",
+                    _ => "errors",
+                };
+                (num > 0).then(|| format!("{num} {noun}"))
+            })
+            .collect();
+        match counts.split_last() {
+            Some((last, [])) => last.clone(),
+            Some((last, rest)) => format!("{} and {last}", rest.join(", ")),
+            None => "0 errors".to_owned(),
+        }
+    }
+
+    // Prints violations as JSON, JSON Lines or SARIF regardless of quiet
+    fn report_json(
+        config: &Config,
+        violations: &[Violation],
+        num_fixed: usize,
+    ) -> Result<ExitCode> {
+        let format = config.lint.output_format();
+        let mut output = BufWriter::new(io::stdout().lock());
+        let summary = Summary::new(num_fixed, violations);
+        if *format == Format::Json {
+            writeln!(output, "{}", JsonReport::new(violations, summary)).into_diagnostic()?;
+        } else if *format == Format::Sarif {
+            writeln!(output, "{}", Sarif::new(violations)).into_diagnostic()?;
+        } else {
+            for violation in violations {
+                writeln!(output, "{}", JsonLine::Violation(Json::new(violation)))
+                    .into_diagnostic()?;
+            }
+            writeln!(output, "{}", JsonLine::Summary(summary)).into_diagnostic()?;
+        }
+
+        Ok(Self::exit_code(config, violations))
+    }
+
+    // Summarizes fixes such as "Found 3 violations (1 fixed, 1 error and 1 warning remaining)."
+    fn summary(num_fixed: usize, remaining: &[Violation]) -> String {
+        let num_violations = num_fixed + remaining.len();
+        let noun = if num_violations == 1 {
+            "violation"
+        } else {
+            "violations"
+        };
+        let remaining = if remaining.is_empty() {
+            "0".to_owned()
+        } else {
+            Self::count(remaining)
+        };
+        format!("Found {num_violations} {noun} ({num_fixed} fixed, {remaining} remaining).")
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::fs;
     use std::path::Path;
 
     use pretty_assertions::assert_eq;
@@ -127,40 +479,114 @@ mod tests {
     use super::*;
 
     #[test]
-    fn options_to_config_none_none_false_none() -> Result<()> {
+    fn options_to_config_none_none_false_false_none() -> Result<()> {
         let options = Options {
             config_path: None,
             output_format: None,
             quiet: false,
+            fix: false,
             exclude: None,
+            max_warnings: None,
+            no_cache: false,
+            diff_base: None,
+            baseline: None,
+            write_baseline: None,
+            stdin_filename: None,
+            github_actions: false,
         };
-        let actual = options.to_config()?;
+        let (actual, _) = options.to_config()?;
         let mut expected = Config::default();
         expected.lint.md013.code_blocks = false;
         expected.lint.md013.tables = false;
         expected.lint.md024.allow_different_nesting = true;
         assert_eq!(actual, expected);
+        let expected_options = RunOptions {
+            cache_dir: Cache::root(),
+            ..RunOptions::default()
+        };
+        assert_eq!(options.to_run_options(&actual), expected_options);
         Ok(())
     }
 
     #[test]
-    fn options_to_config_some_some_true_some() -> Result<()> {
+    fn options_to_config_some_some_true_true_some() -> Result<()> {
         let exclude = vec![Glob::new("README.md").into_diagnostic()?];
         let options = Options {
             config_path: Some(Path::new("mado.toml").to_path_buf()),
             output_format: Some(Format::Mdl),
             quiet: true,
+            fix: true,
             exclude: Some(exclude.clone()),
+            max_warnings: Some(3),
+            no_cache: true,
+            diff_base: None,
+            baseline: None,
+            write_baseline: None,
+            stdin_filename: None,
+            github_actions: true,
         };
-        let actual = options.to_config()?;
+        let (actual, _) = options.to_config()?;
         let mut expected = Config::default();
-        expected.lint.output_format = Format::Mdl;
+        expected.lint.output_format = Some(Format::Mdl);
         expected.lint.quiet = true;
+        expected.lint.fix = true;
         expected.lint.exclude = exclude;
+        expected.lint.max_warnings = Some(3);
         expected.lint.md013.code_blocks = false;
         expected.lint.md013.tables = false;
         expected.lint.md024.allow_different_nesting = true;
         assert_eq!(actual, expected);
+        let expected_options = RunOptions {
+            config_path: Some(Path::new("mado.toml").to_path_buf()),
+            ..RunOptions::default()
+        };
+        assert_eq!(options.to_run_options(&actual), expected_options);
         Ok(())
     }
+
+    #[test]
+    fn options_to_config_github_actions() -> Result<()> {
+        let options = Options {
+            config_path: Some(Path::new("mado.toml").to_path_buf()),
+            output_format: None,
+            quiet: false,
+            fix: false,
+            exclude: None,
+            max_warnings: None,
+            no_cache: false,
+            diff_base: None,
+            baseline: None,
+            write_baseline: None,
+            stdin_filename: None,
+            github_actions: true,
+        };
+        let (actual, _) = options.to_config()?;
+        assert_eq!(actual.lint.output_format, Some(Format::Github));
+        Ok(())
+    }
+
+    #[test]
+    fn options_to_config_github_actions_with_config_format() -> Result<()> {
+        let tmp_dir = tempfile::tempdir().into_diagnostic()?;
+        let config_path = tmp_dir.path().join("mado.toml");
+        fs::write(&config_path, "[lint]\noutput-format = \"concise\"\n").into_diagnostic()?;
+        let options = Options {
+            config_path: Some(config_path),
+            output_format: None,
+            quiet: false,
+            fix: false,
+            exclude: None,
+            max_warnings: None,
+            no_cache: false,
+            diff_base: None,
+            baseline: None,
+            write_baseline: None,
+            stdin_filename: None,
+            github_actions: true,
+        };
+        // Formats set explicitly are respected even if the default is concise
+        let (actual, _) = options.to_config()?;
+        assert_eq!(actual.lint.output_format, Some(Format::Concise));
+        tmp_dir.close().into_diagnostic()
+    }
 }
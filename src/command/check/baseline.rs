@@ -0,0 +1,251 @@
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::fmt::{self, Display, Formatter};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use miette::{miette, IntoDiagnostic as _, Result};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+
+use crate::{Source, Violation};
+
+/// Violations accepted as existing ones.
+/// Entries are identified by hashes of lines rather than line numbers to survive unrelated
+/// edits, without copying the contents of documents into the baseline.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    violations: Vec<Entry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Entry {
+    path: String,
+    rule: String,
+    /// Hex SHA-256 digest of the first line of the violation without surrounding whitespace
+    hash: String,
+}
+
+impl Baseline {
+    #[inline]
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| miette!("Failed to load {}: {err}", path.display()))?;
+        serde_json::from_str(&text).into_diagnostic()
+    }
+
+    #[inline]
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut text = serde_json::to_string_pretty(self).into_diagnostic()?;
+        text.push('\n');
+        fs::write(path, text).into_diagnostic()
+    }
+
+    /// Creates a baseline of the violations with sources of files with violations
+    #[inline]
+    #[must_use]
+    pub fn new(violations: &[Violation], sources: &FxHashMap<PathBuf, Arc<Source>>) -> Self {
+        let mut violations = Entry::from_violations(violations, sources);
+        violations.sort();
+        Self { violations }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.violations.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Returns violations not in the baseline and baseline entries that have been fixed.
+    /// Entries of files not in `linted_paths` are neither matched nor reported as fixed.
+    #[inline]
+    #[must_use]
+    pub fn filter(
+        &self,
+        violations: Vec<Violation>,
+        sources: &FxHashMap<PathBuf, Arc<Source>>,
+        linted_paths: &FxHashSet<PathBuf>,
+    ) -> (Vec<Violation>, Vec<Entry>) {
+        let mut remaining: FxHashMap<&Entry, usize> = FxHashMap::default();
+        for entry in &self.violations {
+            *remaining.entry(entry).or_default() += 1;
+        }
+
+        let entries = Entry::from_violations(&violations, sources);
+        let new_violations = violations
+            .into_iter()
+            .zip(entries)
+            .filter_map(|(violation, entry)| match remaining.get_mut(&entry) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    None
+                }
+                _ => Some(violation),
+            })
+            .collect();
+
+        let linted_paths: FxHashSet<_> = linted_paths.iter().map(|path| normalize(path)).collect();
+        let mut fixed_entries = vec![];
+        for entry in &self.violations {
+            if !linted_paths.contains(&entry.path) {
+                continue;
+            }
+            if let Some(count) = remaining.get_mut(entry) {
+                if *count > 0 {
+                    *count -= 1;
+                    fixed_entries.push(entry.clone());
+                }
+            }
+        }
+
+        (new_violations, fixed_entries)
+    }
+}
+
+impl Display for Entry {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.rule)
+    }
+}
+
+impl Entry {
+    fn new(violation: &Violation, source: Option<&Source>) -> Self {
+        let lineno = violation.position().start.line;
+        let line = source.and_then(|src| src.line(lineno)).unwrap_or_default();
+
+        Self {
+            path: normalize(violation.path()),
+            rule: violation.name().to_owned(),
+            hash: format!("{:x}", Sha256::digest(line.trim())),
+        }
+    }
+
+    // Converts violations into entries looking up lines in the sources
+    fn from_violations(
+        violations: &[Violation],
+        sources: &FxHashMap<PathBuf, Arc<Source>>,
+    ) -> Vec<Self> {
+        violations
+            .iter()
+            .map(|violation| {
+                let source = sources.get(violation.path()).map(AsRef::as_ref);
+                Self::new(violation, source)
+            })
+            .collect()
+    }
+}
+
+// Ignores `./` to match paths regardless of how files are specified
+fn normalize(path: &Path) -> String {
+    path.components()
+        .filter(|component| *component != Component::CurDir)
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use comrak::nodes::Sourcepos;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    use crate::rule::RuleLike as _;
+    use crate::rule::{MD026, MD047};
+
+    use super::*;
+
+    const PATH: &str = "./(stdin)";
+
+    fn violation(line: usize) -> Violation {
+        MD026::default().to_violation(PathBuf::from(PATH), Sourcepos::from((line, 1, line, 8)))
+    }
+
+    fn sources(text: &str) -> FxHashMap<PathBuf, Arc<Source>> {
+        let source = Source::new(PathBuf::from(PATH), text.to_owned());
+        FxHashMap::from_iter([(PathBuf::from(PATH), Arc::new(source))])
+    }
+
+    fn linted_paths() -> FxHashSet<PathBuf> {
+        FxHashSet::from_iter([PathBuf::from(PATH)])
+    }
+
+    #[test]
+    fn entry_new() {
+        let source = Source::new(PathBuf::from(PATH), "# Hello.\n\n  # Hello.  \n".to_owned());
+        let entry = Entry::new(&violation(1), Some(&source));
+        assert_eq!(entry.path, "(stdin)");
+        assert_eq!(entry.rule, "MD026");
+        assert_eq!(
+            entry.hash,
+            "4efa557bb08fd69a1ed36dabffa4704de63421f409bca9e449bb3c1ee43b71ba"
+        );
+        assert_eq!(entry, Entry::new(&violation(3), Some(&source)));
+        assert_ne!(entry, Entry::new(&violation(2), Some(&source)));
+    }
+
+    #[test]
+    fn filter() {
+        let old_sources = sources("# Hello.\n\n## World.\n");
+        let baseline = Baseline::new(&[violation(1), violation(3)], &old_sources);
+        assert_eq!(baseline.len(), 2);
+
+        // The old line moved and the new line has the same violation
+        let new_sources = sources("Intro\n\n# Hello.\n\n## New.\n");
+        let other_violation =
+            MD047::default().to_violation(PathBuf::from(PATH), Sourcepos::from((3, 1, 3, 8)));
+        let violations = vec![violation(3), violation(5), other_violation.clone()];
+        let (actual, fixed_entries) = baseline.filter(violations, &new_sources, &linted_paths());
+        assert_eq!(actual, vec![violation(5), other_violation]);
+        let fixed: Vec<_> = fixed_entries.iter().map(ToString::to_string).collect();
+        assert_eq!(fixed, vec!["(stdin): MD026"]);
+    }
+
+    #[test]
+    fn filter_duplicates() {
+        let sources = sources("# Hello.\n\n# Hello.\n");
+        let baseline = Baseline::new(&[violation(1)], &sources);
+        let (actual, fixed_entries) =
+            baseline.filter(vec![violation(1), violation(3)], &sources, &linted_paths());
+        assert_eq!(actual, vec![violation(3)]);
+        assert!(fixed_entries.is_empty());
+    }
+
+    #[test]
+    fn filter_not_linted() {
+        let baseline = Baseline::new(&[violation(1)], &sources("# Hello.\n"));
+        let other_paths = FxHashSet::from_iter([PathBuf::from("README.md")]);
+        let (actual, fixed_entries) = baseline.filter(vec![], &FxHashMap::default(), &other_paths);
+        assert!(actual.is_empty());
+        assert!(fixed_entries.is_empty());
+
+        // Paths are matched regardless of `./`
+        let stdin_paths = FxHashSet::from_iter([PathBuf::from("(stdin)")]);
+        let (_, stdin_fixed_entries) = baseline.filter(vec![], &FxHashMap::default(), &stdin_paths);
+        assert_eq!(stdin_fixed_entries.len(), 1);
+    }
+
+    #[test]
+    fn save_and_load() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let path = tmp_dir.path().join("mado-baseline.json");
+        let baseline = Baseline::new(&[violation(1)], &sources("# Hello.\n"));
+        baseline.save(&path)?;
+        assert_eq!(Baseline::load(&path)?, baseline);
+        // Lines of documents are not stored
+        let text = fs::read_to_string(&path).into_diagnostic()?;
+        assert!(text.contains("\"hash\": \"4efa557b"));
+        assert!(!text.contains("Hello"));
+        assert!(Baseline::load(&tmp_dir.path().join("missing.json")).is_err());
+        tmp_dir.close().into_diagnostic()
+    }
+}
@@ -0,0 +1,538 @@
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::time::Duration;
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal as _, Write as _};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::mpsc::{self, Receiver};
+
+use comrak::Arena;
+use miette::{miette, IntoDiagnostic as _, Report, Result};
+use notify::event::ModifyKind;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher as _};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::{Checker, Options};
+use crate::config::Config;
+use crate::service::filter::FileFilter;
+use crate::service::runner::ParallelLintRunner;
+use crate::service::walker::WalkSequentialBuilder;
+use crate::service::LinterResolver;
+use crate::{Document, Source, Violation};
+
+/// Re-lints changed files and redraws the report until the process is killed
+pub struct Watcher {
+    patterns: Vec<PathBuf>,
+    options: Options,
+}
+
+/// Paths changed by events debounced together
+struct Changes {
+    paths: FxHashSet<PathBuf>,
+    /// Whether files may have been created, removed or renamed
+    rewalk: bool,
+}
+
+/// Config files reloaded on changes
+#[derive(Default)]
+struct ConfigFiles {
+    /// Canonical paths of config files in use including files specified by `extends`
+    paths: FxHashSet<PathBuf>,
+    /// Directories watched for config files
+    dirs: FxHashSet<PathBuf>,
+}
+
+/// Lint results of watched files
+struct State {
+    config: Config,
+    resolver: LinterResolver,
+    filter: FileFilter,
+    /// Paths of Markdown files to lint by canonical paths
+    files: FxHashMap<PathBuf, PathBuf>,
+    /// Violations by canonical paths
+    violations: FxHashMap<PathBuf, Vec<Violation>>,
+    /// Sources of files with violations by canonical paths
+    sources: FxHashMap<PathBuf, Arc<Source>>,
+    /// Errors of files failed to lint by canonical paths
+    errors: FxHashMap<PathBuf, Report>,
+    /// Warnings of config files in use
+    warnings: Vec<String>,
+}
+
+impl Watcher {
+    // Waits for more events to lint files saved at once together
+    const DEBOUNCE: Duration = Duration::from_millis(100);
+
+    #[inline]
+    pub fn new(patterns: &[PathBuf], options: Options) -> Result<Self> {
+        if options.fix {
+            return Err(miette!("--fix cannot be used with --watch"));
+        }
+        if options.diff_base.is_some() {
+            return Err(miette!("--diff-base cannot be used with --watch"));
+        }
+        if options.baseline.is_some() || options.write_baseline.is_some() {
+            return Err(miette!("Baselines cannot be used with --watch"));
+        }
+        if options.stdin_filename.is_some()
+            || patterns
+                .iter()
+                .any(|pattern| pattern.as_os_str() == Checker::STDIN)
+        {
+            return Err(miette!("stdin cannot be used with --watch"));
+        }
+
+        Ok(Self {
+            patterns: patterns.to_vec(),
+            options,
+        })
+    }
+
+    #[inline]
+    pub fn watch(self) -> Result<ExitCode> {
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx).into_diagnostic()?;
+        for pattern in &self.patterns {
+            watcher
+                .watch(pattern, RecursiveMode::Recursive)
+                .into_diagnostic()?;
+        }
+        if self.options.config_path.is_none() {
+            // A config file may be created in the current directory
+            let current_dir = env::current_dir().into_diagnostic()?;
+            watcher
+                .watch(&current_dir, RecursiveMode::NonRecursive)
+                .into_diagnostic()?;
+        }
+
+        let mut config_files = ConfigFiles::default();
+        let mut state = self.load();
+        self.watch_configs(&mut watcher, &mut config_files, &mut state);
+        Self::redraw(&state);
+
+        while let Some(Changes { mut paths, rewalk }) = Self::wait(&rx) {
+            // Ignore changes of other files such as swap files of editors
+            paths.retain(|path| {
+                self.is_config(&config_files, path)
+                    || state.as_ref().is_ok_and(|current| current.is_target(path))
+            });
+            if paths.is_empty() {
+                continue;
+            }
+
+            // Configs failed to load are retried after changes of config files
+            let reload = paths.iter().any(|path| self.is_config(&config_files, path));
+            if reload {
+                state = self.load();
+                self.watch_configs(&mut watcher, &mut config_files, &mut state);
+            } else if let Ok(current) = &mut state {
+                if let Err(err) = current.update(&self.patterns, &paths, rewalk) {
+                    state = Err(err);
+                }
+            }
+            Self::redraw(&state);
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    // Watches directories of config files in use, as editors may replace files on saves
+    fn watch_configs(
+        &self,
+        watcher: &mut RecommendedWatcher,
+        config_files: &mut ConfigFiles,
+        state: &mut Result<State>,
+    ) {
+        let mut config_paths = FxHashSet::default();
+        match &self.options.config_path {
+            Some(config_path) => {
+                config_paths.insert(config_path.clone());
+            }
+            None => {
+                if let Ok(Some(config_path)) = Config::resolve_path() {
+                    config_paths.insert(config_path);
+                }
+            }
+        }
+        // Config files discovered in subdirectories
+        if let Ok(current) = state {
+            for path in current.files.values() {
+                if let Ok(Some(config_path)) = current.resolver.config_path(path) {
+                    config_paths.insert(config_path);
+                }
+            }
+        }
+        config_files.paths = config_paths.iter().flat_map(Config::files).collect();
+
+        let patterns: Vec<_> = self
+            .patterns
+            .iter()
+            .filter_map(|pattern| fs::canonicalize(pattern).ok())
+            .collect();
+        for dir in config_files.paths.iter().filter_map(|path| path.parent()) {
+            // Directories under patterns are watched recursively
+            let is_watched = config_files.dirs.contains(dir)
+                || patterns.iter().any(|pattern| dir.starts_with(pattern));
+            if !is_watched && watcher.watch(dir, RecursiveMode::NonRecursive).is_ok() {
+                config_files.dirs.insert(dir.to_path_buf());
+            }
+        }
+    }
+
+    fn is_config(&self, config_files: &ConfigFiles, path: &Path) -> bool {
+        let canonical_path = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        if config_files.paths.contains(&canonical_path) {
+            return true;
+        }
+
+        // Config files may be created while discovering config files
+        self.options.config_path.is_none()
+            && path
+                .file_name()
+                .is_some_and(|file_name| Config::file_names().any(|name| file_name == name))
+    }
+
+    // Reloads the config and lints all files
+    fn load(&self) -> Result<State> {
+        let (config, mut warnings) = self.options.to_config()?;
+        let options = self.options.to_run_options(&config);
+        let resolver = LinterResolver::new(&config, options.config_path.as_deref())?;
+        let filter = FileFilter::new(&config.lint)?;
+        let runner = ParallelLintRunner::new(&self.patterns, config.clone(), options, 100)?;
+        let report = runner.run()?;
+        let mut violations: FxHashMap<_, Vec<_>> = FxHashMap::default();
+        for violation in report.violations {
+            let path = fs::canonicalize(violation.path()).into_diagnostic()?;
+            violations.entry(path).or_default().push(violation);
+        }
+        let mut sources = FxHashMap::default();
+        for (path, source) in report.sources {
+            sources.insert(fs::canonicalize(path).into_diagnostic()?, source);
+        }
+        for warning in report.warnings {
+            if !warnings.contains(&warning) {
+                warnings.push(warning);
+            }
+        }
+
+        let mut state = State {
+            config,
+            resolver,
+            filter,
+            files: FxHashMap::default(),
+            violations,
+            sources,
+            errors: FxHashMap::default(),
+            warnings,
+        };
+        state.files = state.files(&self.patterns)?;
+        Ok(state)
+    }
+
+    // Returns changed paths, or None if the watcher is dropped
+    fn wait(rx: &Receiver<notify::Result<Event>>) -> Option<Changes> {
+        let mut changes = Changes {
+            paths: FxHashSet::default(),
+            rewalk: false,
+        };
+        let mut result = rx.recv().ok()?;
+        loop {
+            if let Ok(event) = result {
+                if !event.kind.is_access() {
+                    changes.rewalk |= matches!(
+                        event.kind,
+                        EventKind::Any
+                            | EventKind::Create(_)
+                            | EventKind::Modify(ModifyKind::Name(_))
+                            | EventKind::Remove(_)
+                            | EventKind::Other
+                    );
+                    changes.paths.extend(event.paths);
+                }
+            }
+
+            match rx.recv_timeout(Self::DEBOUNCE) {
+                Ok(next) => result = next,
+                Err(_) if changes.paths.is_empty() => result = rx.recv().ok()?,
+                Err(_) => return Some(changes),
+            }
+        }
+    }
+
+    // Errors are shown in place of the report to keep watching until they are fixed
+    fn redraw(state: &Result<State>) {
+        // Clear the screen and move the cursor to the top-left corner unless piped
+        if io::stdout().is_terminal() {
+            print!("\u{1b}[2J\u{1b}[H");
+            // Clear before errors are written to stderr
+            let _result = io::stdout().flush();
+        }
+        match state {
+            Ok(current) => {
+                for warning in &current.warnings {
+                    eprintln!("warning: {warning}");
+                }
+                let mut errors: Vec<_> = current.errors.iter().collect();
+                errors.sort_by_key(|(path, _)| *path);
+                for (_, err) in errors {
+                    eprintln!("{err:?}");
+                }
+                if let Err(err) = current.report() {
+                    eprintln!("{err:?}");
+                }
+            }
+            Err(err) => eprintln!("{err:?}"),
+        }
+        println!("\nWatching for changes...");
+    }
+}
+
+impl State {
+    // Returns true if the changed file may be linted
+    fn is_target(&self, path: &Path) -> bool {
+        self.filter.is_match(path)
+            || fs::canonicalize(path)
+                .is_ok_and(|canonical_path| self.files.contains_key(&canonical_path))
+    }
+
+    // Returns files to lint respecting ignore files and `lint.include` and `lint.exclude`
+    fn files(&self, patterns: &[PathBuf]) -> Result<FxHashMap<PathBuf, PathBuf>> {
+        let walk = WalkSequentialBuilder::build(
+            patterns,
+            self.config.lint.respect_ignore,
+            self.config.lint.respect_gitignore,
+            self.filter.types()?,
+        )?;
+
+        let mut files = FxHashMap::default();
+        for entry in walk {
+            let entry = entry.into_diagnostic()?;
+            if self.filter.is_match_entry(&entry) {
+                let path = entry.into_path();
+                files.insert(fs::canonicalize(&path).into_diagnostic()?, path);
+            }
+        }
+
+        Ok(files)
+    }
+
+    // Re-lints changed files only. Files are walked again only if they may be created or removed.
+    // Errors of files are kept to be shown until the files are fixed.
+    fn update(
+        &mut self,
+        patterns: &[PathBuf],
+        changed_paths: &FxHashSet<PathBuf>,
+        rewalk: bool,
+    ) -> Result<()> {
+        if rewalk {
+            self.files = self.files(patterns)?;
+            let files = &self.files;
+            self.violations.retain(|path, _| files.contains_key(path));
+            self.sources.retain(|path, _| files.contains_key(path));
+            self.errors.retain(|path, _| files.contains_key(path));
+        }
+
+        for changed_path in changed_paths {
+            let Ok(canonical_path) = fs::canonicalize(changed_path) else {
+                // Removed files
+                continue;
+            };
+            let Some(path) = self.files.get(&canonical_path) else {
+                continue;
+            };
+
+            let arena = Arena::new();
+            let result = Document::open(&arena, path).and_then(|doc| {
+                let violations = self.resolver.resolve(path)?.check(&doc)?;
+                Ok((doc, violations))
+            });
+            match result {
+                Ok((doc, violations)) => {
+                    if violations.is_empty() {
+                        self.sources.remove(&canonical_path);
+                    } else {
+                        let source = Arc::new(Source::from(doc));
+                        self.sources.insert(canonical_path.clone(), source);
+                    }
+                    self.errors.remove(&canonical_path);
+                    self.violations.insert(canonical_path, violations);
+                }
+                Err(err) => {
+                    self.sources.remove(&canonical_path);
+                    self.violations.remove(&canonical_path);
+                    self.errors.insert(canonical_path, err);
+                }
+            }
+        }
+
+        // Config files may be discovered for new files
+        for warning in self.resolver.take_warnings() {
+            if !self.warnings.contains(&warning) {
+                self.warnings.push(warning);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn report(&self) -> Result<ExitCode> {
+        let mut violations: Vec<_> = self.violations.values().flatten().cloned().collect();
+        violations.sort_by(self.config.lint.output_format().sorter());
+        let sources = self
+            .sources
+            .values()
+            .map(|source| (source.path().to_path_buf(), Arc::clone(source)))
+            .collect();
+        Checker::report(&self.config, &sources, &violations, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const fn options(config_path: Option<PathBuf>, fix: bool) -> Options {
+        Options {
+            config_path,
+            output_format: None,
+            quiet: false,
+            fix,
+            exclude: None,
+            max_warnings: None,
+            no_cache: true,
+            diff_base: None,
+            baseline: None,
+            write_baseline: None,
+            stdin_filename: None,
+            github_actions: false,
+        }
+    }
+
+    #[test]
+    fn new_with_fix() {
+        let actual = Watcher::new(&[PathBuf::from(".")], options(None, true));
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn is_config() -> Result<()> {
+        let watcher = Watcher::new(&[PathBuf::from(".")], options(None, false))?;
+        let config_files = ConfigFiles::default();
+        assert!(watcher.is_config(&config_files, Path::new("/path/to/mado.toml")));
+        assert!(watcher.is_config(&config_files, Path::new("/path/to/.mado.toml")));
+        assert!(!watcher.is_config(&config_files, Path::new("/path/to/README.md")));
+
+        let config_path = PathBuf::from("/path/to/config/custom.toml");
+        let custom_watcher = Watcher::new(
+            &[PathBuf::from(".")],
+            options(Some(config_path.clone()), false),
+        )?;
+        let custom_config_files = ConfigFiles {
+            paths: FxHashSet::from_iter([config_path.clone()]),
+            dirs: FxHashSet::default(),
+        };
+        assert!(custom_watcher.is_config(&custom_config_files, &config_path));
+        assert!(!custom_watcher.is_config(&custom_config_files, Path::new("/path/to/mado.toml")));
+        Ok(())
+    }
+
+    #[test]
+    fn watch_configs() -> Result<()> {
+        let tmp_dir = tempfile::tempdir().into_diagnostic()?;
+        let docs_dir = tmp_dir.path().join("docs");
+        fs::create_dir(&docs_dir).into_diagnostic()?;
+        fs::write(docs_dir.join("README.md"), "# Hello\n").into_diagnostic()?;
+        let base_path = tmp_dir.path().join("base.toml");
+        fs::write(&base_path, "").into_diagnostic()?;
+        let config_path = docs_dir.join("mado.toml");
+        fs::write(&config_path, "extends = \"../base.toml\"\n").into_diagnostic()?;
+
+        let watcher = Watcher::new(&[docs_dir.clone()], options(None, false))?;
+        let (tx, _rx) = mpsc::channel();
+        let mut notify_watcher = notify::recommended_watcher(tx).into_diagnostic()?;
+        let mut config_files = ConfigFiles::default();
+        let mut state = watcher.load();
+        watcher.watch_configs(&mut notify_watcher, &mut config_files, &mut state);
+
+        // The config file in the subdirectory and its base are reloaded
+        let canonical_base_path = fs::canonicalize(&base_path).into_diagnostic()?;
+        assert!(watcher.is_config(&config_files, &config_path));
+        assert!(watcher.is_config(&config_files, &base_path));
+        // The directory of the base is watched, but the docs directory is watched recursively
+        let canonical_dir = fs::canonicalize(tmp_dir.path()).into_diagnostic()?;
+        assert!(config_files.dirs.contains(&canonical_dir));
+        assert!(config_files.paths.contains(&canonical_base_path));
+        assert!(!config_files
+            .dirs
+            .contains(&fs::canonicalize(&docs_dir).into_diagnostic()?));
+        drop(notify_watcher);
+        tmp_dir.close().into_diagnostic()
+    }
+
+    #[test]
+    fn state_update() -> Result<()> {
+        let tmp_dir = tempfile::tempdir().into_diagnostic()?;
+        let path = tmp_dir.path().join("README.md");
+        fs::write(&path, "# Hello\n").into_diagnostic()?;
+        let config_path = Path::new("mado.toml").to_path_buf();
+        let patterns = vec![tmp_dir.path().to_path_buf()];
+        let watcher = Watcher::new(&patterns, options(Some(config_path), false))?;
+        let mut state = watcher.load()?;
+        assert_eq!(state.violations.len(), 0);
+        assert_eq!(state.files.len(), 1);
+
+        fs::write(&path, "# Hello.\n").into_diagnostic()?;
+        let changed_paths = FxHashSet::from_iter([path.clone()]);
+        state.update(&patterns, &changed_paths, false)?;
+        let canonical_path = fs::canonicalize(&path).into_diagnostic()?;
+        let names: Vec<_> = state.violations[&canonical_path]
+            .iter()
+            .map(Violation::name)
+            .collect();
+        assert_eq!(names, vec!["MD026"]);
+
+        // New files are linted after walking files again
+        let new_path = tmp_dir.path().join("NEW.md");
+        fs::write(&new_path, "# New.\n").into_diagnostic()?;
+        let new_changed_paths = FxHashSet::from_iter([new_path.clone()]);
+        state.update(&patterns, &new_changed_paths, false)?;
+        assert_eq!(state.violations.len(), 1);
+        state.update(&patterns, &new_changed_paths, true)?;
+        assert_eq!(state.violations.len(), 2);
+
+        fs::remove_file(&path).into_diagnostic()?;
+        fs::remove_file(&new_path).into_diagnostic()?;
+        state.update(&patterns, &changed_paths, true)?;
+        assert_eq!(state.violations.len(), 0);
+        tmp_dir.close().into_diagnostic()
+    }
+
+    #[test]
+    fn state_update_with_error() -> Result<()> {
+        let tmp_dir = tempfile::tempdir().into_diagnostic()?;
+        let path = tmp_dir.path().join("README.md");
+        fs::write(&path, "# Hello.\n").into_diagnostic()?;
+        let config_path = Path::new("mado.toml").to_path_buf();
+        let patterns = vec![tmp_dir.path().to_path_buf()];
+        let watcher = Watcher::new(&patterns, options(Some(config_path), false))?;
+        let mut state = watcher.load()?;
+        assert_eq!(state.violations.len(), 1);
+
+        // Errors of files are kept until the files are fixed
+        fs::write(&path, [0xff, 0xfe]).into_diagnostic()?;
+        let changed_paths = FxHashSet::from_iter([path.clone()]);
+        state.update(&patterns, &changed_paths, false)?;
+        assert_eq!(state.violations.len(), 0);
+        assert_eq!(state.errors.len(), 1);
+
+        fs::write(&path, "# Hello\n").into_diagnostic()?;
+        state.update(&patterns, &changed_paths, false)?;
+        assert_eq!(state.errors.len(), 0);
+        tmp_dir.close().into_diagnostic()
+    }
+}
@@ -0,0 +1,54 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use miette::{IntoDiagnostic as _, Result};
+
+#[derive(Debug, Clone)]
+pub struct Cleaner {
+    cache_dirs: Vec<PathBuf>,
+}
+
+impl Cleaner {
+    #[inline]
+    #[must_use]
+    pub fn new(cache_dirs: &[PathBuf]) -> Self {
+        Self {
+            cache_dirs: cache_dirs.to_vec(),
+        }
+    }
+
+    /// Removes the cache directories if exist
+    #[inline]
+    pub fn clean(&self) -> Result<ExitCode> {
+        for cache_dir in &self.cache_dirs {
+            if fs::exists(cache_dir).into_diagnostic()? {
+                fs::remove_dir_all(cache_dir).into_diagnostic()?;
+                println!("Removed {}", cache_dir.display());
+            }
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn clean() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let cache_dir = tmp_dir.path().join("mado");
+        fs::create_dir_all(cache_dir.join("0.3.0")).into_diagnostic()?;
+        let cleaner = Cleaner::new(&[cache_dir.clone()]);
+        assert_eq!(cleaner.clean()?, ExitCode::SUCCESS);
+        assert!(!fs::exists(&cache_dir).into_diagnostic()?);
+
+        // No errors without the cache directory
+        assert_eq!(cleaner.clean()?, ExitCode::SUCCESS);
+        tmp_dir.close().into_diagnostic()
+    }
+}
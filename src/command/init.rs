@@ -0,0 +1,454 @@
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use std::fs;
+use std::io::{self, BufWriter, Write as _};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use comrak::nodes::{AstNode, NodeValue};
+use comrak::Arena;
+use miette::{miette, IntoDiagnostic as _, Result};
+use toml::{Table, Value};
+
+use crate::config::lint::{MD003, MD004, MD007, MD013, MD029, MD035, MD046};
+use crate::config::{Config, Lint};
+use crate::rule::md003::HeadingStyle;
+use crate::rule::md004::ListStyle;
+use crate::rule::md029::OrderedListStyle;
+use crate::rule::md035::HorizontalRuleStyle;
+use crate::rule::md046::CodeBlockStyle;
+use crate::service::filter::FileFilter;
+use crate::service::walker::WalkSequentialBuilder;
+use crate::service::Linter;
+use crate::{rule, Document, Rule};
+
+/// Writes a config file, optionally inferring styles from existing Markdown files
+pub struct Initializer {
+    patterns: Vec<PathBuf>,
+    config_path: PathBuf,
+    /// Config to select files to infer from
+    lint: Lint,
+    infer: bool,
+}
+
+impl Initializer {
+    // Candidates of `line-length` from the shortest
+    const LINE_LENGTHS: [usize; 2] = [80, 100];
+    // `line-length` used if too many lines exceed all candidates
+    const MAX_LINE_LENGTH: usize = 120;
+    // Maximum percentage of lines allowed to exceed the inferred line length
+    const MAX_LONG_LINES_PERCENT: usize = 1;
+
+    #[inline]
+    #[must_use]
+    pub fn new(patterns: &[PathBuf], config_path: &Path, lint: &Lint, infer: bool) -> Self {
+        Self {
+            patterns: patterns.to_vec(),
+            config_path: config_path.to_path_buf(),
+            lint: lint.clone(),
+            infer,
+        }
+    }
+
+    #[inline]
+    pub fn init(&self) -> Result<ExitCode> {
+        if fs::exists(&self.config_path).into_diagnostic()? {
+            return Err(miette!("{} already exists", self.config_path.display()));
+        }
+
+        let mut output = BufWriter::new(io::stdout().lock());
+        if !self.infer {
+            fs::write(&self.config_path, Self::to_toml(&Lint::default())?).into_diagnostic()?;
+            writeln!(output, "Wrote {}", self.config_path.display()).into_diagnostic()?;
+            return Ok(ExitCode::SUCCESS);
+        }
+
+        let arena = Arena::new();
+        let docs = self.documents(&arena)?;
+        let lint = Self::infer(&docs)?;
+        let text = Self::to_toml(&lint)?;
+        fs::write(&self.config_path, &text).into_diagnostic()?;
+
+        writeln!(output, "Inferred from {} files:", docs.len()).into_diagnostic()?;
+        for (name, value) in Self::sections(&lint)? {
+            if let Value::Table(options) = value {
+                for (key, option) in options {
+                    writeln!(output, "  {} {key} = {option}", name.to_uppercase())
+                        .into_diagnostic()?;
+                }
+            }
+        }
+        writeln!(output, "\nWrote {}", self.config_path.display()).into_diagnostic()?;
+
+        let counts = Self::count_violations(&lint, &docs)?;
+        let num_violations: usize = counts.values().sum();
+        if num_violations == 0 {
+            writeln!(output, "All checks pass with the configuration.").into_diagnostic()?;
+        } else {
+            let errors = if num_violations == 1 {
+                "error"
+            } else {
+                "errors"
+            };
+            writeln!(
+                output,
+                "Found {num_violations} {errors} with the configuration:"
+            )
+            .into_diagnostic()?;
+            for (name, count) in counts {
+                writeln!(output, "  {name}: {count}").into_diagnostic()?;
+            }
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    // Parses Markdown files selected by the config respecting ignore files
+    fn documents<'a>(&self, arena: &'a Arena<AstNode<'a>>) -> Result<Vec<Document<'a>>> {
+        let filter = FileFilter::new(&self.lint)?;
+        let walk = WalkSequentialBuilder::build(
+            &self.patterns,
+            self.lint.respect_ignore,
+            self.lint.respect_gitignore,
+            filter.types()?,
+        )?;
+        let mut docs = vec![];
+        for entry in walk {
+            let entry = entry.into_diagnostic()?;
+            if filter.is_match_entry(&entry) {
+                docs.push(Document::open(arena, entry.path())?);
+            }
+        }
+        Ok(docs)
+    }
+
+    /// Returns the default lint configuration with styles used the most in the documents
+    fn infer(docs: &[Document]) -> Result<Lint> {
+        let mut lint = Lint::default();
+
+        let heading_styles = [
+            HeadingStyle::Atx,
+            HeadingStyle::AtxClosed,
+            HeadingStyle::Setext,
+            HeadingStyle::SetextWithAtx,
+        ];
+        lint.md003.style = dominant(docs, lint.md003.style, &heading_styles, |style| {
+            Rule::MD003(rule::MD003::from(&MD003 {
+                style: style.clone(),
+            }))
+        })?;
+
+        let list_styles = [ListStyle::Asterisk, ListStyle::Plus, ListStyle::Dash];
+        lint.md004.style = dominant(docs, lint.md004.style, &list_styles, |style| {
+            Rule::MD004(rule::MD004::from(&MD004 {
+                style: style.clone(),
+            }))
+        })?;
+
+        lint.md007.indent = dominant(docs, lint.md007.indent, &[2, 3, 4], |&indent| {
+            Rule::MD007(rule::MD007::from(&MD007 { indent }))
+        })?;
+
+        lint.md013.line_length = Self::infer_line_length(docs, &lint.md013)?;
+
+        let ordered_list_styles = [OrderedListStyle::One, OrderedListStyle::Ordered];
+        lint.md029.style = dominant(docs, lint.md029.style, &ordered_list_styles, |style| {
+            Rule::MD029(rule::MD029::from(&MD029 {
+                style: style.clone(),
+            }))
+        })?;
+
+        let hr_styles: Vec<_> = horizontal_rules(docs)
+            .into_iter()
+            .map(HorizontalRuleStyle::Custom)
+            .collect();
+        lint.md035.style = dominant(docs, lint.md035.style, &hr_styles, |style| {
+            Rule::MD035(rule::MD035::from(&MD035 {
+                style: style.clone(),
+            }))
+        })?;
+
+        let code_block_styles = [CodeBlockStyle::Fenced, CodeBlockStyle::Indented];
+        lint.md046.style = dominant(docs, lint.md046.style, &code_block_styles, |style| {
+            Rule::MD046(rule::MD046::from(&MD046 {
+                style: style.clone(),
+            }))
+        })?;
+
+        Ok(lint)
+    }
+
+    // Returns the shortest line length that few lines exceed
+    fn infer_line_length(docs: &[Document], md013: &MD013) -> Result<usize> {
+        let num_lines: usize = docs.iter().map(|doc| doc.lines.len()).sum();
+        let found = Self::LINE_LENGTHS
+            .into_iter()
+            .map(|line_length| {
+                let rule = Rule::MD013(rule::MD013::from(&MD013 {
+                    line_length,
+                    ..md013.clone()
+                }));
+                let fits = count(&rule, docs)? * 100 <= num_lines * Self::MAX_LONG_LINES_PERCENT;
+                Ok((line_length, fits))
+            })
+            .find(|result: &Result<_>| result.as_ref().map_or(true, |&(_, fits)| fits))
+            .transpose()?;
+        Ok(found.map_or(Self::MAX_LINE_LENGTH, |(line_length, _)| line_length))
+    }
+
+    // Returns inferred sections by names such as `md003`
+    fn sections(lint: &Lint) -> Result<Table> {
+        [
+            ("md003", Value::try_from(&lint.md003)),
+            ("md004", Value::try_from(&lint.md004)),
+            ("md007", Value::try_from(&lint.md007)),
+            ("md013", Value::try_from(&lint.md013)),
+            ("md029", Value::try_from(&lint.md029)),
+            ("md035", Value::try_from(&lint.md035)),
+            ("md046", Value::try_from(&lint.md046)),
+        ]
+        .into_iter()
+        .map(|(name, value)| Ok((name.to_owned(), value.into_diagnostic()?)))
+        .collect()
+    }
+
+    fn to_toml(lint: &Lint) -> Result<String> {
+        let mut table = Table::new();
+        table.insert("lint".to_owned(), Value::Table(Self::sections(lint)?));
+        toml::to_string(&table).into_diagnostic()
+    }
+
+    // Counts violations by rules with the configuration
+    fn count_violations(lint: &Lint, docs: &[Document]) -> Result<BTreeMap<String, usize>> {
+        let config = Config { lint: lint.clone() };
+        let linter = Linter::from_config(&config);
+        let mut counts = BTreeMap::new();
+        for doc in docs {
+            for violation in linter.check(doc)? {
+                *counts.entry(violation.name().to_owned()).or_default() += 1;
+            }
+        }
+        Ok(counts)
+    }
+}
+
+fn count(rule: &Rule, docs: &[Document]) -> Result<usize> {
+    let mut num_violations = 0;
+    for doc in docs {
+        num_violations += rule.check(doc)?.len();
+    }
+    Ok(num_violations)
+}
+
+// Returns the candidate with the fewest violations,
+// or the default if no candidates make a difference
+fn dominant<T: Clone>(
+    docs: &[Document],
+    default: T,
+    candidates: &[T],
+    to_rule: impl Fn(&T) -> Rule,
+) -> Result<T> {
+    let counts = candidates
+        .iter()
+        .map(|candidate| count(&to_rule(candidate), docs))
+        .collect::<Result<Vec<_>>>()?;
+    let (Some(min), Some(max)) = (counts.iter().min(), counts.iter().max()) else {
+        return Ok(default);
+    };
+    if candidates.len() > 1 && min == max {
+        return Ok(default);
+    }
+
+    let index = counts.iter().position(|count| count == min).unwrap_or(0);
+    Ok(candidates.get(index).cloned().unwrap_or(default))
+}
+
+// Returns distinct horizontal rules in order of appearance
+fn horizontal_rules(docs: &[Document]) -> Vec<String> {
+    let mut hrs: Vec<String> = vec![];
+    for doc in docs {
+        for node in doc.ast.descendants() {
+            if node.data.borrow().value == NodeValue::ThematicBreak {
+                let lineno = node.data.borrow().sourcepos.start.line;
+                if let Some(line) = doc.lines.get(lineno - 1) {
+                    if !hrs.contains(line) {
+                        hrs.push(line.clone());
+                    }
+                }
+            }
+        }
+    }
+    hrs
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    use super::*;
+
+    fn documents<'a>(arena: &'a Arena<AstNode<'a>>, texts: &[&str]) -> Result<Vec<Document<'a>>> {
+        texts
+            .iter()
+            .map(|text| Document::new(arena, PathBuf::from("test.md"), (*text).to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn infer() -> Result<()> {
+        let arena = Arena::new();
+        let text = indoc! {"
+            Title
+            =====
+
+            Section
+            -------
+
+            - Item
+              - Nested item
+
+            1. One
+            2. Two
+
+            ***
+
+                indented code
+
+            ***
+        "};
+        let other_text = indoc! {"
+            # Title #
+
+            - Item
+            + Item
+        "};
+        let docs = documents(&arena, &[text, other_text])?;
+        let actual = Initializer::infer(&docs)?;
+        let mut expected = Lint::default();
+        expected.md003.style = HeadingStyle::Setext;
+        expected.md004.style = ListStyle::Dash;
+        expected.md007.indent = 2;
+        expected.md029.style = OrderedListStyle::Ordered;
+        expected.md035.style = HorizontalRuleStyle::Custom("***".to_owned());
+        expected.md046.style = CodeBlockStyle::Indented;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn infer_empty() -> Result<()> {
+        let actual = Initializer::infer(&[])?;
+        assert_eq!(actual, Lint::default());
+        Ok(())
+    }
+
+    #[test]
+    fn infer_line_length() -> Result<()> {
+        let arena = Arena::new();
+        let long_line = format!("{} end\n", "a".repeat(95));
+        let docs = documents(&arena, &[&long_line])?;
+        let actual = Initializer::infer_line_length(&docs, &MD013::default())?;
+        assert_eq!(actual, 100);
+
+        let longer_line = format!("{} end\n", "a".repeat(150));
+        let longer_docs = documents(&arena, &[&longer_line])?;
+        let longer_actual = Initializer::infer_line_length(&longer_docs, &MD013::default())?;
+        assert_eq!(longer_actual, 120);
+        Ok(())
+    }
+
+    #[test]
+    fn horizontal_rules_in_containers() -> Result<()> {
+        let arena = Arena::new();
+        let text = indoc! {"
+            - Item
+
+              * * *
+
+            > - - -
+
+            ___
+        "};
+        let docs = documents(&arena, &[text])?;
+        let expected = vec!["  * * *", "> - - -", "___"];
+        assert_eq!(horizontal_rules(&docs), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn to_toml() -> Result<()> {
+        let actual = Initializer::to_toml(&Lint::default())?;
+        let expected = indoc! {r#"
+            [lint.md003]
+            style = "consistent"
+
+            [lint.md004]
+            style = "consistent"
+
+            [lint.md007]
+            indent = 4
+
+            [lint.md013]
+            code-blocks = true
+            line-length = 80
+            tables = true
+
+            [lint.md029]
+            style = "one"
+
+            [lint.md035]
+            style = "consistent"
+
+            [lint.md046]
+            style = "fenced"
+        "#};
+        assert_eq!(actual, expected);
+        let config: Config = toml::from_str(&actual).into_diagnostic()?;
+        assert_eq!(config.lint, Lint::default());
+        Ok(())
+    }
+
+    #[test]
+    fn init_exists() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let config_path = tmp_dir.path().join("mado.toml");
+        fs::write(&config_path, "").into_diagnostic()?;
+        let lint = Lint::default();
+        let initializer =
+            Initializer::new(&[tmp_dir.path().to_path_buf()], &config_path, &lint, true);
+        assert!(initializer.init().is_err());
+        tmp_dir.close().into_diagnostic()
+    }
+
+    #[test]
+    fn documents_with_config() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        for name in ["README.md", "notes.txt", "vendor.md"] {
+            fs::write(tmp_dir.path().join(name), "# Hello\n").into_diagnostic()?;
+        }
+        let lint = Lint {
+            extensions: vec!["md".to_owned(), "txt".to_owned()],
+            exclude: vec![globset::Glob::new("**/vendor.md").into_diagnostic()?],
+            ..Lint::default()
+        };
+        let config_path = tmp_dir.path().join("mado.toml");
+        let initializer =
+            Initializer::new(&[tmp_dir.path().to_path_buf()], &config_path, &lint, true);
+        let arena = Arena::new();
+        let mut names: Vec<_> = initializer
+            .documents(&arena)?
+            .iter()
+            .filter_map(|doc| {
+                doc.path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+            })
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["README.md", "notes.txt"]);
+        tmp_dir.close().into_diagnostic()
+    }
+}
@@ -0,0 +1,309 @@
+use core::fmt::{self, Display, Formatter, Write as _};
+use std::io::{self, BufWriter, Write as _};
+use std::process::ExitCode;
+
+use miette::{miette, IntoDiagnostic as _, Result};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::config::Lint;
+use crate::output::Format;
+use crate::rule::{Metadata, Tag};
+use crate::{Rule, Severity};
+
+/// Lists all rules with their effective configuration
+pub struct RuleLister {
+    rules: Vec<RuleInfo>,
+    output_format: Format,
+}
+
+/// Explains a rule with examples
+pub struct RuleExplainer {
+    rule: RuleInfo,
+    output_format: Format,
+}
+
+/// A rule with the effective configuration
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct RuleInfo {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    description: &'static str,
+    tags: &'static [Tag],
+    enabled: bool,
+    severity: Severity,
+    /// Options of the rule by kebab-case keys in the config file
+    options: Map<String, Value>,
+    rationale: &'static str,
+    examples: Examples,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct Examples {
+    bad: &'static str,
+    good: &'static str,
+}
+
+impl RuleLister {
+    #[inline]
+    pub fn new(lint: &Lint, output_format: Option<Format>) -> Result<Self> {
+        let rules = Rule::all()
+            .iter()
+            .map(|rule| RuleInfo::new(rule.metadata(), lint))
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            rules,
+            output_format: output_format.unwrap_or(Format::Concise),
+        })
+    }
+
+    #[inline]
+    pub fn list(&self) -> Result<ExitCode> {
+        let mut output = BufWriter::new(io::stdout().lock());
+        match self.output_format {
+            Format::Json => {
+                let json = serde_json::to_string_pretty(&self.rules).into_diagnostic()?;
+                writeln!(output, "{json}").into_diagnostic()?;
+            }
+            Format::JsonLines => {
+                for rule in &self.rules {
+                    let json = serde_json::to_string(rule).into_diagnostic()?;
+                    writeln!(output, "{json}").into_diagnostic()?;
+                }
+            }
+            _ => {
+                for rule in &self.rules {
+                    writeln!(output, "{}", rule.summary()).into_diagnostic()?;
+                }
+            }
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+impl RuleExplainer {
+    /// Finds a rule by the name or an alias case-insensitively
+    #[inline]
+    pub fn new(name: &str, lint: &Lint, output_format: Option<Format>) -> Result<Self> {
+        let rule = Rule::all()
+            .into_iter()
+            .find(|rule| {
+                let metadata = rule.metadata();
+                metadata.name.eq_ignore_ascii_case(name)
+                    || metadata
+                        .aliases
+                        .iter()
+                        .any(|alias| alias.eq_ignore_ascii_case(name))
+            })
+            .ok_or_else(|| miette!("Unknown rule: {name}"))?;
+        Ok(Self {
+            rule: RuleInfo::new(rule.metadata(), lint)?,
+            output_format: output_format.unwrap_or(Format::Concise),
+        })
+    }
+
+    #[inline]
+    pub fn explain(&self) -> Result<ExitCode> {
+        let mut output = BufWriter::new(io::stdout().lock());
+        match self.output_format {
+            Format::Json => {
+                let json = serde_json::to_string_pretty(&self.rule).into_diagnostic()?;
+                writeln!(output, "{json}").into_diagnostic()?;
+            }
+            Format::JsonLines => {
+                let json = serde_json::to_string(&self.rule).into_diagnostic()?;
+                writeln!(output, "{json}").into_diagnostic()?;
+            }
+            _ => write!(output, "{}", self.rule).into_diagnostic()?,
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+impl RuleInfo {
+    fn new(metadata: &'static Metadata, lint: &Lint) -> Result<Self> {
+        let enabled = lint
+            .to_rules()
+            .iter()
+            .any(|rule| rule.metadata().name == metadata.name);
+        let severity = lint.severity_of(metadata);
+
+        // Options are configured in `[lint.mdXXX]`
+        let lint_value = serde_json::to_value(lint).into_diagnostic()?;
+        let options = match lint_value.get(metadata.name.to_lowercase()) {
+            Some(Value::Object(options)) => options.clone(),
+            _ => Map::new(),
+        };
+
+        Ok(Self {
+            name: metadata.name,
+            aliases: metadata.aliases,
+            description: metadata.description,
+            tags: metadata.tags,
+            enabled: enabled && severity != Severity::Off,
+            severity,
+            options,
+            rationale: metadata.rationale,
+            examples: Examples {
+                bad: metadata.bad_example,
+                good: metadata.good_example,
+            },
+        })
+    }
+
+    fn title(&self) -> String {
+        let mut title = format!("{} ({})", self.name, self.aliases.join(", "));
+        if !self.enabled {
+            title.push_str(" [disabled]");
+        }
+        title
+    }
+
+    fn tags(&self) -> String {
+        self.tags
+            .iter()
+            .filter_map(|tag| match serde_json::to_value(tag) {
+                Ok(Value::String(tag)) => Some(tag),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn options(&self) -> Vec<String> {
+        self.options
+            .iter()
+            .map(|(key, value)| format!("{key} = {value}"))
+            .collect()
+    }
+
+    // Returns a few lines for the list of rules
+    fn summary(&self) -> String {
+        let mut summary = format!("{}\n  {}", self.title(), self.description);
+        // Writing to a string never fails
+        let _ = write!(summary, "\n  Tags: {}", self.tags());
+        let _ = write!(summary, "\n  Severity: {}", self.severity);
+        let options = self.options();
+        if !options.is_empty() {
+            let _ = write!(summary, "\n  Options: {}", options.join(", "));
+        }
+        summary
+    }
+}
+
+impl Display for RuleInfo {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}: {}", self.title(), self.description)?;
+        writeln!(f)?;
+        writeln!(f, "{}", self.rationale)?;
+        writeln!(f)?;
+        writeln!(f, "Tags: {}", self.tags())?;
+        writeln!(f, "Severity: {}", self.severity)?;
+        let options = self.options();
+        if !options.is_empty() {
+            writeln!(f, "Options:")?;
+            for option in options {
+                writeln!(f, "  {option}")?;
+            }
+        }
+        writeln!(f)?;
+        writeln!(f, "Bad:")?;
+        writeln!(f)?;
+        writeln!(f, "```markdown\n{}\n```", self.examples.bad.trim_end())?;
+        writeln!(f)?;
+        writeln!(f, "Good:")?;
+        writeln!(f)?;
+        writeln!(f, "```markdown\n{}\n```", self.examples.good.trim_end())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::collections::BTreeMap;
+
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use crate::config::lint::RuleSet;
+    use crate::rule::RuleLike as _;
+    use crate::rule::{MD001, MD013, MD026};
+
+    use super::*;
+
+    #[test]
+    fn rule_info_summary() -> Result<()> {
+        let lint = Lint::default();
+        let md013 = RuleInfo::new(MD013::default().metadata(), &lint)?;
+        let expected = indoc! {"
+            MD013 (line-length)
+              Line length
+              Tags: line-length
+              Severity: error
+              Options: code-blocks = true, line-length = 80, tables = true"};
+        assert_eq!(md013.summary(), expected);
+
+        let md001 = RuleInfo::new(MD001::new().metadata(), &lint)?;
+        let expected_md001 = indoc! {"
+            MD001 (header-increment)
+              Header levels should only increment by one level at a time
+              Tags: headers
+              Severity: error"};
+        assert_eq!(md001.summary(), expected_md001);
+        Ok(())
+    }
+
+    #[test]
+    fn rule_info_disabled() -> Result<()> {
+        let lint = Lint {
+            rules: vec![RuleSet::MD001],
+            severity: BTreeMap::from([("MD001".to_owned(), Severity::Off)]),
+            ..Lint::default()
+        };
+        let md001 = RuleInfo::new(MD001::new().metadata(), &lint)?;
+        assert!(!md001.enabled);
+        assert_eq!(md001.title(), "MD001 (header-increment) [disabled]");
+
+        let md026 = RuleInfo::new(MD026::default().metadata(), &lint)?;
+        assert!(!md026.enabled);
+        Ok(())
+    }
+
+    #[test]
+    fn rule_info_display_fmt() -> Result<()> {
+        let explainer = RuleExplainer::new("no-trailing-punctuation", &Lint::default(), None)?;
+        let expected = indoc! {r#"
+            MD026 (no-trailing-punctuation): Trailing punctuation in header
+
+            Headers are not sentences, so punctuation at the end is unnecessary. The punctuation characters can be configured with `punctuation`.
+
+            Tags: headers
+            Severity: error
+            Options:
+              punctuation = ".,;:!?"
+
+            Bad:
+
+            ```markdown
+            # This is a header.
+            ```
+
+            Good:
+
+            ```markdown
+            # This is a header
+            ```
+        "#};
+        assert_eq!(explainer.rule.to_string(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn rule_explainer_new_unknown() {
+        assert!(RuleExplainer::new("MD999", &Lint::default(), None).is_err());
+    }
+}
@@ -0,0 +1,775 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use comrak::nodes::{AstNode, NodeValue, Sourcepos};
+use comrak::Arena;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, RequestId, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidChangeWatchedFiles, DidCloseTextDocument, DidOpenTextDocument,
+    DidSaveTextDocument, Notification as LspNotification, PublishDiagnostics, ShowMessage,
+};
+use lsp_types::request::{CodeActionRequest, RegisterCapability, Request as _};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams,
+    CodeActionProviderCapability, Diagnostic, DiagnosticSeverity,
+    DidChangeWatchedFilesRegistrationOptions, FileSystemWatcher, GlobPattern, InitializeParams,
+    MessageType, NumberOrString, Position, PublishDiagnosticsParams, Range, Registration,
+    RegistrationParams, ServerCapabilities, ShowMessageParams, TextDocumentSyncCapability,
+    TextDocumentSyncKind, TextEdit, Url, WorkspaceEdit,
+};
+use miette::{IntoDiagnostic as _, Result};
+use rustc_hash::FxHashMap;
+
+use crate::service::{Linter, LinterResolver};
+use crate::{Config, Document, Severity, Violation};
+
+/// Language server speaking JSON-RPC over stdio
+pub struct Server {
+    config_path: Option<PathBuf>,
+}
+
+/// Open documents and linters of a running server
+struct Session {
+    config_path: Option<PathBuf>,
+    resolver: LinterResolver,
+    /// Texts of open Markdown documents
+    documents: FxHashMap<Url, String>,
+}
+
+impl Server {
+    /// Name of diagnostics in editors
+    const SOURCE: &str = "mado";
+
+    #[inline]
+    #[must_use]
+    pub const fn new(config_path: Option<PathBuf>) -> Self {
+        Self { config_path }
+    }
+
+    #[inline]
+    pub fn serve(&self) -> Result<ExitCode> {
+        let (connection, io_threads) = Connection::stdio();
+        let capabilities = serde_json::to_value(Self::capabilities()).into_diagnostic()?;
+        let params = connection.initialize(capabilities).into_diagnostic()?;
+        let params: InitializeParams = serde_json::from_value(params).into_diagnostic()?;
+
+        let can_watch_files = params
+            .capabilities
+            .workspace
+            .and_then(|workspace| workspace.did_change_watched_files)
+            .and_then(|capability| capability.dynamic_registration)
+            .unwrap_or(false);
+        if can_watch_files {
+            let request = Self::register_config_watchers()?;
+            connection
+                .sender
+                .send(Message::Request(request))
+                .into_diagnostic()?;
+        }
+
+        let (mut session, messages) = Session::new(self.config_path.clone());
+        for message in messages {
+            connection.sender.send(message).into_diagnostic()?;
+        }
+        session.run(&connection)?;
+
+        drop(connection);
+        io_threads.join().into_diagnostic()?;
+        Ok(ExitCode::SUCCESS)
+    }
+
+    fn capabilities() -> ServerCapabilities {
+        ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+            code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+            ..ServerCapabilities::default()
+        }
+    }
+
+    // Asks the client to notify changes of config files
+    fn register_config_watchers() -> Result<Request> {
+        let watchers = Config::file_names()
+            .map(|name| FileSystemWatcher {
+                glob_pattern: GlobPattern::String(format!("**/{name}")),
+                kind: None,
+            })
+            .collect();
+        let options = DidChangeWatchedFilesRegistrationOptions { watchers };
+        let params = RegistrationParams {
+            registrations: vec![Registration {
+                id: "mado/config-watchers".to_owned(),
+                method: DidChangeWatchedFiles::METHOD.to_owned(),
+                register_options: Some(serde_json::to_value(options).into_diagnostic()?),
+            }],
+        };
+        Ok(Request::new(
+            RequestId::from("mado/register-config-watchers".to_owned()),
+            RegisterCapability::METHOD.to_owned(),
+            params,
+        ))
+    }
+}
+
+impl Session {
+    // Starts with the default config showing the error if the config fails to load,
+    // and the config is loaded again when it is fixed
+    fn new(config_path: Option<PathBuf>) -> (Self, Vec<Message>) {
+        let (resolver, messages) = match Self::load_resolver(config_path.as_deref()) {
+            Ok((resolver, warnings)) => (
+                resolver,
+                warnings
+                    .iter()
+                    .map(|warning| show_warning(warning))
+                    .collect(),
+            ),
+            Err(err) => {
+                let linter = Linter::from_config(&Config::default());
+                (LinterResolver::from(linter), vec![show_error(&err)])
+            }
+        };
+        let session = Self {
+            config_path,
+            resolver,
+            documents: FxHashMap::default(),
+        };
+        (session, messages)
+    }
+
+    // Discovers config files for each document unless a config file is specified.
+    // Warnings of the config file are returned alongside the resolver.
+    fn load_resolver(config_path: Option<&Path>) -> Result<(LinterResolver, Vec<String>)> {
+        let (config, warnings) = match config_path {
+            Some(config_path) => Config::load(config_path)?,
+            None => Config::resolve()?,
+        };
+        Ok((LinterResolver::new(&config, config_path)?, warnings))
+    }
+
+    fn run(&mut self, connection: &Connection) -> Result<()> {
+        for message in &connection.receiver {
+            match message {
+                Message::Request(request) => {
+                    if connection.handle_shutdown(&request).into_diagnostic()? {
+                        return Ok(());
+                    }
+                    let response = self.handle_request(request);
+                    connection
+                        .sender
+                        .send(Message::Response(response))
+                        .into_diagnostic()?;
+                }
+                Message::Notification(notification) => {
+                    for reply in self.handle_notification(&notification) {
+                        connection.sender.send(reply).into_diagnostic()?;
+                    }
+                }
+                Message::Response(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_request(&self, request: Request) -> Response {
+        if request.method != CodeActionRequest::METHOD {
+            let message = format!("Unsupported request: {}", request.method);
+            return Response::new_err(request.id, ErrorCode::MethodNotFound as i32, message);
+        }
+        let Ok(params) = serde_json::from_value::<CodeActionParams>(request.params) else {
+            let message = "Invalid params".to_owned();
+            return Response::new_err(request.id, ErrorCode::InvalidParams as i32, message);
+        };
+
+        let actions = self.code_actions(&params);
+        Response::new_ok(request.id, actions)
+    }
+
+    // Returns messages to send
+    fn handle_notification(&mut self, notification: &Notification) -> Vec<Message> {
+        if let Some(params) = cast::<DidOpenTextDocument>(notification) {
+            let item = params.text_document;
+            if item.language_id != "markdown" {
+                return vec![];
+            }
+            self.documents.insert(item.uri.clone(), item.text);
+            return self.publish(&item.uri);
+        }
+
+        if let Some(params) = cast::<DidChangeTextDocument>(notification) {
+            let uri = params.text_document.uri;
+            // The whole text is sent since the sync kind is full
+            let Some(change) = params.content_changes.into_iter().last() else {
+                return vec![];
+            };
+            if !self.documents.contains_key(&uri) {
+                return vec![];
+            }
+            self.documents.insert(uri.clone(), change.text);
+            return self.publish(&uri);
+        }
+
+        if let Some(params) = cast::<DidCloseTextDocument>(notification) {
+            let uri = params.text_document.uri;
+            if self.documents.remove(&uri).is_none() {
+                return vec![];
+            }
+            let cleared = PublishDiagnosticsParams::new(uri, vec![], None);
+            return vec![notify::<PublishDiagnostics>(cleared)];
+        }
+
+        if let Some(params) = cast::<DidSaveTextDocument>(notification) {
+            if self.is_config(&params.text_document.uri) {
+                return self.reload();
+            }
+            return vec![];
+        }
+
+        if let Some(params) = cast::<DidChangeWatchedFiles>(notification) {
+            if params
+                .changes
+                .iter()
+                .any(|change| self.is_config(&change.uri))
+            {
+                return self.reload();
+            }
+        }
+
+        vec![]
+    }
+
+    fn is_config(&self, uri: &Url) -> bool {
+        let Ok(path) = uri.to_file_path() else {
+            return false;
+        };
+
+        self.config_path.as_ref().map_or_else(
+            || {
+                path.file_name()
+                    .is_some_and(|file_name| Config::file_names().any(|name| file_name == name))
+            },
+            |config_path| path.ends_with(config_path),
+        )
+    }
+
+    // Reloads config files and lints all open documents again
+    fn reload(&mut self) -> Vec<Message> {
+        let mut messages: Vec<_> = match Self::load_resolver(self.config_path.as_deref()) {
+            Ok((resolver, warnings)) => {
+                self.resolver = resolver;
+                warnings
+                    .iter()
+                    .map(|warning| show_warning(warning))
+                    .collect()
+            }
+            Err(err) => return vec![show_error(&err)],
+        };
+
+        let mut uris: Vec<_> = self.documents.keys().cloned().collect();
+        uris.sort();
+        messages.extend(uris.iter().flat_map(|uri| self.publish(uri)));
+        messages
+    }
+
+    // Lints the document and returns the diagnostics or an error message,
+    // following warnings of config files discovered for the document
+    fn publish(&mut self, uri: &Url) -> Vec<Message> {
+        let Some(text) = self.documents.get(uri).cloned() else {
+            return vec![];
+        };
+        let result = self.lint(uri, text.clone());
+        let mut messages: Vec<_> = self
+            .resolver
+            .take_warnings()
+            .iter()
+            .map(|warning| show_warning(warning))
+            .collect();
+        match result {
+            Ok(violations) => {
+                let diagnostics = to_diagnostics(&violations, &text);
+                let params = PublishDiagnosticsParams::new(uri.clone(), diagnostics, None);
+                messages.push(notify::<PublishDiagnostics>(params));
+            }
+            Err(err) => messages.push(show_error(&err)),
+        }
+        messages
+    }
+
+    fn lint(&mut self, uri: &Url, text: String) -> Result<Vec<Violation>> {
+        let path = uri
+            .to_file_path()
+            .unwrap_or_else(|()| PathBuf::from(uri.path()));
+        let linter = self.resolver.resolve(&path)?;
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let mut violations = linter.check(&doc)?;
+        violations.sort();
+        Ok(violations)
+    }
+
+    fn code_actions(&self, params: &CodeActionParams) -> Vec<CodeActionOrCommand> {
+        let uri = &params.text_document.uri;
+        let Some(text) = self.documents.get(uri) else {
+            return vec![];
+        };
+        let arena = Arena::new();
+        let Ok(doc) = Document::new(&arena, PathBuf::from(uri.path()), text.clone()) else {
+            return vec![];
+        };
+
+        params
+            .context
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.source.as_deref() == Some(Server::SOURCE))
+            .filter_map(|diagnostic| {
+                let Some(NumberOrString::String(name)) = &diagnostic.code else {
+                    return None;
+                };
+                let action = disable_rule_action(uri, &doc, diagnostic, name)?;
+                Some(CodeActionOrCommand::CodeAction(action))
+            })
+            .collect()
+    }
+}
+
+// Deserializes params if the method matches
+fn cast<N: LspNotification>(notification: &Notification) -> Option<N::Params> {
+    if notification.method != N::METHOD {
+        return None;
+    }
+    serde_json::from_value(notification.params.clone()).ok()
+}
+
+fn notify<N: LspNotification>(params: N::Params) -> Message {
+    Message::Notification(Notification::new(N::METHOD.to_owned(), params))
+}
+
+fn show_error(err: &miette::Report) -> Message {
+    let params = ShowMessageParams {
+        typ: MessageType::ERROR,
+        message: format!("mado: {err}"),
+    };
+    notify::<ShowMessage>(params)
+}
+
+fn show_warning(warning: &str) -> Message {
+    let params = ShowMessageParams {
+        typ: MessageType::WARNING,
+        message: format!("mado: {warning}"),
+    };
+    notify::<ShowMessage>(params)
+}
+
+fn to_diagnostics(violations: &[Violation], text: &str) -> Vec<Diagnostic> {
+    let lines: Vec<_> = text.lines().collect();
+    violations
+        .iter()
+        .map(|violation| Diagnostic {
+            range: to_range(violation.position(), &lines),
+            severity: Some(match violation.severity() {
+                Severity::Warning => DiagnosticSeverity::WARNING,
+                Severity::Info => DiagnosticSeverity::INFORMATION,
+                _ => DiagnosticSeverity::ERROR,
+            }),
+            code: Some(NumberOrString::String(violation.name().to_owned())),
+            source: Some(Server::SOURCE.to_owned()),
+            message: violation.description().to_owned(),
+            ..Diagnostic::default()
+        })
+        .collect()
+}
+
+// Sourcepos is 1-based and inclusive in bytes, while Range is 0-based and exclusive
+// in UTF-16 code units
+fn to_range(position: &Sourcepos, lines: &[&str]) -> Range {
+    Range {
+        start: to_position(
+            lines,
+            position.start.line,
+            position.start.column.saturating_sub(1),
+        ),
+        end: to_position(lines, position.end.line, position.end.column),
+    }
+}
+
+fn to_position(lines: &[&str], line: usize, byte_offset: usize) -> Position {
+    let line_index = line.saturating_sub(1);
+    let text = lines.get(line_index).copied().unwrap_or_default();
+    let mut offset = byte_offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let character = text[..offset].encode_utf16().count();
+    Position {
+        line: u32::try_from(line_index).unwrap_or(u32::MAX),
+        character: u32::try_from(character).unwrap_or(u32::MAX),
+    }
+}
+
+// Inserts `<!-- mado-disable-next-line MDXXX -->` above the block starting at the line of
+// the diagnostic, or wraps the block with `mado-disable` and `mado-enable` if the line is
+// inside the block such as a code block. Returns None if comments cannot be inserted.
+fn disable_rule_action(
+    uri: &Url,
+    doc: &Document,
+    diagnostic: &Diagnostic,
+    name: &str,
+) -> Option<CodeAction> {
+    let lineno = usize::try_from(diagnostic.range.start.line).ok()? + 1;
+    let lines: Vec<_> = doc.lines.iter().map(String::as_str).collect();
+    let block = enclosing_block(doc.ast, lineno);
+    let data = block.data.borrow();
+    let is_code_block = matches!(data.value, NodeValue::CodeBlock(_));
+
+    let (title, edits) = if is_container(&data.value) {
+        // Only blank lines are not in any leaf blocks except in lists
+        let line = lines.get(lineno - 1).copied().unwrap_or_default();
+        let is_blank = line.trim_matches([' ', '\t', '>']).is_empty();
+        if !is_blank || !matches!(data.value, NodeValue::Document | NodeValue::BlockQuote) {
+            return None;
+        }
+        let mut prefix = line.trim_end().to_owned();
+        if !prefix.is_empty() {
+            prefix.push(' ');
+        }
+        let comment = format!("{prefix}<!-- mado-disable-next-line {name} -->\n");
+        let position = to_position(&lines, lineno, 0);
+        let edit = TextEdit::new(Range::new(position, position), comment);
+        (format!("Disable {name} for this line"), vec![edit])
+    } else if data.sourcepos.start.line == lineno && !is_code_block {
+        let edit = insert_before(&lines, block, &format!("mado-disable-next-line {name}"));
+        (format!("Disable {name} for this line"), vec![edit])
+    } else {
+        let start_edit = insert_before(&lines, block, &format!("mado-disable {name}"));
+        // Trailing blank lines of indented code blocks end at column 0
+        let end = data.sourcepos.end;
+        let last_line = if end.column == 0 {
+            end.line.saturating_sub(1)
+        } else {
+            end.line
+        }
+        .max(data.sourcepos.start.line);
+        let end_position = to_position(&lines, last_line, usize::MAX);
+        let prefix = line_prefix(&lines, block);
+        let end_edit = TextEdit::new(
+            Range::new(end_position, end_position),
+            format!("\n{prefix}<!-- mado-enable {name} -->"),
+        );
+        (
+            format!("Disable {name} for this block"),
+            vec![start_edit, end_edit],
+        )
+    };
+
+    Some(CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic.clone()]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri.clone(), edits)])),
+            ..WorkspaceEdit::default()
+        }),
+        ..CodeAction::default()
+    })
+}
+
+// Returns the leaf block such as a paragraph or a code block containing the 1-based line,
+// or the innermost container if no leaf blocks contain the line
+fn enclosing_block<'a>(root: &'a AstNode<'a>, lineno: usize) -> &'a AstNode<'a> {
+    let mut node = root;
+    while is_container(&node.data.borrow().value) {
+        let child = node.children().find(|child| {
+            let data = child.data.borrow();
+            let position = data.sourcepos;
+            data.value.block() && position.start.line <= lineno && lineno <= position.end.line
+        });
+        let Some(child) = child else {
+            break;
+        };
+        node = child;
+    }
+
+    node
+}
+
+const fn is_container(value: &NodeValue) -> bool {
+    matches!(
+        value,
+        NodeValue::Document | NodeValue::BlockQuote | NodeValue::List(_) | NodeValue::Item(_)
+    )
+}
+
+// Inserts a comment before the block in the same container. Comments for blocks on the first
+// line of the first list item are inserted before the list, and comments for other items are
+// indented to the content of the list items not to split the list.
+fn insert_before(lines: &[&str], block: &AstNode<'_>, directive: &str) -> TextEdit {
+    let mut node = block;
+    while let Some(parent) = node.parent() {
+        let is_first = match parent.data.borrow().value {
+            NodeValue::List(_) => true,
+            NodeValue::Item(_) => parent.previous_sibling().is_none(),
+            _ => false,
+        };
+        if !is_first
+            || parent.data.borrow().sourcepos.start.line != node.data.borrow().sourcepos.start.line
+        {
+            break;
+        }
+        node = parent;
+    }
+
+    let lineno = node.data.borrow().sourcepos.start.line;
+    let position = to_position(lines, lineno, 0);
+    let prefix = line_prefix(lines, node);
+    TextEdit::new(
+        Range::new(position, position),
+        format!("{prefix}<!-- {directive} -->\n"),
+    )
+}
+
+// Returns blockquote markers and indentation before the node with list markers replaced by spaces
+fn line_prefix(lines: &[&str], node: &AstNode<'_>) -> String {
+    let data = node.data.borrow();
+    let mut column = data.sourcepos.start.column;
+    if let NodeValue::CodeBlock(code_block) = &data.value {
+        if !code_block.fenced {
+            // Indented code blocks start after the indentation
+            column = column.saturating_sub(4);
+        }
+    }
+
+    let line = lines
+        .get(data.sourcepos.start.line.saturating_sub(1))
+        .copied()
+        .unwrap_or_default();
+    line.get(..column.saturating_sub(1))
+        .unwrap_or_default()
+        .chars()
+        .map(|c| {
+            if matches!(c, ' ' | '\t' | '>') {
+                c
+            } else {
+                ' '
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    use crate::rule::{RuleLike as _, MD026};
+
+    use super::*;
+
+    #[test]
+    fn to_range_with_multibyte() {
+        let lines = ["# こんにちは。", "Text"];
+        let position = Sourcepos::from((1, 1, 1, 20));
+        let expected = Range::new(Position::new(0, 0), Position::new(0, 8));
+        assert_eq!(to_range(&position, &lines), expected);
+    }
+
+    #[test]
+    fn to_range_out_of_lines() {
+        let lines = ["Text"];
+        let position = Sourcepos::from((2, 1, 2, 1));
+        let expected = Range::new(Position::new(1, 0), Position::new(1, 0));
+        assert_eq!(to_range(&position, &lines), expected);
+    }
+
+    #[test]
+    fn to_diagnostics_from_violations() {
+        let text = "# Hello.\n";
+        let rule = MD026::default();
+        let violation = rule
+            .to_violation(PathBuf::from("test.md"), Sourcepos::from((1, 1, 1, 8)))
+            .with_severity(Severity::Warning);
+        let expected = vec![Diagnostic {
+            range: Range::new(Position::new(0, 0), Position::new(0, 8)),
+            severity: Some(DiagnosticSeverity::WARNING),
+            code: Some(NumberOrString::String("MD026".to_owned())),
+            source: Some("mado".to_owned()),
+            message: "Trailing punctuation in header".to_owned(),
+            ..Diagnostic::default()
+        }];
+        assert_eq!(to_diagnostics(&[violation], text), expected);
+    }
+
+    // Returns edits of the action for the diagnostic at the 0-based line
+    fn disable_rule_edits(text: &str, line: u32) -> Result<Option<Vec<TextEdit>>> {
+        let uri = Url::parse("file:///tmp/test.md").into_diagnostic()?;
+        let arena = Arena::new();
+        let doc = Document::new(&arena, PathBuf::from("test.md"), text.to_owned())?;
+        let diagnostic = Diagnostic {
+            range: Range::new(Position::new(line, 0), Position::new(line, 1)),
+            ..Diagnostic::default()
+        };
+        let action = disable_rule_action(&uri, &doc, &diagnostic, "MD013");
+        Ok(action
+            .and_then(|code_action| code_action.edit)
+            .and_then(|edit| edit.changes)
+            .and_then(|mut changes| changes.remove(&uri)))
+    }
+
+    fn insertion(line: u32, character: u32, new_text: &str) -> TextEdit {
+        let position = Position::new(line, character);
+        TextEdit::new(Range::new(position, position), new_text.to_owned())
+    }
+
+    #[test]
+    fn disable_rule_action_in_blockquote() -> Result<()> {
+        let uri = Url::parse("file:///tmp/test.md").into_diagnostic()?;
+        let arena = Arena::new();
+        let text = "Text\n\n> # Hello.\n".to_owned();
+        let doc = Document::new(&arena, PathBuf::from("test.md"), text)?;
+        let diagnostic = Diagnostic {
+            range: Range::new(Position::new(2, 2), Position::new(2, 10)),
+            ..Diagnostic::default()
+        };
+        let action = disable_rule_action(&uri, &doc, &diagnostic, "MD026");
+        let expected_edit = insertion(2, 0, "> <!-- mado-disable-next-line MD026 -->\n");
+        assert_eq!(
+            action
+                .as_ref()
+                .map(|code_action| code_action.title.as_str()),
+            Some("Disable MD026 for this line")
+        );
+        assert_eq!(
+            action
+                .and_then(|code_action| code_action.edit)
+                .and_then(|edit| edit.changes),
+            Some(HashMap::from([(uri, vec![expected_edit])]))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn disable_rule_action_in_lists() -> Result<()> {
+        // Indented to the content of the previous item not to split the list
+        let expected = vec![insertion(1, 0, "  <!-- mado-disable-next-line MD013 -->\n")];
+        assert_eq!(disable_rule_edits("- a\n- b\n", 1)?, Some(expected));
+
+        let quoted_text = "> 1. a\n>\n> 2. b\n";
+        let quoted_expected = vec![insertion(
+            2,
+            0,
+            ">    <!-- mado-disable-next-line MD013 -->\n",
+        )];
+        assert_eq!(disable_rule_edits(quoted_text, 2)?, Some(quoted_expected));
+        // Comments on blank lines would make the list loose
+        assert_eq!(disable_rule_edits(quoted_text, 1)?, None);
+
+        // Inserted before the list for the first item
+        let first_expected = vec![insertion(1, 0, "<!-- mado-disable-next-line MD013 -->\n")];
+        assert_eq!(
+            disable_rule_edits("Text\n- - a\n", 1)?,
+            Some(first_expected)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn disable_rule_action_in_code_blocks() -> Result<()> {
+        let fenced_expected = vec![
+            insertion(2, 0, "<!-- mado-disable MD013 -->\n"),
+            insertion(4, 3, "\n<!-- mado-enable MD013 -->"),
+        ];
+        assert_eq!(
+            disable_rule_edits("Text\n\n```\ncode\n```\n", 3)?,
+            Some(fenced_expected)
+        );
+
+        let indented_expected = vec![
+            insertion(2, 0, "  <!-- mado-disable MD013 -->\n"),
+            insertion(2, 10, "\n  <!-- mado-enable MD013 -->"),
+        ];
+        assert_eq!(
+            disable_rule_edits("- a\n\n      code\n\nText\n", 2)?,
+            Some(indented_expected)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn disable_rule_action_in_paragraph() -> Result<()> {
+        let expected = vec![
+            insertion(0, 0, "<!-- mado-disable MD013 -->\n"),
+            insertion(1, 1, "\n<!-- mado-enable MD013 -->"),
+        ];
+        assert_eq!(disable_rule_edits("a\nb\n", 1)?, Some(expected));
+
+        let blank_expected = vec![insertion(2, 0, "<!-- mado-disable-next-line MD013 -->\n")];
+        assert_eq!(disable_rule_edits("a\n\n\nb\n", 2)?, Some(blank_expected));
+        Ok(())
+    }
+
+    #[test]
+    fn disable_rule_action_in_front_matter() -> Result<()> {
+        assert_eq!(disable_rule_edits("---\ntitle: a\n---\n\nText\n", 1)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn session_new_with_invalid_config() {
+        let (_, messages) = Session::new(Some(PathBuf::from("missing.toml")));
+        let [Message::Notification(shown)] = messages.as_slice() else {
+            panic!("Unexpected messages: {messages:?}");
+        };
+        assert_eq!(shown.method, ShowMessage::METHOD);
+    }
+
+    #[test]
+    fn session_new_with_config_warnings() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let config_path = tmp_dir.path().join(".markdownlint.json");
+        fs::write(&config_path, "{\"MD011\": true}").into_diagnostic()?;
+        let (_, messages) = Session::new(Some(config_path.clone()));
+        let [Message::Notification(shown)] = messages.as_slice() else {
+            return Err(miette::miette!("Unexpected messages: {messages:?}"));
+        };
+        let params: ShowMessageParams =
+            serde_json::from_value(shown.params.clone()).into_diagnostic()?;
+        assert_eq!(params.typ, MessageType::WARNING);
+        let expected = format!(
+            "mado: {}: MD011 (no-reversed-links) is not supported yet",
+            config_path.display()
+        );
+        assert_eq!(params.message, expected);
+        tmp_dir.close().into_diagnostic()
+    }
+
+    #[test]
+    fn session_handle_notification() -> Result<()> {
+        let (mut session, _) = Session::new(Some(PathBuf::from("mado.toml")));
+        let uri = Url::parse("file:///tmp/test.md").into_diagnostic()?;
+        let opened = lsp_types::DidOpenTextDocumentParams {
+            text_document: lsp_types::TextDocumentItem::new(
+                uri.clone(),
+                "markdown".to_owned(),
+                1,
+                "# Hello.\n".to_owned(),
+            ),
+        };
+        let notification = Notification::new(DidOpenTextDocument::METHOD.to_owned(), opened);
+        let messages = session.handle_notification(&notification);
+        let [Message::Notification(published)] = messages.as_slice() else {
+            return Err(miette::miette!("Unexpected messages: {messages:?}"));
+        };
+        let params: PublishDiagnosticsParams =
+            serde_json::from_value(published.params.clone()).into_diagnostic()?;
+        assert_eq!(params.uri, uri);
+        let codes: Vec<_> = params
+            .diagnostics
+            .iter()
+            .filter_map(|diagnostic| diagnostic.code.clone())
+            .collect();
+        assert_eq!(codes, vec![NumberOrString::String("MD026".to_owned())]);
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
+use std::env;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use etcetera::choose_base_strategy;
 use etcetera::BaseStrategy as _;
@@ -7,13 +8,16 @@ use miette::miette;
 use miette::IntoDiagnostic as _;
 use miette::Result;
 use serde::Deserialize;
+use toml::{Table, Value};
 
 pub mod lint;
+mod markdownlint;
+mod mdl;
 
 pub use lint::Lint;
 use serde::Serialize;
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 #[serde(default)]
 #[allow(clippy::exhaustive_structs)]
 pub struct Config {
@@ -21,37 +25,127 @@ pub struct Config {
 }
 
 impl Config {
-    const FILE_NAME: &str = "mado.toml";
+    pub const FILE_NAME: &str = "mado.toml";
     const HIDDEN_FILE_NAME: &str = ".mado.toml";
+    /// Names of config files in order of precedence
+    pub const FILE_NAMES: [&str; 2] = [Self::FILE_NAME, Self::HIDDEN_FILE_NAME];
+    /// Names of markdownlint config files, used if no mado config file exists in the directory
+    pub const MARKDOWNLINT_FILE_NAMES: [&str; 6] = markdownlint::FILE_NAMES;
+    /// Names of Ruby mdl config files, used if no mado or markdownlint config file exists
+    pub const MDL_FILE_NAMES: [&str; 1] = [mdl::FILE_NAME];
 
+    /// Loads a config file, merging config files specified by `extends`.
+    /// markdownlint config files such as `.markdownlint.json` and Ruby mdl config files
+    /// such as `.mdlrc` are converted, and warnings of settings mado does not support yet
+    /// are returned alongside the config.
     #[inline]
-    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<(Self, Vec<String>)> {
+        let mut warnings = vec![];
+        let table = Self::load_table(path.as_ref(), &mut vec![], &mut warnings)?;
+        let config = table.try_into().map_err(|err| miette!(err))?;
+        Ok((config, warnings))
+    }
+
+    fn load_table(
+        path: &Path,
+        visited: &mut Vec<PathBuf>,
+        warnings: &mut Vec<String>,
+    ) -> Result<Table> {
+        let canonical_path = fs::canonicalize(path)
+            .map_err(|err| miette!("Failed to load {}: {err}", path.display()))?;
+        if visited.contains(&canonical_path) {
+            return Err(miette!("Circular extends in {}", path.display()));
+        }
+        visited.push(canonical_path);
+
+        if markdownlint::is_markdownlint(path) {
+            return markdownlint::load(path, visited, warnings);
+        }
+        if mdl::is_mdl(path) {
+            return mdl::load(path);
+        }
+
         let config_text = fs::read_to_string(path).into_diagnostic()?;
-        toml::from_str(&config_text).map_err(|err| miette!(err))
+        let mut table: Table = toml::from_str(&config_text).map_err(|err| miette!(err))?;
+        match table.remove("extends") {
+            Some(Value::String(extends)) => {
+                let base_dir = path.parent().unwrap_or_else(|| Path::new(""));
+                let mut base = Self::load_table(&base_dir.join(extends), visited, warnings)?;
+                Self::merge(&mut base, table);
+                Ok(base)
+            }
+            Some(_) => Err(miette!("extends must be a string in {}", path.display())),
+            None => Ok(table),
+        }
     }
 
+    /// Returns canonical paths of the config file and config files specified by `extends`.
+    /// Files loaded before an error are also returned to reload them after fixes.
     #[inline]
-    pub fn resolve() -> Result<Self> {
-        let local_path = Path::new(Self::FILE_NAME);
-        let exists_local = fs::exists(local_path).into_diagnostic()?;
-        if exists_local {
-            return Self::load(local_path);
+    #[must_use]
+    pub fn files<P: AsRef<Path>>(path: P) -> Vec<PathBuf> {
+        let mut visited = vec![];
+        let _result = Self::load_table(path.as_ref(), &mut visited, &mut vec![]);
+        visited
+    }
+
+    // Merges tables recursively. Other values such as arrays are replaced.
+    fn merge(base: &mut Table, table: Table) {
+        for (key, value) in table {
+            match (base.get_mut(&key), value) {
+                (Some(Value::Table(base_table)), Value::Table(child_table)) => {
+                    Self::merge(base_table, child_table);
+                }
+                (_, value) => {
+                    base.insert(key, value);
+                }
+            }
         }
+    }
+
+    /// Returns names of all config files searched in a directory in order of precedence
+    #[inline]
+    pub fn file_names() -> impl Iterator<Item = &'static str> {
+        Self::FILE_NAMES
+            .into_iter()
+            .chain(Self::MARKDOWNLINT_FILE_NAMES)
+            .chain(Self::MDL_FILE_NAMES)
+    }
 
-        let hidden_local_path = Path::new(Self::HIDDEN_FILE_NAME);
-        let exists_hidden_local = fs::exists(hidden_local_path).into_diagnostic()?;
-        if exists_hidden_local {
-            return Self::load(hidden_local_path);
+    /// Returns the path of the config file in the directory if exists
+    #[inline]
+    pub fn find<P: AsRef<Path>>(dir: P) -> Result<Option<PathBuf>> {
+        for name in Self::file_names() {
+            let path = dir.as_ref().join(name);
+            if fs::exists(&path).into_diagnostic()? {
+                return Ok(Some(path));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Loads the config file returned by `resolve_path`, or the default config
+    #[inline]
+    pub fn resolve() -> Result<(Self, Vec<String>)> {
+        Self::resolve_path()?.map_or_else(|| Ok((Self::default(), vec![])), Self::load)
+    }
+
+    /// Returns the path of the nearest config file from the current directory,
+    /// or the user config file if exists
+    #[inline]
+    pub fn resolve_path() -> Result<Option<PathBuf>> {
+        let current_dir = env::current_dir().into_diagnostic()?;
+        for dir in current_dir.ancestors() {
+            if let Some(path) = Self::find(dir)? {
+                return Ok(Some(path));
+            }
         }
 
         let strategy = choose_base_strategy().into_diagnostic()?;
         let config_path = strategy.config_dir().join("mado").join(Self::FILE_NAME);
         let exists_config = fs::exists(&config_path).into_diagnostic()?;
-        if exists_config {
-            return Self::load(&config_path);
-        }
-
-        Ok(Self::default())
+        Ok(exists_config.then_some(config_path))
     }
 }
 
@@ -60,22 +154,141 @@ mod tests {
     use super::*;
 
     use crate::output::Format;
+    use crate::rule::md003::HeadingStyle;
     use indoc::indoc;
     use lint::{RuleSet, MD002};
     use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
 
     #[test]
     fn load() -> Result<()> {
         let path = Path::new("mado.toml");
-        let actual = Config::load(path)?;
+        let (actual, warnings) = Config::load(path)?;
         let mut expected = Config::default();
         expected.lint.md013.code_blocks = false;
         expected.lint.md013.tables = false;
         expected.lint.md024.allow_different_nesting = true;
         assert_eq!(actual, expected);
+        assert!(warnings.is_empty());
         Ok(())
     }
 
+    #[test]
+    fn load_extends() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let base_text = indoc! {r#"
+            [lint]
+            quiet = true
+            rules = ["MD013", "MD026"]
+
+            [lint.md013]
+            line-length = 100
+            tables = false
+        "#};
+        fs::write(tmp_dir.path().join("mado.toml"), base_text).into_diagnostic()?;
+        let package_dir = tmp_dir.path().join("package");
+        fs::create_dir(&package_dir).into_diagnostic()?;
+        let text = indoc! {r#"
+            extends = "../mado.toml"
+
+            [lint]
+            rules = ["MD013"]
+
+            [lint.md013]
+            line-length = 120
+        "#};
+        let path = package_dir.join(".mado.toml");
+        fs::write(&path, text).into_diagnostic()?;
+
+        let (actual, _) = Config::load(&path)?;
+        let mut expected = Config::default();
+        expected.lint.quiet = true;
+        expected.lint.rules = vec![RuleSet::MD013];
+        expected.lint.md013.line_length = 120;
+        expected.lint.md013.tables = false;
+        assert_eq!(actual, expected);
+
+        let files = vec![
+            fs::canonicalize(&path).into_diagnostic()?,
+            fs::canonicalize(tmp_dir.path().join("mado.toml")).into_diagnostic()?,
+        ];
+        assert_eq!(Config::files(&path), files);
+        tmp_dir.close().into_diagnostic()
+    }
+
+    #[test]
+    fn load_circular_extends() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let path1 = tmp_dir.path().join("mado.toml");
+        let path2 = tmp_dir.path().join(".mado.toml");
+        fs::write(&path1, "extends = \".mado.toml\"").into_diagnostic()?;
+        fs::write(&path2, "extends = \"mado.toml\"").into_diagnostic()?;
+        assert!(Config::load(&path1).is_err());
+        tmp_dir.close().into_diagnostic()
+    }
+
+    #[test]
+    fn find() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        assert_eq!(Config::find(tmp_dir.path())?, None);
+
+        let hidden_path = tmp_dir.path().join(".mado.toml");
+        fs::write(&hidden_path, "").into_diagnostic()?;
+        assert_eq!(Config::find(tmp_dir.path())?, Some(hidden_path));
+
+        let path = tmp_dir.path().join("mado.toml");
+        fs::write(&path, "").into_diagnostic()?;
+        assert_eq!(Config::find(tmp_dir.path())?, Some(path));
+        tmp_dir.close().into_diagnostic()
+    }
+
+    #[test]
+    fn find_markdownlint() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let markdownlint_path = tmp_dir.path().join(".markdownlint.json");
+        fs::write(&markdownlint_path, "{}").into_diagnostic()?;
+        assert_eq!(Config::find(tmp_dir.path())?, Some(markdownlint_path));
+
+        let path = tmp_dir.path().join(".mado.toml");
+        fs::write(&path, "").into_diagnostic()?;
+        assert_eq!(Config::find(tmp_dir.path())?, Some(path));
+        tmp_dir.close().into_diagnostic()
+    }
+
+    #[test]
+    fn find_mdl() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let mdl_path = tmp_dir.path().join(".mdlrc");
+        fs::write(&mdl_path, "").into_diagnostic()?;
+        assert_eq!(Config::find(tmp_dir.path())?, Some(mdl_path));
+
+        let path = tmp_dir.path().join(".markdownlint.yaml");
+        fs::write(&path, "").into_diagnostic()?;
+        assert_eq!(Config::find(tmp_dir.path())?, Some(path));
+        tmp_dir.close().into_diagnostic()
+    }
+
+    #[test]
+    fn load_markdownlint() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let text = indoc! {r#"
+            {
+              "default": true,
+              "MD003": { "style": "setext_with_atx" },
+              "no-bare-urls": false
+            }
+        "#};
+        let path = tmp_dir.path().join(".markdownlint.json");
+        fs::write(&path, text).into_diagnostic()?;
+
+        let (actual, _) = Config::load(&path)?;
+        let mut expected = Config::default();
+        expected.lint.rules.retain(|rule| *rule != RuleSet::MD034);
+        expected.lint.md003.style = HeadingStyle::SetextWithAtx;
+        assert_eq!(actual, expected);
+        tmp_dir.close().into_diagnostic()
+    }
+
     #[test]
     fn resolve() -> Result<()> {
         let actual = Config::resolve()?;
@@ -97,7 +310,7 @@ mod tests {
         "#};
         let actual: Config = toml::from_str(text).into_diagnostic()?;
         let mut expected = Config::default();
-        expected.lint.output_format = Format::Mdl;
+        expected.lint.output_format = Some(Format::Mdl);
         expected.lint.rules = vec![RuleSet::MD027];
         expected.lint.md002 = MD002 { level: 2 };
         assert_eq!(actual, expected);
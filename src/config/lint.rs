@@ -1,8 +1,15 @@
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use core::result;
+
 use globset::{Glob, GlobSet, GlobSetBuilder};
 use miette::{IntoDiagnostic as _, Result};
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
 
-use crate::{output::Format, rule, rule::Rule};
+use crate::directive::Directive;
+use crate::rule::Metadata;
+use crate::{output::Format, rule, rule::Rule, Severity};
 
 mod md002;
 mod md003;
@@ -18,7 +25,13 @@ mod md033;
 mod md035;
 mod md036;
 mod md041;
+mod md045;
 mod md046;
+mod md048;
+mod md049;
+mod md050;
+mod md051;
+mod overrides;
 
 pub use md002::MD002;
 pub use md003::MD003;
@@ -34,18 +47,39 @@ pub use md033::MD033;
 pub use md035::MD035;
 pub use md036::MD036;
 pub use md041::MD041;
+pub use md045::MD045;
 pub use md046::MD046;
+pub use md048::MD048;
+pub use md049::MD049;
+pub use md050::MD050;
+pub use md051::MD051;
+pub use overrides::Override;
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(default, rename_all = "kebab-case")]
 #[allow(clippy::exhaustive_structs)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Lint {
     pub respect_ignore: bool,
     pub respect_gitignore: bool,
-    pub output_format: Format,
+    /// Format of violations. Unset means `concise`, or `github` on GitHub Actions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_format: Option<Format>,
     pub quiet: bool,
+    pub fix: bool,
+    pub report_unused_directives: bool,
+    /// Extensions of Markdown files to lint such as `md`
+    pub extensions: Vec<String>,
+    /// Patterns of files to lint regardless of extensions
+    pub include: Vec<Glob>,
     pub exclude: Vec<Glob>,
     pub rules: Vec<RuleSet>,
+    /// Severities of rules by names or aliases such as `MD013 = "warning"`
+    #[serde(deserialize_with = "deserialize_severity")]
+    pub severity: BTreeMap<String, Severity>,
+    /// Maximum number of warnings allowed before failing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_warnings: Option<usize>,
     pub md002: MD002,
     pub md003: MD003,
     pub md004: MD004,
@@ -60,25 +94,89 @@ pub struct Lint {
     pub md035: MD035,
     pub md036: MD036,
     pub md041: MD041,
+    pub md045: MD045,
     pub md046: MD046,
+    pub md048: MD048,
+    pub md049: MD049,
+    pub md050: MD050,
+    pub md051: MD051,
+    pub overrides: Vec<Override>,
 }
 
 impl Lint {
+    #[inline]
+    pub fn include_set(&self) -> Result<GlobSet> {
+        Self::glob_set(&self.include)
+    }
+
     #[inline]
     pub fn exclude_set(&self) -> Result<GlobSet> {
+        Self::glob_set(&self.exclude)
+    }
+
+    fn glob_set(globs: &[Glob]) -> Result<GlobSet> {
         let mut builder = GlobSetBuilder::new();
-        for glob in &self.exclude {
+        for glob in globs {
             builder.add(glob.clone());
         }
         builder.build().into_diagnostic()
     }
 
+    /// Returns the output format, which is `concise` if unset
+    #[inline]
+    #[must_use]
+    pub const fn output_format(&self) -> &Format {
+        match &self.output_format {
+            Some(format) => format,
+            None => &Format::Concise,
+        }
+    }
+
+    /// Returns true if edits of violations are needed to fix them or to report fixable ones
+    #[inline]
+    #[must_use]
+    pub const fn needs_edits(&self) -> bool {
+        self.fix || self.output_format().reports_fixable()
+    }
+
+    /// Returns the lint configuration with overrides of the given indices applied in order
+    #[inline]
+    pub fn with_overrides(&self, indices: &[usize]) -> Result<Self> {
+        let mut lint = self.clone();
+        lint.overrides = vec![];
+        for overrides in indices.iter().filter_map(|&i| self.overrides.get(i)) {
+            lint = overrides.apply(&lint)?;
+        }
+        Ok(lint)
+    }
+
+    /// Returns the severity of the rule. Names and aliases are case-insensitive.
+    #[inline]
+    #[must_use]
+    pub fn severity_of(&self, metadata: &Metadata) -> Severity {
+        self.severity
+            .iter()
+            .find(|(key, _)| {
+                key.eq_ignore_ascii_case(metadata.name)
+                    || metadata
+                        .aliases
+                        .iter()
+                        .any(|alias| key.eq_ignore_ascii_case(alias))
+            })
+            .map_or(Severity::Error, |(_, &severity)| severity)
+    }
+
     fn flatten_rules(&self) -> Vec<RuleSet> {
-        let mut flatten: Vec<_> = self
-            .rules
+        Self::flatten(&self.rules)
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn flatten(rules: &[RuleSet]) -> Vec<RuleSet> {
+        let mut flatten: Vec<_> = rules
             .iter()
             .flat_map(|rule| match rule {
                 // TODO: Use rule::Metadata#tags
+                RuleSet::Accessibility | RuleSet::Images => vec![RuleSet::MD045],
                 RuleSet::Atx => vec![RuleSet::MD018, RuleSet::MD019],
                 RuleSet::AtxClosed => vec![RuleSet::MD020, RuleSet::MD021],
                 RuleSet::BlankLines => vec![
@@ -102,8 +200,14 @@ impl Lint {
                     RuleSet::MD038,
                     RuleSet::MD040,
                     RuleSet::MD046,
+                    RuleSet::MD048,
+                ],
+                RuleSet::Emphasis => vec![
+                    RuleSet::MD036,
+                    RuleSet::MD037,
+                    RuleSet::MD049,
+                    RuleSet::MD050,
                 ],
-                RuleSet::Emphasis => vec![RuleSet::MD036, RuleSet::MD037],
                 RuleSet::HardTab => vec![RuleSet::MD010],
                 RuleSet::Headers => vec![
                     RuleSet::MD001,
@@ -131,7 +235,12 @@ impl Lint {
                 ],
                 RuleSet::Language => vec![RuleSet::MD040],
                 RuleSet::LineLength => vec![RuleSet::MD013],
-                RuleSet::Links => vec![RuleSet::MD034, RuleSet::MD039],
+                RuleSet::Links => vec![
+                    RuleSet::MD034,
+                    RuleSet::MD039,
+                    RuleSet::MD042,
+                    RuleSet::MD051,
+                ],
                 RuleSet::Ol => vec![RuleSet::MD029, RuleSet::MD030, RuleSet::MD032],
                 RuleSet::Spaces => vec![
                     RuleSet::MD018,
@@ -208,8 +317,16 @@ pub enum RuleSet {
     MD039,
     MD040,
     MD041,
+    MD042,
+    MD045,
     MD046,
     MD047,
+    MD048,
+    MD049,
+    MD050,
+    MD051,
+    #[serde(rename = "accessibility")]
+    Accessibility,
     #[serde(rename = "atx")]
     Atx,
     #[serde(rename = "atx-closed")]
@@ -232,6 +349,8 @@ pub enum RuleSet {
     Hr,
     #[serde(rename = "html")]
     Html,
+    #[serde(rename = "images")]
+    Images,
     #[serde(rename = "indentation")]
     Indentation,
     #[serde(rename = "language")]
@@ -252,14 +371,54 @@ pub enum RuleSet {
     Whitespace,
 }
 
+// Rejects unknown rules as `rules` does, and rules specified by both names and aliases
+fn deserialize_severity<'de, D>(
+    deserializer: D,
+) -> result::Result<BTreeMap<String, Severity>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let severity = BTreeMap::<String, Severity>::deserialize(deserializer)?;
+    let catalogue: Vec<_> = Rule::all()
+        .iter()
+        .map(Rule::metadata)
+        .chain([&Directive::UNUSED_METADATA])
+        .collect();
+    let mut keys_by_name = BTreeMap::new();
+    for key in severity.keys() {
+        let metadata = catalogue
+            .iter()
+            .find(|metadata| {
+                key.eq_ignore_ascii_case(metadata.name)
+                    || metadata
+                        .aliases
+                        .iter()
+                        .any(|alias| key.eq_ignore_ascii_case(alias))
+            })
+            .ok_or_else(|| de::Error::custom(format!("unknown rule `{key}` in lint.severity")))?;
+        if let Some(other_key) = keys_by_name.insert(metadata.name, key) {
+            return Err(de::Error::custom(format!(
+                "`{other_key}` and `{key}` in lint.severity are the same rule {}",
+                metadata.name
+            )));
+        }
+    }
+
+    Ok(severity)
+}
+
 impl Default for Lint {
     #[inline]
     fn default() -> Self {
         Self {
             respect_ignore: true,
             respect_gitignore: true,
-            output_format: Format::Concise,
+            output_format: None,
             quiet: false,
+            fix: false,
+            report_unused_directives: false,
+            extensions: vec!["md".to_owned()],
+            include: vec![],
             exclude: vec![],
             rules: vec![
                 RuleSet::MD001,
@@ -298,9 +457,17 @@ impl Default for Lint {
                 RuleSet::MD039,
                 RuleSet::MD040,
                 RuleSet::MD041,
+                RuleSet::MD042,
+                RuleSet::MD045,
                 RuleSet::MD046,
                 RuleSet::MD047,
+                RuleSet::MD048,
+                RuleSet::MD049,
+                RuleSet::MD050,
+                RuleSet::MD051,
             ],
+            severity: BTreeMap::new(),
+            max_warnings: None,
             md002: MD002::default(),
             md003: MD003::default(),
             md004: MD004::default(),
@@ -315,7 +482,13 @@ impl Default for Lint {
             md035: MD035::default(),
             md036: MD036::default(),
             md041: MD041::default(),
+            md045: MD045::default(),
             md046: MD046::default(),
+            md048: MD048::default(),
+            md049: MD049::default(),
+            md050: MD050::default(),
+            md051: MD051::default(),
+            overrides: vec![],
         }
     }
 }
@@ -363,8 +536,14 @@ impl Lint {
                 RuleSet::MD039 => Rule::MD039(rule::MD039::new()),
                 RuleSet::MD040 => Rule::MD040(rule::MD040::new()),
                 RuleSet::MD041 => Rule::MD041(rule::MD041::from(&self.md041)),
+                RuleSet::MD042 => Rule::MD042(rule::MD042::new()),
+                RuleSet::MD045 => Rule::MD045(rule::MD045::from(&self.md045)),
                 RuleSet::MD046 => Rule::MD046(rule::MD046::from(&self.md046)),
                 RuleSet::MD047 => Rule::MD047(rule::MD047::new()),
+                RuleSet::MD048 => Rule::MD048(rule::MD048::from(&self.md048)),
+                RuleSet::MD049 => Rule::MD049(rule::MD049::from(&self.md049)),
+                RuleSet::MD050 => Rule::MD050(rule::MD050::from(&self.md050)),
+                RuleSet::MD051 => Rule::MD051(rule::MD051::from(&self.md051)),
                 _ => unreachable!("tags are flatten"),
             })
             .collect()
@@ -397,6 +576,43 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn severity_of() {
+        let lint = Lint {
+            severity: BTreeMap::from([
+                ("md013".to_owned(), Severity::Warning),
+                ("No-Trailing-Punctuation".to_owned(), Severity::Off),
+            ]),
+            ..Lint::default()
+        };
+        let md013 = Rule::MD013(rule::MD013::default());
+        let md026 = Rule::MD026(rule::MD026::default());
+        let md001 = Rule::MD001(rule::MD001::new());
+        assert_eq!(lint.severity_of(md013.metadata()), Severity::Warning);
+        assert_eq!(lint.severity_of(md026.metadata()), Severity::Off);
+        assert_eq!(lint.severity_of(md001.metadata()), Severity::Error);
+    }
+
+    #[test]
+    fn deserialize_severity() -> Result<()> {
+        let text = "[severity]\nMD013 = \"warning\"\nno-trailing-punctuation = \"off\"\n";
+        let lint: Lint = toml::from_str(text).into_diagnostic()?;
+        assert_eq!(lint.severity.len(), 2);
+
+        let unknown = toml::from_str::<Lint>("[severity]\nMD999 = \"warning\"\n");
+        assert!(unknown.is_err_and(|err| err.message() == "unknown rule `MD999` in lint.severity"));
+
+        let duplicate =
+            toml::from_str::<Lint>("[severity]\nMD013 = \"warning\"\nline-length = \"off\"\n");
+        assert!(duplicate.is_err_and(|err| err.message()
+            == "`MD013` and `line-length` in lint.severity are the same rule MD013"));
+
+        let duplicate_case =
+            toml::from_str::<Lint>("[severity]\nMD013 = \"warning\"\nmd013 = \"off\"\n");
+        assert!(duplicate_case.is_err());
+        Ok(())
+    }
+
     #[test]
     fn from_lint_for_vec_rule() {
         let config = Lint::default();
@@ -437,8 +653,14 @@ mod tests {
             Rule::MD039(rule::MD039::new()),
             Rule::MD040(rule::MD040::new()),
             Rule::MD041(rule::MD041::default()),
+            Rule::MD042(rule::MD042::new()),
+            Rule::MD045(rule::MD045::default()),
             Rule::MD046(rule::MD046::default()),
             Rule::MD047(rule::MD047::new()),
+            Rule::MD048(rule::MD048::default()),
+            Rule::MD049(rule::MD049::default()),
+            Rule::MD050(rule::MD050::default()),
+            Rule::MD051(rule::MD051::default()),
         ];
         assert_eq!(config.to_rules(), expected);
     }
@@ -484,8 +706,15 @@ mod tests {
                 RuleSet::MD039,
                 RuleSet::MD040,
                 RuleSet::MD041,
+                RuleSet::MD042,
+                RuleSet::MD045,
                 RuleSet::MD046,
                 RuleSet::MD047,
+                RuleSet::MD048,
+                RuleSet::MD049,
+                RuleSet::MD050,
+                RuleSet::MD051,
+                RuleSet::Accessibility,
                 RuleSet::Atx,
                 RuleSet::AtxClosed,
                 RuleSet::BlankLines,
@@ -497,6 +726,7 @@ mod tests {
                 RuleSet::Headers,
                 RuleSet::Hr,
                 RuleSet::Html,
+                RuleSet::Images,
                 RuleSet::Indentation,
                 RuleSet::Language,
                 RuleSet::LineLength,
@@ -546,8 +776,14 @@ mod tests {
             Rule::MD039(rule::MD039::new()),
             Rule::MD040(rule::MD040::new()),
             Rule::MD041(rule::MD041::default()),
+            Rule::MD042(rule::MD042::new()),
+            Rule::MD045(rule::MD045::default()),
             Rule::MD046(rule::MD046::default()),
             Rule::MD047(rule::MD047::new()),
+            Rule::MD048(rule::MD048::default()),
+            Rule::MD049(rule::MD049::default()),
+            Rule::MD050(rule::MD050::default()),
+            Rule::MD051(rule::MD051::default()),
         ];
         assert_eq!(config.to_rules(), expected);
     }
@@ -555,6 +791,7 @@ mod tests {
     #[test]
     fn from_lint_for_vec_rule_tag_association() {
         let ruleset_list = vec![
+            (RuleSet::Accessibility, Tag::Accessibility),
             (RuleSet::Atx, Tag::Atx),
             (RuleSet::AtxClosed, Tag::AtxClosed),
             (RuleSet::BlankLines, Tag::BlankLines),
@@ -566,6 +803,7 @@ mod tests {
             (RuleSet::Headers, Tag::Headers),
             (RuleSet::Hr, Tag::Hr),
             (RuleSet::Html, Tag::Html),
+            (RuleSet::Images, Tag::Images),
             (RuleSet::Indentation, Tag::Indentation),
             (RuleSet::Language, Tag::Language),
             (RuleSet::LineLength, Tag::LineLength),
@@ -49,6 +49,18 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn serialize_for_horizontal_rule_style() -> Result<()> {
+        let config = MD035 {
+            style: HorizontalRuleStyle::Custom("***".to_owned()),
+        };
+        let text = toml::to_string(&config).into_diagnostic()?;
+        assert_eq!(text, "style = \"***\"\n");
+        let consistent_text = toml::to_string(&MD035::default()).into_diagnostic()?;
+        assert_eq!(consistent_text, "style = \"consistent\"\n");
+        Ok(())
+    }
+
     #[test]
     fn from_for_rule_md035() {
         let style = HorizontalRuleStyle::Custom("~~~".to_owned());
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rule;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+#[allow(clippy::exhaustive_structs)]
+pub struct MD045 {
+    pub reject_file_name: bool,
+}
+
+impl From<&MD045> for rule::MD045 {
+    #[inline]
+    fn from(config: &MD045) -> Self {
+        Self::new(config.reject_file_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn from_for_rule_md045() {
+        let reject_file_name = true;
+        let config = MD045 { reject_file_name };
+        let expected = rule::MD045::new(reject_file_name);
+        assert_eq!(rule::MD045::from(&config), expected);
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rule;
+use crate::rule::md048::CodeFenceStyle;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+#[allow(clippy::exhaustive_structs)]
+pub struct MD048 {
+    pub style: CodeFenceStyle,
+    pub min_length: usize,
+}
+
+impl Default for MD048 {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            style: rule::MD048::DEFAULT_STYLE,
+            min_length: rule::MD048::DEFAULT_MIN_LENGTH,
+        }
+    }
+}
+
+impl From<&MD048> for rule::MD048 {
+    #[inline]
+    fn from(config: &MD048) -> Self {
+        Self::new(config.style, config.min_length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn from_for_rule_md048() {
+        let style = CodeFenceStyle::Tilde;
+        let config = MD048 {
+            style,
+            min_length: 4,
+        };
+        let expected = rule::MD048::new(style, 4);
+        assert_eq!(rule::MD048::from(&config), expected);
+    }
+}
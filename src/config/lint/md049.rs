@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rule;
+use crate::rule::md049::EmphasisStyle;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+#[allow(clippy::exhaustive_structs)]
+pub struct MD049 {
+    pub style: EmphasisStyle,
+}
+
+impl Default for MD049 {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            style: rule::MD049::DEFAULT_STYLE,
+        }
+    }
+}
+
+impl From<&MD049> for rule::MD049 {
+    #[inline]
+    fn from(config: &MD049) -> Self {
+        Self::new(config.style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn from_for_rule_md049() {
+        let style = EmphasisStyle::Underscore;
+        let config = MD049 { style };
+        let expected = rule::MD049::new(style);
+        assert_eq!(rule::MD049::from(&config), expected);
+    }
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rule;
+use crate::rule::md049::EmphasisStyle;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+#[allow(clippy::exhaustive_structs)]
+pub struct MD050 {
+    pub style: EmphasisStyle,
+}
+
+impl Default for MD050 {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            style: rule::MD050::DEFAULT_STYLE,
+        }
+    }
+}
+
+impl From<&MD050> for rule::MD050 {
+    #[inline]
+    fn from(config: &MD050) -> Self {
+        Self::new(config.style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn from_for_rule_md050() {
+        let style = EmphasisStyle::Underscore;
+        let config = MD050 { style };
+        let expected = rule::MD050::new(style);
+        assert_eq!(rule::MD050::from(&config), expected);
+    }
+}
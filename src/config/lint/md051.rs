@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rule;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+#[allow(clippy::exhaustive_structs)]
+pub struct MD051 {
+    pub ignore_case: bool,
+}
+
+impl From<&MD051> for rule::MD051 {
+    #[inline]
+    fn from(config: &MD051) -> Self {
+        Self::new(config.ignore_case)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn from_for_rule_md051() {
+        let ignore_case = true;
+        let config = MD051 { ignore_case };
+        let expected = rule::MD051::new(ignore_case);
+        assert_eq!(rule::MD051::from(&config), expected);
+    }
+}
@@ -0,0 +1,143 @@
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use miette::{miette, IntoDiagnostic as _, Result};
+use serde::{Deserialize, Serialize};
+use toml::{Table, Value};
+
+use super::{Lint, RuleSet};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[allow(clippy::exhaustive_structs)]
+pub struct Override {
+    pub files: Vec<Glob>,
+    #[serde(default)]
+    pub enable: Vec<RuleSet>,
+    #[serde(default)]
+    pub disable: Vec<RuleSet>,
+    /// `rules` and rule options such as `[lint.overrides.md013]`
+    #[serde(flatten)]
+    pub options: Table,
+}
+
+impl Override {
+    #[inline]
+    pub fn file_set(&self) -> Result<GlobSet> {
+        let mut builder = GlobSetBuilder::new();
+        for glob in &self.files {
+            builder.add(glob.clone());
+        }
+        builder.build().into_diagnostic()
+    }
+
+    /// Returns the lint configuration overridden by this override.
+    /// Rule options are merged with the base options key by key.
+    #[inline]
+    pub fn apply(&self, lint: &Lint) -> Result<Lint> {
+        let mut table = Table::try_from(lint).into_diagnostic()?;
+        for (key, value) in &self.options {
+            match (key.as_str(), table.get_mut(key), value) {
+                ("rules", Some(rules), _) => *rules = value.clone(),
+                (_, Some(Value::Table(base)), Value::Table(options)) => {
+                    base.extend(options.clone());
+                }
+                _ => return Err(miette!("Unsupported option in lint.overrides: {key}")),
+            }
+        }
+
+        let mut overridden: Lint = table.try_into().map_err(|err| miette!(err))?;
+        overridden.rules.extend(self.enable.iter().cloned());
+        let disabled = Lint::flatten(&self.disable);
+        overridden.rules = Lint::flatten(&overridden.rules)
+            .into_iter()
+            .filter(|rule| !disabled.contains(rule))
+            .collect();
+        Ok(overridden)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn file_set() -> Result<()> {
+        let text = indoc! {r#"
+            files = ["docs/**/*.md", "README.md"]
+        "#};
+        let overrides: Override = toml::from_str(text).into_diagnostic()?;
+        let set = overrides.file_set()?;
+        assert!(set.is_match("docs/api/index.md"));
+        assert!(set.is_match("README.md"));
+        assert!(!set.is_match("CHANGELOG.md"));
+        Ok(())
+    }
+
+    #[test]
+    fn apply() -> Result<()> {
+        let text = indoc! {r#"
+            files = ["docs/**/*.md"]
+            enable = ["MD013"]
+            disable = ["atx", "MD041"]
+
+            [md013]
+            line-length = 120
+        "#};
+        let overrides: Override = toml::from_str(text).into_diagnostic()?;
+        let mut lint = Lint {
+            rules: vec![RuleSet::Headers, RuleSet::MD009],
+            ..Lint::default()
+        };
+        lint.md013.code_blocks = false;
+        let actual = overrides.apply(&lint)?;
+        let mut expected = lint;
+        expected.rules = vec![
+            RuleSet::MD001,
+            RuleSet::MD002,
+            RuleSet::MD003,
+            RuleSet::MD009,
+            RuleSet::MD013,
+            RuleSet::MD020,
+            RuleSet::MD021,
+            RuleSet::MD022,
+            RuleSet::MD023,
+            RuleSet::MD024,
+            RuleSet::MD025,
+            RuleSet::MD026,
+            RuleSet::MD036,
+        ];
+        expected.md013.line_length = 120;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn apply_rules() -> Result<()> {
+        let text = indoc! {r#"
+            files = ["*.md"]
+            rules = ["MD009"]
+        "#};
+        let overrides: Override = toml::from_str(text).into_diagnostic()?;
+        let actual = overrides.apply(&Lint::default())?;
+        let expected = Lint {
+            rules: vec![RuleSet::MD009],
+            ..Lint::default()
+        };
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn apply_unsupported_option() -> Result<()> {
+        let text = indoc! {r#"
+            files = ["*.md"]
+            quiet = true
+        "#};
+        let overrides: Override = toml::from_str(text).into_diagnostic()?;
+        let actual = overrides.apply(&Lint::default());
+        assert!(actual.is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,644 @@
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use miette::{miette, Result};
+use serde_json::{Map, Value};
+use toml::Table;
+
+use crate::config::Lint;
+use crate::rule::Metadata;
+use crate::Rule;
+
+/// Names of markdownlint and markdownlint-cli2 config files in order of precedence
+pub const FILE_NAMES: [&str; 6] = [
+    ".markdownlint-cli2.jsonc",
+    ".markdownlint-cli2.yaml",
+    ".markdownlint.jsonc",
+    ".markdownlint.json",
+    ".markdownlint.yaml",
+    ".markdownlint.yml",
+];
+
+/// Rules of markdownlint with aliases, including rules mado does not support yet
+const RULES: &[(&str, &[&str])] = &[
+    ("MD001", &["heading-increment"]),
+    ("MD002", &["first-heading-h1"]),
+    ("MD003", &["heading-style"]),
+    ("MD004", &["ul-style"]),
+    ("MD005", &["list-indent"]),
+    ("MD006", &["ul-start-left"]),
+    ("MD007", &["ul-indent"]),
+    ("MD009", &["no-trailing-spaces"]),
+    ("MD010", &["no-hard-tabs"]),
+    ("MD011", &["no-reversed-links"]),
+    ("MD012", &["no-multiple-blanks"]),
+    ("MD013", &["line-length"]),
+    ("MD014", &["commands-show-output"]),
+    ("MD018", &["no-missing-space-atx"]),
+    ("MD019", &["no-multiple-space-atx"]),
+    ("MD020", &["no-missing-space-closed-atx"]),
+    ("MD021", &["no-multiple-space-closed-atx"]),
+    ("MD022", &["blanks-around-headings"]),
+    ("MD023", &["heading-start-left"]),
+    ("MD024", &["no-duplicate-heading"]),
+    ("MD025", &["single-title", "single-h1"]),
+    ("MD026", &["no-trailing-punctuation"]),
+    ("MD027", &["no-multiple-space-blockquote"]),
+    ("MD028", &["no-blanks-blockquote"]),
+    ("MD029", &["ol-prefix"]),
+    ("MD030", &["list-marker-space"]),
+    ("MD031", &["blanks-around-fences"]),
+    ("MD032", &["blanks-around-lists"]),
+    ("MD033", &["no-inline-html"]),
+    ("MD034", &["no-bare-urls"]),
+    ("MD035", &["hr-style"]),
+    ("MD036", &["no-emphasis-as-heading"]),
+    ("MD037", &["no-space-in-emphasis"]),
+    ("MD038", &["no-space-in-code"]),
+    ("MD039", &["no-space-in-links"]),
+    ("MD040", &["fenced-code-language"]),
+    ("MD041", &["first-line-heading", "first-line-h1"]),
+    ("MD042", &["no-empty-links"]),
+    ("MD043", &["required-headings"]),
+    ("MD044", &["proper-names"]),
+    ("MD045", &["no-alt-text"]),
+    ("MD046", &["code-block-style"]),
+    ("MD047", &["single-trailing-newline"]),
+    ("MD048", &["code-fence-style"]),
+    ("MD049", &["emphasis-style"]),
+    ("MD050", &["strong-style"]),
+    ("MD051", &["link-fragments"]),
+    ("MD052", &["reference-links-images"]),
+    ("MD053", &["link-image-reference-definitions"]),
+    ("MD054", &["link-image-style"]),
+    ("MD055", &["table-pipe-style"]),
+    ("MD056", &["table-column-count"]),
+    ("MD058", &["blanks-around-tables"]),
+    ("MD059", &["descriptive-link-text"]),
+];
+
+/// Options of markdownlint rules supported by mado.
+/// Option names are converted from `snake_case` to `kebab-case`.
+const OPTIONS: &[(&str, &[&str])] = &[
+    ("MD002", &["level"]),
+    ("MD003", &["style"]),
+    ("MD004", &["style"]),
+    ("MD007", &["indent"]),
+    ("MD013", &["line_length", "code_blocks", "tables"]),
+    ("MD024", &["allow_different_nesting"]),
+    ("MD025", &["level"]),
+    ("MD026", &["punctuation"]),
+    ("MD029", &["style"]),
+    ("MD030", &["ul_single", "ol_single", "ul_multi", "ol_multi"]),
+    ("MD033", &["allowed_elements"]),
+    ("MD035", &["style"]),
+    ("MD036", &["punctuation"]),
+    ("MD041", &["level"]),
+    ("MD046", &["style"]),
+    ("MD048", &["style"]),
+    ("MD049", &["style"]),
+    ("MD050", &["style"]),
+    ("MD051", &["ignore_case"]),
+];
+
+/// Returns true if the path looks like a markdownlint config file rather than `mado.toml`
+#[inline]
+#[must_use]
+pub fn is_markdownlint(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(OsStr::to_str),
+        Some("json" | "jsonc" | "yaml" | "yml")
+    )
+}
+
+/// Loads a markdownlint or markdownlint-cli2 config file as a mado config table.
+/// Rules and options mado does not support yet are pushed to `warnings`.
+/// Canonical paths of files specified by `extends` are pushed to `visited`.
+#[inline]
+pub fn load(path: &Path, visited: &mut Vec<PathBuf>, warnings: &mut Vec<String>) -> Result<Table> {
+    let mut converter = Converter::default();
+    let table = converter.load(path, visited)?;
+    warnings.extend(
+        converter
+            .warnings
+            .iter()
+            .map(|warning| format!("{}: {warning}", path.display())),
+    );
+    Ok(table)
+}
+
+/// A key of markdownlint config
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Key {
+    Default,
+    /// A rule by the markdownlint name
+    Rule(&'static str),
+    /// Names of mado rules with the tag
+    Tag(Vec<&'static str>),
+    Unknown,
+}
+
+#[derive(Debug, Default)]
+struct Converter {
+    warnings: Vec<String>,
+}
+
+impl Converter {
+    fn load(&mut self, path: &Path, visited: &mut Vec<PathBuf>) -> Result<Table> {
+        let mut object = read(path)?;
+        let is_cli2 = path
+            .file_name()
+            .and_then(OsStr::to_str)
+            .is_some_and(|name| name.starts_with(".markdownlint-cli2"));
+        if !is_cli2 {
+            let config = resolve_extends(path, object, visited)?;
+            return Ok(self.convert(&config));
+        }
+
+        // markdownlint-cli2 has rules in `config` with its own options
+        let config = match object.remove("config") {
+            Some(Value::Object(config)) => config,
+            Some(Value::Null) | None => Map::new(),
+            Some(_) => return Err(miette!("config must be an object in {}", path.display())),
+        };
+        let config = resolve_extends(path, config, visited)?;
+        let mut table = self.convert(&config);
+        let mut lint = Table::new();
+        for (key, value) in object {
+            match (key.as_str(), value) {
+                ("ignores", Value::Array(ignores)) => {
+                    let exclude = ignores
+                        .into_iter()
+                        .filter_map(|ignore| match ignore {
+                            Value::String(ignore) => Some(toml::Value::String(ignore)),
+                            _ => None,
+                        })
+                        .collect();
+                    lint.insert("exclude".to_owned(), toml::Value::Array(exclude));
+                }
+                ("fix", Value::Bool(fix)) => {
+                    lint.insert("fix".to_owned(), toml::Value::Boolean(fix));
+                }
+                ("$schema", _) => {}
+                (key, _) => self.warn(format!("`{key}` is not supported yet")),
+            }
+        }
+        if let Some(toml::Value::Table(converted)) = table.get_mut("lint") {
+            converted.extend(lint);
+        }
+        Ok(table)
+    }
+
+    // Converts rules of markdownlint config into `[lint]`.
+    // Tags are applied before rules so that rules take precedence.
+    fn convert(&mut self, config: &Map<String, Value>) -> Table {
+        let all_rules = Rule::all();
+        let metadata: Vec<&'static Metadata> = all_rules.iter().map(Rule::metadata).collect();
+        let keys: Vec<_> = config
+            .iter()
+            .map(|(key, value)| (Self::classify(key, &metadata), key, value))
+            .collect();
+
+        let default = keys
+            .iter()
+            .find(|(key, _, _)| *key == Key::Default)
+            .is_none_or(|(_, _, value)| is_truthy(value));
+        let mut enabled: Vec<_> = metadata.iter().map(|meta| (meta.name, default)).collect();
+        let mut lint = Table::new();
+
+        for (key, name, value) in &keys {
+            match key {
+                Key::Tag(names) => {
+                    for (rule, is_enabled) in &mut enabled {
+                        if names.contains(rule) {
+                            *is_enabled = is_truthy(value);
+                        }
+                    }
+                }
+                Key::Unknown => self.warn(format!("Unknown rule or tag `{name}`")),
+                Key::Default | Key::Rule(_) => {}
+            }
+        }
+
+        for (key, _, value) in &keys {
+            let Key::Rule(name) = key else {
+                continue;
+            };
+
+            match enabled.iter_mut().find(|(rule, _)| rule == name) {
+                Some((_, is_enabled)) => {
+                    *is_enabled = is_truthy(value);
+                    if let Value::Object(options) = value {
+                        self.convert_options(name, options, &mut lint);
+                    }
+                }
+                None if is_truthy(value) => {
+                    let aliases = RULES
+                        .iter()
+                        .find(|(rule, _)| rule == name)
+                        .map_or(String::new(), |(_, aliases)| aliases.join(", "));
+                    self.warn(format!("{name} ({aliases}) is not supported yet"));
+                }
+                None => {}
+            }
+        }
+
+        let rules = enabled
+            .into_iter()
+            .filter(|(_, is_enabled)| *is_enabled)
+            .map(|(name, _)| toml::Value::String(name.to_owned()))
+            .collect();
+        lint.insert("rules".to_owned(), toml::Value::Array(rules));
+        Table::from_iter([("lint".to_owned(), toml::Value::Table(lint))])
+    }
+
+    // Converts options of the rule into `[lint.mdXXX]`, skipping unsupported ones
+    fn convert_options(&mut self, name: &str, options: &Map<String, Value>, lint: &mut Table) {
+        for (option, value) in options {
+            if !is_supported_option(name, option) {
+                self.warn(format!("{name}: `{option}` is not supported yet"));
+                continue;
+            }
+
+            let converted = match (name, value) {
+                // markdownlint uses `atx_closed` while mado uses `atx-closed`
+                ("MD003", Value::String(style)) => Value::String(style.replace('_', "-")),
+                _ => value.clone(),
+            };
+            let is_inserted = toml::Value::try_from(converted)
+                .is_ok_and(|toml_value| insert_option(lint, name, option, toml_value));
+            if !is_inserted {
+                self.warn(format!("{name}: `{option}: {value}` is not supported yet"));
+            }
+        }
+    }
+
+    fn classify(key: &str, metadata: &[&'static Metadata]) -> Key {
+        if key.eq_ignore_ascii_case("default") {
+            return Key::Default;
+        }
+
+        let markdownlint_rule = RULES.iter().find(|(name, aliases)| {
+            name.eq_ignore_ascii_case(key)
+                || aliases.iter().any(|alias| alias.eq_ignore_ascii_case(key))
+        });
+        if let Some((name, _)) = markdownlint_rule {
+            return Key::Rule(name);
+        }
+
+        // Aliases of mado such as `header-increment` are also accepted
+        let rule = metadata.iter().find(|meta| {
+            meta.aliases
+                .iter()
+                .any(|alias| alias.eq_ignore_ascii_case(key))
+        });
+        if let Some(meta) = rule {
+            return Key::Rule(meta.name);
+        }
+
+        // markdownlint uses `headings` and `hard_tab` while mado uses `headers` and `hard-tab`
+        let tag = match key.to_lowercase().replace('_', "-").as_str() {
+            "headings" => "headers".to_owned(),
+            tag => tag.to_owned(),
+        };
+        let names: Vec<_> = metadata
+            .iter()
+            .filter(|meta| {
+                meta.tags.iter().any(|meta_tag| {
+                    serde_json::to_value(meta_tag).is_ok_and(|value| value == tag.as_str())
+                })
+            })
+            .map(|meta| meta.name)
+            .collect();
+        if names.is_empty() {
+            Key::Unknown
+        } else {
+            Key::Tag(names)
+        }
+    }
+
+    fn warn(&mut self, warning: String) {
+        self.warnings.push(warning);
+    }
+}
+
+/// Returns true if mado supports the option of the rule by the `snake_case` name
+pub(super) fn is_supported_option(rule: &str, option: &str) -> bool {
+    OPTIONS
+        .iter()
+        .any(|(name, options)| *name == rule && options.contains(&option))
+}
+
+/// Inserts the option by the `snake_case` name into `[lint.mdXXX]`.
+/// Returns false if mado does not accept the value.
+pub(super) fn insert_option(
+    lint: &mut Table,
+    rule: &str,
+    option: &str,
+    value: toml::Value,
+) -> bool {
+    let section_name = rule.to_lowercase();
+    let key = option.replace('_', "-");
+
+    // Each option is validated alone to skip only invalid ones
+    let section = Table::from_iter([(key.clone(), value.clone())]);
+    let validation = Table::from_iter([(section_name.clone(), toml::Value::Table(section))]);
+    if validation.try_into::<Lint>().is_err() {
+        return false;
+    }
+
+    if let toml::Value::Table(converted_section) = lint
+        .entry(section_name)
+        .or_insert_with(|| toml::Value::Table(Table::new()))
+    {
+        converted_section.insert(key, value);
+    }
+    true
+}
+
+fn read(path: &Path) -> Result<Map<String, Value>> {
+    let text = fs::read_to_string(path)
+        .map_err(|err| miette!("Failed to load {}: {err}", path.display()))?;
+    let value: Value = match path.extension().and_then(OsStr::to_str) {
+        Some("yaml" | "yml") => serde_yaml_ng::from_str(&text)
+            .map_err(|err| miette!("Failed to parse {}: {err}", path.display()))?,
+        _ => serde_json::from_str(&strip_jsonc(&text))
+            .map_err(|err| miette!("Failed to parse {}: {err}", path.display()))?,
+    };
+    match value {
+        Value::Object(object) => Ok(object),
+        // An empty YAML file
+        Value::Null => Ok(Map::new()),
+        _ => Err(miette!("{} must be an object", path.display())),
+    }
+}
+
+// Merges config files specified by `extends`. Keys of the base are replaced.
+fn resolve_extends(
+    path: &Path,
+    mut config: Map<String, Value>,
+    visited: &mut Vec<PathBuf>,
+) -> Result<Map<String, Value>> {
+    match config.remove("extends") {
+        Some(Value::String(extends)) => {
+            let base_path = path.parent().unwrap_or_else(|| Path::new("")).join(extends);
+            let canonical_path = fs::canonicalize(&base_path)
+                .map_err(|err| miette!("Failed to load {}: {err}", base_path.display()))?;
+            if visited.contains(&canonical_path) {
+                return Err(miette!("Circular extends in {}", path.display()));
+            }
+            visited.push(canonical_path);
+
+            let mut base = resolve_extends(&base_path, read(&base_path)?, visited)?;
+            base.extend(config);
+            Ok(base)
+        }
+        Some(Value::Null) | None => Ok(config),
+        Some(_) => Err(miette!("extends must be a string in {}", path.display())),
+    }
+}
+
+// Same as JavaScript
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(value) => *value,
+        Value::Number(number) => number.as_f64().is_some_and(|float| float != 0.0),
+        Value::String(string) => !string.is_empty(),
+        Value::Array(_) | Value::Object(_) => true,
+    }
+}
+
+// Removes comments and trailing commas of JSONC
+fn strip_jsonc(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            stripped.push(c);
+            match c {
+                '\\' => {
+                    if let Some(escaped) = chars.next() {
+                        stripped.push(escaped);
+                    }
+                }
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                stripped.push(c);
+            }
+            ('/', Some('/')) => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        stripped.push(next);
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = None;
+                for next in chars.by_ref() {
+                    if prev == Some('*') && next == '/' {
+                        break;
+                    }
+                    prev = Some(next);
+                }
+            }
+            ('}' | ']', _) => {
+                let trimmed_len = stripped.trim_end().len();
+                if stripped[..trimmed_len].ends_with(',') {
+                    stripped.remove(trimmed_len - 1);
+                }
+                stripped.push(c);
+            }
+            _ => stripped.push(c),
+        }
+    }
+
+    stripped
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    use crate::config::lint::RuleSet;
+    use crate::config::Config;
+    use crate::rule::md003::HeadingStyle;
+    use miette::IntoDiagnostic as _;
+
+    use super::*;
+
+    fn convert(config: &Value) -> Result<(Lint, Vec<String>)> {
+        let Value::Object(object) = config else {
+            return Err(miette!("config must be an object"));
+        };
+        let mut converter = Converter::default();
+        let table = converter.convert(object);
+        let mado_config: Config = table.try_into().into_diagnostic()?;
+        Ok((mado_config.lint, converter.warnings))
+    }
+
+    #[test]
+    fn convert_rules() -> Result<()> {
+        let config = serde_json::json!({
+            "default": false,
+            "MD001": true,
+            "line-length": { "line_length": 120, "code_blocks": false },
+            "heading-style": { "style": "atx_closed" },
+            "no-inline-html": { "allowed_elements": ["br"] },
+        });
+        let (actual, warnings) = convert(&config)?;
+        let mut expected = Lint {
+            rules: vec![
+                RuleSet::MD001,
+                RuleSet::MD003,
+                RuleSet::MD013,
+                RuleSet::MD033,
+            ],
+            ..Lint::default()
+        };
+        expected.md003.style = HeadingStyle::AtxClosed;
+        expected.md013.line_length = 120;
+        expected.md013.code_blocks = false;
+        expected.md033.allowed_elements = vec!["br".to_owned()];
+        assert_eq!(actual, expected);
+        assert_eq!(warnings, Vec::<String>::new());
+        Ok(())
+    }
+
+    #[test]
+    fn convert_disabled_rules_and_tags() -> Result<()> {
+        let config = serde_json::json!({
+            "MD013": false,
+            "headings": false,
+            "MD041": true,
+        });
+        let (actual, _) = convert(&config)?;
+        let rules = actual.rules;
+        assert!(!rules.contains(&RuleSet::MD013));
+        assert!(!rules.contains(&RuleSet::MD001));
+        assert!(rules.contains(&RuleSet::MD041));
+        assert!(rules.contains(&RuleSet::MD009));
+        Ok(())
+    }
+
+    #[test]
+    fn convert_unsupported() -> Result<()> {
+        let config = serde_json::json!({
+            "MD011": true,
+            "MD042": false,
+            "MD013": { "line_length": 100, "heading_line_length": 80 },
+            "MD029": { "style": "one_or_ordered" },
+            "unknown": true,
+        });
+        let (actual, warnings) = convert(&config)?;
+        assert_eq!(actual.md013.line_length, 100);
+        assert_eq!(actual.md029, Lint::default().md029);
+        let expected = vec![
+            "MD011 (no-reversed-links) is not supported yet",
+            "MD013: `heading_line_length` is not supported yet",
+            "MD029: `style: \"one_or_ordered\"` is not supported yet",
+            "Unknown rule or tag `unknown`",
+        ];
+        let mut sorted = warnings;
+        sorted.sort();
+        assert_eq!(sorted, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn load_yaml_with_extends() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let base_text = indoc! {"
+            default: true
+            MD013:
+              line_length: 100
+            MD046: false
+        "};
+        fs::write(tmp_dir.path().join("base.yaml"), base_text).into_diagnostic()?;
+        let text = indoc! {"
+            extends: base.yaml
+            MD013:
+              tables: false
+        "};
+        let path = tmp_dir.path().join(".markdownlint.yaml");
+        fs::write(&path, text).into_diagnostic()?;
+
+        let (actual, _) = Config::load(&path)?;
+        assert!(!actual.lint.rules.contains(&RuleSet::MD046));
+        // Rules are replaced as markdownlint does
+        assert_eq!(actual.lint.md013.line_length, 80);
+        assert!(!actual.lint.md013.tables);
+        tmp_dir.close().into_diagnostic()
+    }
+
+    #[test]
+    fn load_cli2() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let text = indoc! {r#"
+            // markdownlint-cli2 config
+            {
+              "config": {
+                "default": true,
+                /* Long lines are allowed */
+                "MD013": false,
+                "MD011": true,
+              },
+              "ignores": ["vendor/**"],
+            }
+        "#};
+        let path = tmp_dir.path().join(".markdownlint-cli2.jsonc");
+        fs::write(&path, text).into_diagnostic()?;
+
+        let (actual, warnings) = Config::load(&path)?;
+        assert!(!actual.lint.rules.contains(&RuleSet::MD013));
+        assert_eq!(actual.lint.exclude.len(), 1);
+        let expected_warning = format!(
+            "{}: MD011 (no-reversed-links) is not supported yet",
+            path.display()
+        );
+        assert_eq!(warnings, vec![expected_warning]);
+        tmp_dir.close().into_diagnostic()
+    }
+
+    #[test]
+    fn strip_jsonc_comments_and_trailing_commas() -> Result<()> {
+        let text = indoc! {r#"
+            {
+              // comment
+              "a": "// not a comment",
+              "b": [1, 2,], /* comment */
+              "c": "\"quoted\"",
+            }
+        "#};
+        let actual: Value = serde_json::from_str(&strip_jsonc(text)).into_diagnostic()?;
+        let expected = serde_json::json!({
+            "a": "// not a comment",
+            "b": [1, 2],
+            "c": "\"quoted\"",
+        });
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn is_markdownlint_extensions() {
+        assert!(is_markdownlint(Path::new(".markdownlint.json")));
+        assert!(is_markdownlint(Path::new(".markdownlint-cli2.jsonc")));
+        assert!(is_markdownlint(Path::new("config.yml")));
+        assert!(!is_markdownlint(Path::new("mado.toml")));
+    }
+}
@@ -0,0 +1,698 @@
+use std::ffi::OsStr;
+use std::fs;
+use std::path::Path;
+
+use miette::{miette, Context as _, Result};
+use toml::{Table, Value};
+
+use super::markdownlint::{insert_option, is_supported_option};
+use crate::rule::Metadata;
+use crate::Rule;
+
+/// Name of the config file of Ruby mdl
+pub const FILE_NAME: &str = ".mdlrc";
+
+/// Styles bundled with mdl that enable all rules
+const ALL_STYLES: [&str; 2] = ["default", "all"];
+
+/// Settings of `.mdlrc` that do not affect lint results
+const IGNORED_SETTINGS: [&str; 5] = [
+    "git_recurse",
+    "ignore_front_matter",
+    "show_aliases",
+    "verbose",
+    "warnings",
+];
+
+/// Returns true if the path is `.mdlrc` or a style file of Ruby mdl
+#[inline]
+#[must_use]
+pub fn is_mdl(path: &Path) -> bool {
+    path.file_name() == Some(OsStr::new(FILE_NAME))
+        || path.extension().and_then(OsStr::to_str) == Some("rb")
+}
+
+/// Loads `.mdlrc` or a style file of Ruby mdl as a mado config table.
+/// Only the common subset of the DSL is supported, and other constructs are errors.
+#[inline]
+pub fn load(path: &Path) -> Result<Table> {
+    let lint = if path.file_name() == Some(OsStr::new(FILE_NAME)) {
+        load_mdlrc(path)?
+    } else {
+        Style::load(path)?.into_lint()
+    };
+    Ok(Table::from_iter([("lint".to_owned(), Value::Table(lint))]))
+}
+
+// Loads `.mdlrc`, which specifies a style with filters of rules and tags
+fn load_mdlrc(path: &Path) -> Result<Table> {
+    let mut style_name = ALL_STYLES[0].to_owned();
+    let mut rule_filters = vec![];
+    let mut tag_filters = vec![];
+    let mut output_format = None;
+
+    for (lineno, call) in parse(path)? {
+        let result = match (call.method.as_str(), call.args.as_slice()) {
+            ("style", [RubyValue::String(name)]) => {
+                name.clone_into(&mut style_name);
+                Ok(())
+            }
+            ("rules", [RubyValue::String(rules)]) => {
+                rule_filters.extend(split_list(rules));
+                Ok(())
+            }
+            ("tags", [RubyValue::String(tags)]) => {
+                tag_filters.extend(split_list(tags));
+                Ok(())
+            }
+            ("json", [RubyValue::Boolean(json)]) => {
+                output_format = json.then(|| Value::String("json".to_owned()));
+                Ok(())
+            }
+            (method, _) if IGNORED_SETTINGS.contains(&method) => Ok(()),
+            (method, _) => Err(miette!("Unsupported setting `{method}`")),
+        };
+        result.wrap_err_with(|| format!("Failed to load {}:{lineno}", path.display()))?;
+    }
+
+    let style_path = path
+        .parent()
+        .unwrap_or_else(|| Path::new(""))
+        .join(&style_name);
+    let mut style = if style_path.is_file() {
+        Style::load(&style_path)?
+    } else if ALL_STYLES.contains(&style_name.as_str()) {
+        let mut style = Style::new();
+        style.all();
+        style
+    } else {
+        return Err(miette!(
+            "Unsupported style `{style_name}` in {}",
+            path.display()
+        ));
+    };
+    style
+        .filter(&rule_filters, &tag_filters)
+        .wrap_err_with(|| format!("Failed to load {}", path.display()))?;
+
+    let mut lint = style.into_lint();
+    if let Some(output_format) = output_format {
+        lint.insert("output-format".to_owned(), output_format);
+    }
+    Ok(lint)
+}
+
+// Splits a comma-separated list such as `"MD001,~MD013"`
+fn split_list(list: &str) -> impl Iterator<Item = String> + '_ {
+    list.split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(ToOwned::to_owned)
+}
+
+/// Rules and options defined by a style file
+#[derive(Debug, Clone, PartialEq)]
+struct Style {
+    metadata: Vec<&'static Metadata>,
+    /// Whether each rule of `metadata` is enabled
+    enabled: Vec<bool>,
+    /// Options in `[lint]`
+    lint: Table,
+}
+
+impl Style {
+    fn new() -> Self {
+        let metadata: Vec<_> = Rule::all().iter().map(Rule::metadata).collect();
+        let enabled = vec![false; metadata.len()];
+        Self {
+            metadata,
+            enabled,
+            lint: Table::new(),
+        }
+    }
+
+    fn load(path: &Path) -> Result<Self> {
+        let mut style = Self::new();
+        for (lineno, call) in parse(path)? {
+            style
+                .apply(&call)
+                .wrap_err_with(|| format!("Failed to load {}:{lineno}", path.display()))?;
+        }
+        Ok(style)
+    }
+
+    fn apply(&mut self, call: &Call) -> Result<()> {
+        match call.method.as_str() {
+            "all" if call.args.is_empty() => self.all(),
+            "rule" => {
+                for name in &call.args {
+                    let index = self.find_rule(name)?;
+                    self.enabled[index] = true;
+                    for (key, value) in &call.options {
+                        self.insert_option(self.metadata[index].name, key, value)?;
+                    }
+                }
+            }
+            "exclude_rule" => {
+                for name in &call.args {
+                    let index = self.find_rule(name)?;
+                    self.enabled[index] = false;
+                }
+            }
+            "tag" | "exclude_tag" => {
+                let is_enabled = call.method == "tag";
+                for tag in &call.args {
+                    for index in self.find_tag(tag)? {
+                        self.enabled[index] = is_enabled;
+                    }
+                }
+            }
+            method => return Err(miette!("Unsupported method `{method}`")),
+        }
+
+        if call.method != "rule" && !call.options.is_empty() {
+            return Err(miette!("Unexpected options for `{}`", call.method));
+        }
+        Ok(())
+    }
+
+    fn all(&mut self) {
+        self.enabled.fill(true);
+    }
+
+    // Applies `rules` and then `tags` of `.mdlrc` as mdl does.
+    // Items prefixed with `~` are excluded.
+    fn filter(&mut self, rules: &[String], tags: &[String]) -> Result<()> {
+        let mut rule_filters = vec![];
+        for rule in rules {
+            let (is_excluded, name) = Self::split_exclusion(rule);
+            let index = self.find_rule(&RubyValue::String(name.to_owned()))?;
+            rule_filters.push((is_excluded, vec![index]));
+        }
+        self.apply_filters(&rule_filters);
+
+        let mut tag_filters = vec![];
+        for tag in tags {
+            let (is_excluded, name) = Self::split_exclusion(tag);
+            let indices = self.find_tag(&RubyValue::String(name.to_owned()))?;
+            tag_filters.push((is_excluded, indices));
+        }
+        self.apply_filters(&tag_filters);
+        Ok(())
+    }
+
+    // Keeps only included rules if any, and then removes excluded rules
+    fn apply_filters(&mut self, filters: &[(bool, Vec<usize>)]) {
+        let indices_of = |excluded: bool| -> Vec<usize> {
+            filters
+                .iter()
+                .filter(|(is_excluded, _)| *is_excluded == excluded)
+                .flat_map(|(_, indices)| indices.iter().copied())
+                .collect()
+        };
+        let included = indices_of(false);
+        let excluded = indices_of(true);
+        for (index, is_enabled) in self.enabled.iter_mut().enumerate() {
+            if (!included.is_empty() && !included.contains(&index)) || excluded.contains(&index) {
+                *is_enabled = false;
+            }
+        }
+    }
+
+    fn split_exclusion(item: &str) -> (bool, &str) {
+        item.strip_prefix('~')
+            .map_or((false, item), |name| (true, name))
+    }
+
+    fn find_rule(&self, name: &RubyValue) -> Result<usize> {
+        let (RubyValue::String(name) | RubyValue::Symbol(name)) = name else {
+            return Err(miette!("Rule name must be a string"));
+        };
+        self.metadata
+            .iter()
+            .position(|metadata| {
+                metadata.name.eq_ignore_ascii_case(name)
+                    || metadata
+                        .aliases
+                        .iter()
+                        .any(|alias| alias.eq_ignore_ascii_case(name))
+            })
+            .ok_or_else(|| miette!("Unknown rule `{name}`"))
+    }
+
+    fn find_tag(&self, tag: &RubyValue) -> Result<Vec<usize>> {
+        let (RubyValue::String(tag) | RubyValue::Symbol(tag)) = tag else {
+            return Err(miette!("Tag must be a symbol"));
+        };
+        let kebab_tag = tag.replace('_', "-");
+        let indices: Vec<_> = self
+            .metadata
+            .iter()
+            .enumerate()
+            .filter(|(_, metadata)| {
+                metadata.tags.iter().any(|metadata_tag| {
+                    serde_json::to_value(metadata_tag)
+                        .is_ok_and(|value| value == kebab_tag.as_str())
+                })
+            })
+            .map(|(index, _)| index)
+            .collect();
+        if indices.is_empty() {
+            return Err(miette!("Unknown tag `{tag}`"));
+        }
+        Ok(indices)
+    }
+
+    fn insert_option(&mut self, rule: &str, key: &str, value: &RubyValue) -> Result<()> {
+        let (option, converted) = match (rule, key, value) {
+            // Deprecated in mdl in favor of `code_blocks`
+            ("MD013", "ignore_code_blocks", RubyValue::Boolean(ignore)) => {
+                ("code_blocks", Value::Boolean(!ignore))
+            }
+            // mdl accepts a comma-separated string
+            ("MD033", "allowed_elements", RubyValue::String(elements)) => {
+                let elements = split_list(elements).map(Value::String).collect();
+                ("allowed_elements", Value::Array(elements))
+            }
+            _ if is_supported_option(rule, key) => (key, value.to_toml()),
+            _ => return Err(miette!("Unsupported option `{key}` for {rule}")),
+        };
+
+        if insert_option(&mut self.lint, rule, option, converted) {
+            Ok(())
+        } else {
+            Err(miette!("Unsupported value of `{key}` for {rule}"))
+        }
+    }
+
+    fn into_lint(self) -> Table {
+        let rules = self
+            .metadata
+            .iter()
+            .zip(&self.enabled)
+            .filter(|(_, is_enabled)| **is_enabled)
+            .map(|(metadata, _)| Value::String(metadata.name.to_owned()))
+            .collect();
+        let mut lint = self.lint;
+        lint.insert("rules".to_owned(), Value::Array(rules));
+        lint
+    }
+}
+
+/// A value of the Ruby DSL
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RubyValue {
+    String(String),
+    Symbol(String),
+    Integer(i64),
+    Boolean(bool),
+    Array(Vec<RubyValue>),
+}
+
+impl RubyValue {
+    fn to_toml(&self) -> Value {
+        match self {
+            Self::String(string) => Value::String(string.clone()),
+            // Symbols such as `:atx_closed` are `atx-closed` in mado
+            Self::Symbol(symbol) => Value::String(symbol.replace('_', "-")),
+            Self::Integer(integer) => Value::Integer(*integer),
+            Self::Boolean(boolean) => Value::Boolean(*boolean),
+            Self::Array(values) => Value::Array(values.iter().map(Self::to_toml).collect()),
+        }
+    }
+}
+
+/// A method call such as `rule 'MD007', :indent => 4`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Call {
+    method: String,
+    args: Vec<RubyValue>,
+    /// Options of a trailing hash
+    options: Vec<(String, RubyValue)>,
+}
+
+// Parses method calls of each line with line numbers
+fn parse(path: &Path) -> Result<Vec<(usize, Call)>> {
+    let text = fs::read_to_string(path)
+        .map_err(|err| miette!("Failed to load {}: {err}", path.display()))?;
+    let mut calls = vec![];
+    for (i, line) in text.lines().enumerate() {
+        let lineno = i + 1;
+        let code = strip_comment(line).trim();
+        if code.is_empty() {
+            continue;
+        }
+
+        let call = Parser::new(code)
+            .call()
+            .ok_or_else(|| miette!("Unsupported syntax in {}:{lineno}: {code}", path.display()))?;
+        calls.push((lineno, call));
+    }
+    Ok(calls)
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut is_escaped = false;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (Some(_), '\\') if !is_escaped => {
+                is_escaped = true;
+                continue;
+            }
+            (Some(q), c) if c == q && !is_escaped => quote = None,
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '#') => return &line[..i],
+            _ => {}
+        }
+        is_escaped = false;
+    }
+    line
+}
+
+/// A parser of a line of the Ruby DSL
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn new(code: &str) -> Self {
+        Self {
+            chars: code.chars().collect(),
+            pos: 0,
+        }
+    }
+
+    // Parses `method arg, :key => value` or `method(arg, key: value)`
+    fn call(&mut self) -> Option<Call> {
+        let method = self.identifier()?;
+        let has_parens = self.eat('(');
+        let mut call = Call {
+            method,
+            args: vec![],
+            options: vec![],
+        };
+
+        loop {
+            self.skip_whitespace();
+            if matches!(self.peek(), None | Some(')')) {
+                break;
+            }
+
+            if let Some(key) = self.hash_key() {
+                self.skip_whitespace();
+                call.options.push((key, self.value()?));
+            } else if call.options.is_empty() {
+                call.args.push(self.value()?);
+            } else {
+                return None;
+            }
+
+            self.skip_whitespace();
+            if !self.eat(',') {
+                break;
+            }
+        }
+
+        self.skip_whitespace();
+        if has_parens && !self.eat(')') {
+            return None;
+        }
+        self.skip_whitespace();
+        (self.pos == self.chars.len()).then_some(call)
+    }
+
+    // Parses `:key =>` or `key:`, or rewinds
+    fn hash_key(&mut self) -> Option<String> {
+        let start = self.pos;
+        if self.eat(':') {
+            if let Some(key) = self.identifier() {
+                self.skip_whitespace();
+                if self.eat('=') && self.eat('>') {
+                    return Some(key);
+                }
+            }
+        } else if let Some(key) = self.identifier() {
+            if self.eat(':') && self.peek() != Some(':') {
+                return Some(key);
+            }
+        }
+        self.pos = start;
+        None
+    }
+
+    fn value(&mut self) -> Option<RubyValue> {
+        match self.peek()? {
+            '\'' | '"' => self.string().map(RubyValue::String),
+            ':' => {
+                self.pos += 1;
+                self.identifier().map(RubyValue::Symbol)
+            }
+            '[' => {
+                self.pos += 1;
+                let mut values = vec![];
+                loop {
+                    self.skip_whitespace();
+                    if self.eat(']') {
+                        return Some(RubyValue::Array(values));
+                    }
+                    values.push(self.value()?);
+                    self.skip_whitespace();
+                    if !self.eat(',') {
+                        return self.eat(']').then_some(RubyValue::Array(values));
+                    }
+                }
+            }
+            c if c.is_ascii_digit() || c == '-' => {
+                let start = self.pos;
+                self.pos += 1;
+                while self
+                    .peek()
+                    .is_some_and(|digit| digit.is_ascii_digit() || digit == '_')
+                {
+                    self.pos += 1;
+                }
+                let digits: String = self.chars[start..self.pos]
+                    .iter()
+                    .filter(|digit| **digit != '_')
+                    .collect();
+                digits.parse().ok().map(RubyValue::Integer)
+            }
+            _ => match self.identifier()?.as_str() {
+                "true" => Some(RubyValue::Boolean(true)),
+                "false" => Some(RubyValue::Boolean(false)),
+                _ => None,
+            },
+        }
+    }
+
+    fn string(&mut self) -> Option<String> {
+        let quote = self.peek()?;
+        self.pos += 1;
+        let mut string = String::new();
+        loop {
+            let c = self.peek()?;
+            self.pos += 1;
+            match c {
+                '\\' => {
+                    string.push(self.peek()?);
+                    self.pos += 1;
+                }
+                c if c == quote => return Some(string),
+                c => string.push(c),
+            }
+        }
+    }
+
+    fn identifier(&mut self) -> Option<String> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_' || c == '?')
+        {
+            self.pos += 1;
+        }
+        let identifier: String = self.chars[start..self.pos].iter().collect();
+        if identifier.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            Some(identifier)
+        } else {
+            self.pos = start;
+            None
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let is_eaten = self.peek() == Some(c);
+        if is_eaten {
+            self.pos += 1;
+        }
+        is_eaten
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use miette::IntoDiagnostic as _;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    use crate::config::lint::RuleSet;
+    use crate::config::Config;
+    use crate::output::Format;
+    use crate::rule::md003::HeadingStyle;
+
+    use super::*;
+
+    #[test]
+    fn parser_call() {
+        let actual = Parser::new("rule 'MD013', :line_length => 100, code_blocks: false").call();
+        let expected = Call {
+            method: "rule".to_owned(),
+            args: vec![RubyValue::String("MD013".to_owned())],
+            options: vec![
+                ("line_length".to_owned(), RubyValue::Integer(100)),
+                ("code_blocks".to_owned(), RubyValue::Boolean(false)),
+            ],
+        };
+        assert_eq!(actual, Some(expected));
+    }
+
+    #[test]
+    fn parser_call_with_parens_and_array() {
+        let actual = Parser::new("rule(\"MD033\", :allowed_elements => ['br', \"img\"])").call();
+        let expected = Call {
+            method: "rule".to_owned(),
+            args: vec![RubyValue::String("MD033".to_owned())],
+            options: vec![(
+                "allowed_elements".to_owned(),
+                RubyValue::Array(vec![
+                    RubyValue::String("br".to_owned()),
+                    RubyValue::String("img".to_owned()),
+                ]),
+            )],
+        };
+        assert_eq!(actual, Some(expected));
+    }
+
+    #[test]
+    fn parser_call_unsupported() {
+        assert_eq!(Parser::new("rule 'MD013' do").call(), None);
+        assert_eq!(
+            Parser::new("rule 'MD013', :indent => 4, 'MD007'").call(),
+            None
+        );
+        assert_eq!(Parser::new("rule 'MD013").call(), None);
+        assert_eq!(Parser::new("rules.each { |r| r }").call(), None);
+    }
+
+    #[test]
+    fn strip_comment_outside_strings() {
+        assert_eq!(strip_comment("all # enable all rules"), "all ");
+        assert_eq!(
+            strip_comment("rule 'MD026', :punctuation => '#.'"),
+            "rule 'MD026', :punctuation => '#.'"
+        );
+    }
+
+    #[test]
+    fn load_style() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let text = indoc! {"
+            # Style for docs
+            all
+            exclude_rule 'MD013'
+            exclude_tag :hard_tab
+            rule 'MD003', :style => :setext_with_atx
+            rule 'MD007', :indent => 2
+            rule 'MD033', :allowed_elements => 'br, img'
+        "};
+        let path = tmp_dir.path().join("style.rb");
+        fs::write(&path, text).into_diagnostic()?;
+
+        let (actual, _) = Config::load(&path)?;
+        let mut expected = Config::default();
+        expected
+            .lint
+            .rules
+            .retain(|rule| *rule != RuleSet::MD013 && *rule != RuleSet::MD010);
+        expected.lint.md003.style = HeadingStyle::SetextWithAtx;
+        expected.lint.md007.indent = 2;
+        expected.lint.md033.allowed_elements = vec!["br".to_owned(), "img".to_owned()];
+        assert_eq!(actual, expected);
+        tmp_dir.close().into_diagnostic()
+    }
+
+    #[test]
+    fn load_mdlrc() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let style_text = indoc! {"
+            rule 'MD001'
+            rule 'MD013', :line_length => 120, :ignore_code_blocks => true
+            rule 'MD026'
+        "};
+        fs::write(tmp_dir.path().join("style.rb"), style_text).into_diagnostic()?;
+        let text = indoc! {r#"
+            style "style.rb"
+            rules "~MD026"
+            verbose true
+            json true
+        "#};
+        let path = tmp_dir.path().join(".mdlrc");
+        fs::write(&path, text).into_diagnostic()?;
+
+        let (actual, _) = Config::load(&path)?;
+        let mut expected = Config::default();
+        expected.lint.output_format = Some(Format::Json);
+        expected.lint.rules = vec![RuleSet::MD001, RuleSet::MD013];
+        expected.lint.md013.line_length = 120;
+        expected.lint.md013.code_blocks = false;
+        assert_eq!(actual, expected);
+        tmp_dir.close().into_diagnostic()
+    }
+
+    #[test]
+    fn load_mdlrc_with_default_style_and_filters() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let path = tmp_dir.path().join(".mdlrc");
+        fs::write(&path, "rules 'MD001,MD013,MD026'\ntags 'headers'\n").into_diagnostic()?;
+
+        let (actual, _) = Config::load(&path)?;
+        // Filters of rules and tags are applied in order
+        assert_eq!(actual.lint.rules, vec![RuleSet::MD001, RuleSet::MD026]);
+        tmp_dir.close().into_diagnostic()
+    }
+
+    #[test]
+    fn load_unsupported() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let path = tmp_dir.path().join("style.rb");
+        for text in [
+            "rule 'MD011'",
+            "rule 'MD009', :br_spaces => 2",
+            "rule 'MD029', :style => :zero",
+            "exclude_tag :unknown",
+            "Dir.glob('*.md')",
+            "rule 'MD013' do",
+        ] {
+            fs::write(&path, text).into_diagnostic()?;
+            assert!(Config::load(&path).is_err(), "{text}");
+        }
+
+        let mdlrc_path = tmp_dir.path().join(".mdlrc");
+        fs::write(&mdlrc_path, "style 'relaxed'").into_diagnostic()?;
+        assert!(Config::load(&mdlrc_path).is_err());
+        tmp_dir.close().into_diagnostic()
+    }
+}
@@ -0,0 +1,332 @@
+use std::sync::LazyLock;
+
+use comrak::nodes::{NodeValue, Sourcepos};
+use regex::Regex;
+
+use crate::rule::Metadata;
+use crate::{Document, Violation};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DirectiveKind {
+    Disable,
+    Enable,
+    DisableLine,
+    DisableNextLine,
+    DisableFile,
+    EnableFile,
+}
+
+impl DirectiveKind {
+    fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "disable" => Some(Self::Disable),
+            "enable" => Some(Self::Enable),
+            "disable-line" => Some(Self::DisableLine),
+            "disable-next-line" => Some(Self::DisableNextLine),
+            "disable-file" => Some(Self::DisableFile),
+            "enable-file" => Some(Self::EnableFile),
+            _ => None,
+        }
+    }
+
+    const fn is_disable(self) -> bool {
+        matches!(
+            self,
+            Self::Disable | Self::DisableLine | Self::DisableNextLine | Self::DisableFile
+        )
+    }
+}
+
+/// An inline configuration comment such as `<!-- mado-disable MD013 -->`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Directive {
+    pub kind: DirectiveKind,
+    pub position: Sourcepos,
+    /// Lowercase rule names or aliases. Empty means all rules.
+    pub rules: Vec<String>,
+}
+
+impl Directive {
+    pub(crate) const UNUSED_METADATA: Metadata = Metadata {
+        name: "unused-directive",
+        description: "Suppression directive does not suppress any violations",
+        tags: &[],
+        aliases: &["unused-directive"],
+        rationale: "Directives that no longer suppress any violations are noise, and may hide new violations later.",
+        bad_example: "<!-- mado-disable-next-line MD026 -->\n# Hello\n",
+        good_example: "# Hello\n",
+    };
+
+    fn matches(&self, violation: &Violation) -> bool {
+        self.rules.is_empty()
+            || self.rules.iter().any(|rule| {
+                rule.eq_ignore_ascii_case(violation.name())
+                    || violation
+                        .aliases()
+                        .iter()
+                        .any(|alias| rule.eq_ignore_ascii_case(alias))
+            })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Directives {
+    directives: Vec<Directive>,
+}
+
+impl Directives {
+    #[inline]
+    #[must_use]
+    pub fn from_document(doc: &Document) -> Self {
+        let mut directives = vec![];
+        for node in doc.ast.descendants() {
+            let position = node.data.borrow().sourcepos;
+            match &node.data.borrow().value {
+                NodeValue::HtmlBlock(html) => {
+                    Self::parse(&html.literal, position, &mut directives);
+                }
+                NodeValue::HtmlInline(html) => Self::parse(html, position, &mut directives),
+                _ => {}
+            }
+        }
+
+        Self { directives }
+    }
+
+    fn parse(html: &str, position: Sourcepos, directives: &mut Vec<Directive>) {
+        static RE: LazyLock<Regex> = LazyLock::new(|| {
+            #[allow(clippy::unwrap_used)]
+            Regex::new(r"<!--\s*(?:mado|markdownlint)-(disable-next-line|disable-line|disable-file|enable-file|disable|enable)((?:\s+[^\s>]+)*)\s*-->").unwrap()
+        });
+
+        for captures in RE.captures_iter(html) {
+            let (Some(whole), Some(kind)) = (
+                captures.get(0),
+                captures
+                    .get(1)
+                    .and_then(|m| DirectiveKind::parse(m.as_str())),
+            ) else {
+                continue;
+            };
+
+            let start_line = position.start.line + html[..whole.start()].matches('\n').count();
+            let end_line = start_line + whole.as_str().matches('\n').count();
+            let line_start = html[..whole.start()].rfind('\n').map_or(0, |i| i + 1);
+            let start_column = if start_line == position.start.line {
+                position.start.column + whole.start()
+            } else {
+                whole.start() - line_start + 1
+            };
+            let end_column = whole
+                .as_str()
+                .rfind('\n')
+                .map_or_else(|| start_column + whole.len() - 1, |i| whole.len() - i - 1);
+            let rules = captures
+                .get(2)
+                .map(|m| {
+                    m.as_str()
+                        .split_whitespace()
+                        .map(str::to_lowercase)
+                        .collect()
+                })
+                .unwrap_or_default();
+            directives.push(Directive {
+                kind,
+                position: Sourcepos::from((start_line, start_column, end_line, end_column)),
+                rules,
+            });
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.directives.is_empty()
+    }
+
+    /// Returns the index of the directive suppressing the violation
+    #[inline]
+    #[must_use]
+    pub fn suppressed_by(&self, violation: &Violation) -> Option<usize> {
+        let lineno = violation.position().start.line;
+        let mut range_state = None;
+        let mut file_state = None;
+
+        for (i, directive) in self.directives.iter().enumerate() {
+            if !directive.matches(violation) {
+                continue;
+            }
+
+            match directive.kind {
+                DirectiveKind::DisableLine
+                    if (directive.position.start.line..=directive.position.end.line)
+                        .contains(&lineno) =>
+                {
+                    return Some(i);
+                }
+                DirectiveKind::DisableNextLine if directive.position.end.line + 1 == lineno => {
+                    return Some(i);
+                }
+                DirectiveKind::Disable if directive.position.start.line <= lineno => {
+                    range_state = Some(i);
+                }
+                DirectiveKind::Enable if directive.position.start.line <= lineno => {
+                    range_state = None;
+                }
+                DirectiveKind::DisableFile => file_state = Some(i),
+                DirectiveKind::EnableFile => file_state = None,
+                _ => {}
+            }
+        }
+
+        range_state.or(file_state)
+    }
+
+    /// Removes suppressed violations and returns violations for directives suppressing nothing
+    #[inline]
+    pub fn apply(&self, violations: &mut Vec<Violation>, doc: &Document) -> Vec<Violation> {
+        let mut used = vec![false; self.directives.len()];
+        violations.retain(|violation| {
+            self.suppressed_by(violation).is_none_or(|i| {
+                used[i] = true;
+                false
+            })
+        });
+
+        self.directives
+            .iter()
+            .zip(used)
+            .filter(|(directive, is_used)| directive.kind.is_disable() && !is_used)
+            .map(|(directive, _)| {
+                Violation::new(
+                    doc.path.clone(),
+                    &Directive::UNUSED_METADATA,
+                    directive.position,
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use comrak::Arena;
+    use indoc::indoc;
+    use miette::Result;
+    use pretty_assertions::assert_eq;
+
+    use crate::rule::RuleLike as _;
+    use crate::rule::{MD009, MD013};
+
+    use super::*;
+
+    #[test]
+    fn from_document() -> Result<()> {
+        let text = indoc! {"
+            <!-- mado-disable MD013 no-trailing-spaces -->
+
+            Text <!-- markdownlint-disable-line -->
+
+            <!--
+            mado-enable-file
+            -->
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let actual = Directives::from_document(&doc);
+        let expected = Directives {
+            directives: vec![
+                Directive {
+                    kind: DirectiveKind::Disable,
+                    position: Sourcepos::from((1, 1, 1, 46)),
+                    rules: vec!["md013".to_owned(), "no-trailing-spaces".to_owned()],
+                },
+                Directive {
+                    kind: DirectiveKind::DisableLine,
+                    position: Sourcepos::from((3, 6, 3, 39)),
+                    rules: vec![],
+                },
+                Directive {
+                    kind: DirectiveKind::EnableFile,
+                    position: Sourcepos::from((5, 1, 7, 3)),
+                    rules: vec![],
+                },
+            ],
+        };
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn apply() -> Result<()> {
+        let text = indoc! {"
+            <!-- mado-disable-next-line MD013 -->
+            Line 2
+            Line 3 <!-- mado-disable-line no-trailing-spaces -->
+            <!-- mado-disable -->
+            Line 5
+            <!-- mado-enable MD009 -->
+            Line 7
+            <!-- mado-disable-line MD013 -->
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let directives = Directives::from_document(&doc);
+        let md009 = MD009::new();
+        let md013 = MD013::default();
+        let mut violations = vec![
+            md013.to_violation(path.clone(), Sourcepos::from((2, 1, 2, 6))),
+            md009.to_violation(path.clone(), Sourcepos::from((2, 1, 2, 6))),
+            md009.to_violation(path.clone(), Sourcepos::from((3, 1, 3, 6))),
+            md009.to_violation(path.clone(), Sourcepos::from((5, 1, 5, 6))),
+            md013.to_violation(path.clone(), Sourcepos::from((7, 1, 7, 6))),
+            md009.to_violation(path.clone(), Sourcepos::from((7, 1, 7, 6))),
+        ];
+        let unused = directives.apply(&mut violations, &doc);
+        let expected = vec![
+            md009.to_violation(path.clone(), Sourcepos::from((2, 1, 2, 6))),
+            md009.to_violation(path.clone(), Sourcepos::from((7, 1, 7, 6))),
+        ];
+        assert_eq!(violations, expected);
+        let expected_unused = vec![Violation::new(
+            path,
+            &Directive::UNUSED_METADATA,
+            Sourcepos::from((8, 1, 8, 32)),
+        )];
+        assert_eq!(unused, expected_unused);
+        Ok(())
+    }
+
+    #[test]
+    fn apply_disable_file() -> Result<()> {
+        let text = indoc! {"
+            Line 1
+
+            <!-- markdownlint-disable-file line-length -->
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let directives = Directives::from_document(&doc);
+        let md009 = MD009::new();
+        let md013 = MD013::default();
+        let mut violations = vec![
+            md013.to_violation(path.clone(), Sourcepos::from((1, 1, 1, 6))),
+            md009.to_violation(path.clone(), Sourcepos::from((1, 1, 1, 6))),
+        ];
+        let unused = directives.apply(&mut violations, &doc);
+        let expected = vec![md009.to_violation(path, Sourcepos::from((1, 1, 1, 6)))];
+        assert_eq!(violations, expected);
+        assert_eq!(unused, vec![]);
+        Ok(())
+    }
+}
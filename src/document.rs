@@ -6,6 +6,8 @@ use comrak::{parse_document, Arena, Options};
 use miette::IntoDiagnostic as _;
 use miette::Result;
 
+use crate::source;
+
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub struct Document<'a> {
@@ -13,6 +15,8 @@ pub struct Document<'a> {
     pub ast: &'a AstNode<'a>,
     pub text: String,
     pub lines: Vec<String>,
+    /// Byte offsets of the start of each line
+    pub(crate) line_offsets: Vec<usize>,
 }
 
 impl<'a> Document<'a> {
@@ -23,12 +27,14 @@ impl<'a> Document<'a> {
         options.extension.table = true;
         let ast = parse_document(arena, &text, &options);
         let lines: Vec<_> = text.lines().map(ToOwned::to_owned).collect();
+        let line_offsets = source::line_offsets(&text);
 
         Ok(Self {
             path,
             ast,
             text,
             lines,
+            line_offsets,
         })
     }
 
@@ -49,6 +55,25 @@ impl<'a> Document<'a> {
 
         None
     }
+
+    /// Returns the byte offset of the start of the given 1-based line
+    #[inline]
+    #[must_use]
+    pub fn line_offset(&self, lineno: usize) -> Option<usize> {
+        self.line_offsets.get(lineno.checked_sub(1)?).copied()
+    }
+
+    /// Returns the byte offset of the given 1-based line and column
+    #[inline]
+    #[must_use]
+    pub fn offset(&self, lineno: usize, column: usize) -> Option<usize> {
+        let line = self.lines.get(lineno.checked_sub(1)?)?;
+        if column == 0 || column > line.len() + 1 {
+            return None;
+        }
+
+        self.line_offset(lineno).map(|offset| offset + column - 1)
+    }
 }
 
 #[cfg(test)]
@@ -92,6 +117,35 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn line_offset() -> Result<()> {
+        let text = "foo\r\nbar\n\nbaz".to_owned();
+        let arena = Arena::new();
+        let path = Path::new("test.md").to_path_buf();
+        let doc = Document::new(&arena, path, text)?;
+        assert_eq!(doc.line_offset(0), None);
+        assert_eq!(doc.line_offset(1), Some(0));
+        assert_eq!(doc.line_offset(2), Some(5));
+        assert_eq!(doc.line_offset(3), Some(9));
+        assert_eq!(doc.line_offset(4), Some(10));
+        assert_eq!(doc.line_offset(5), None);
+        Ok(())
+    }
+
+    #[test]
+    fn offset() -> Result<()> {
+        let text = "foo\nbar\n".to_owned();
+        let arena = Arena::new();
+        let path = Path::new("test.md").to_path_buf();
+        let doc = Document::new(&arena, path, text)?;
+        assert_eq!(doc.offset(1, 1), Some(0));
+        assert_eq!(doc.offset(2, 3), Some(6));
+        assert_eq!(doc.offset(2, 4), Some(7));
+        assert_eq!(doc.offset(2, 5), None);
+        assert_eq!(doc.offset(0, 1), None);
+        Ok(())
+    }
+
     #[test]
     fn front_matter_empty() -> Result<()> {
         let text = String::new();
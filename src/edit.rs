@@ -0,0 +1,85 @@
+use core::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Edit {
+    start: usize,
+    end: usize,
+    replacement: String,
+}
+
+impl Edit {
+    #[inline]
+    #[must_use]
+    pub const fn new(range: Range<usize>, replacement: String) -> Self {
+        Self {
+            start: range.start,
+            end: range.end,
+            replacement,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn insertion(offset: usize, content: String) -> Self {
+        Self::new(offset..offset, content)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn deletion(range: Range<usize>) -> Self {
+        Self::new(range, String::new())
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn replacement(&self) -> &str {
+        &self.replacement
+    }
+
+    /// Applies edits to the text. Edits must be sorted and must not overlap.
+    #[inline]
+    #[must_use]
+    pub fn apply_all(text: &str, edits: &[&Self]) -> String {
+        let mut fixed = String::with_capacity(text.len());
+        let mut last_end = 0;
+        for edit in edits {
+            fixed.push_str(&text[last_end..edit.start]);
+            fixed.push_str(&edit.replacement);
+            last_end = edit.end;
+        }
+        fixed.push_str(&text[last_end..]);
+        fixed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn apply_all() {
+        let text = "#Heading  \nText\tbody";
+        let edit1 = Edit::insertion(1, " ".to_owned());
+        let edit2 = Edit::deletion(8..10);
+        let edit3 = Edit::new(15..16, "    ".to_owned());
+        let actual = Edit::apply_all(text, &[&edit1, &edit2, &edit3]);
+        assert_eq!(actual, "# Heading\nText    body");
+    }
+
+    #[test]
+    fn apply_all_empty() {
+        let text = "Text";
+        let actual = Edit::apply_all(text, &[]);
+        assert_eq!(actual, text);
+    }
+}
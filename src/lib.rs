@@ -5,15 +5,22 @@ mod cli;
 pub mod collection;
 pub mod command;
 pub mod config;
+mod directive;
 mod document;
+mod edit;
 mod output;
 pub mod rule;
 pub mod service;
+mod severity;
+mod source;
 mod violation;
 
 pub use cli::Cli;
 pub use command::Command;
 pub use config::Config;
 pub use document::Document;
+pub use edit::Edit;
 pub use rule::Rule;
+pub use severity::Severity;
+pub use source::Source;
 pub use violation::Violation;
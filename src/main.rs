@@ -15,6 +15,8 @@ static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
+use std::env;
+use std::path::Path;
 use std::process::ExitCode;
 
 use clap::CommandFactory as _;
@@ -22,11 +24,17 @@ use clap::Parser as _;
 use mado::command::check::Options;
 use miette::Result;
 
-use mado::command::check::Checker;
+use mado::command::check::{Checker, Watcher};
+use mado::command::clean::Cleaner;
 use mado::command::generate_shell_completion::ShellCompletionGenerator;
+use mado::command::init::Initializer;
+use mado::command::rules::{RuleExplainer, RuleLister};
+use mado::command::server::Server;
 use mado::command::CompletionShell;
+use mado::service::cache::Cache;
 use mado::Cli;
 use mado::Command;
+use mado::Config;
 
 fn main() -> Result<ExitCode> {
     let cli = Cli::parse();
@@ -34,20 +42,72 @@ fn main() -> Result<ExitCode> {
     match &cli.command {
         Command::Check {
             files,
+            stdin_filename,
             output_format,
             quiet,
+            fix,
             exclude,
+            max_warnings,
+            watch,
+            no_cache,
+            diff_base,
+            baseline,
+            write_baseline,
         } => {
             let options = Options {
                 output_format: output_format.clone(),
                 config_path: cli.config,
                 quiet: *quiet,
+                fix: *fix,
                 exclude: exclude.clone(),
+                max_warnings: *max_warnings,
+                no_cache: *no_cache,
+                diff_base: diff_base.clone(),
+                baseline: baseline.clone(),
+                write_baseline: write_baseline.clone(),
+                stdin_filename: stdin_filename.clone(),
+                github_actions: env::var("GITHUB_ACTIONS").is_ok_and(|value| value == "true"),
             };
-            let config = options.to_config()?;
-            let checker = Checker::new(files, config)?;
+            if *watch {
+                let watcher = Watcher::new(files, options)?;
+                return watcher.watch();
+            }
+
+            let (config, warnings) = options.to_config()?;
+            let run_options = options.to_run_options(&config);
+            let checker = Checker::new(files, config, run_options)?.with_warnings(warnings);
             checker.check()
         }
+        Command::Init { files, infer } => {
+            let config_path = cli
+                .config
+                .unwrap_or_else(|| Path::new(Config::FILE_NAME).to_path_buf());
+            let config = load_init_config(*infer)?;
+            let initializer = Initializer::new(files, &config_path, &config.lint, *infer);
+            initializer.init()
+        }
+        Command::Clean => {
+            let cache_dirs: Vec<_> = Cache::root().into_iter().collect();
+            let cleaner = Cleaner::new(&cache_dirs);
+            cleaner.clean()
+        }
+        Command::Rules { output_format } => {
+            let config = load_config(cli.config.as_deref())?;
+            let lister = RuleLister::new(&config.lint, output_format.clone())?;
+            lister.list()
+        }
+        Command::Rule {
+            name,
+            output_format,
+        } => {
+            let config = load_config(cli.config.as_deref())?;
+            let explainer = RuleExplainer::new(name, &config.lint, output_format.clone())?;
+            explainer.explain()
+        }
+        Command::Server => {
+            let server = Server::new(cli.config);
+            server.serve()
+        }
         Command::GenerateShellCompletion { shell } => {
             let cmd = Cli::command();
             let mut generator = ShellCompletionGenerator::new(cmd);
@@ -78,3 +138,21 @@ fn main() -> Result<ExitCode> {
         }
     }
 }
+
+// Loads the specified config file or the nearest one, printing warnings of the config file
+fn load_config(config_path: Option<&Path>) -> Result<Config> {
+    let (config, warnings) = config_path.map_or_else(Config::resolve, Config::load)?;
+    for warning in warnings {
+        eprintln!("warning: {warning}");
+    }
+    Ok(config)
+}
+
+// Loads the existing config such as `.markdownlint.json` to select files to infer styles from
+fn load_init_config(infer: bool) -> Result<Config> {
+    if infer {
+        load_config(None)
+    } else {
+        Ok(Config::default())
+    }
+}
@@ -4,32 +4,57 @@ use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 
 mod concise;
+mod full;
+mod github;
+mod json;
 mod markdownlint;
 mod mdl;
+mod sarif;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
 #[serde(rename_all = "lowercase")]
 pub enum Format {
     Concise,
+    #[serde(alias = "pretty")]
+    #[value(alias = "pretty")]
+    Full,
     Mdl,
     Markdownlint,
+    Json,
+    #[serde(rename = "json-lines")]
+    JsonLines,
+    Sarif,
+    Github,
 }
 
 impl Format {
+    /// Returns true if the format reports whether violations are fixable
+    #[inline]
+    #[must_use]
+    pub const fn reports_fixable(&self) -> bool {
+        matches!(self, Self::Full | Self::Json | Self::JsonLines)
+    }
+
     #[inline]
     #[must_use]
     pub fn sorter(&self) -> fn(a: &Violation, b: &Violation) -> Ordering {
         match self {
-            Self::Concise => |a, b| Concise::new(a).cmp(&Concise::new(b)),
+            Self::Concise | Self::Full => |a, b| Concise::new(a).cmp(&Concise::new(b)),
             Self::Mdl => |a, b| Mdl::new(a).cmp(&Mdl::new(b)),
             Self::Markdownlint => |a, b| Markdownlint::new(a).cmp(&Markdownlint::new(b)),
+            Self::Json | Self::JsonLines | Self::Sarif => |a, b| Json::new(a).cmp(&Json::new(b)),
+            Self::Github => |a, b| Github::new(a).cmp(&Github::new(b)),
         }
     }
 }
 
 pub use concise::Concise;
+pub use full::Full;
+pub use github::Github;
+pub use json::{Json, JsonLine, JsonReport, Summary};
 pub use markdownlint::Markdownlint;
 pub use mdl::Mdl;
+pub use sarif::Sarif;
 
 use crate::Violation;
 
@@ -114,4 +139,26 @@ mod tests {
         let expected: Vec<_> = outputs.iter().map(|o| o.violation().clone()).collect();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn sorter_json() {
+        let violations = violations();
+        let mut actual = violations.clone();
+        actual.sort_by(Format::Json.sorter());
+        let mut outputs: Vec<_> = violations.iter().map(Json::new).collect();
+        outputs.sort();
+        let expected: Vec<_> = outputs.iter().map(|o| o.violation().clone()).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn sorter_github() {
+        let violations = violations();
+        let mut actual = violations.clone();
+        actual.sort_by(Format::Github.sorter());
+        let mut outputs: Vec<_> = violations.iter().map(Github::new).collect();
+        outputs.sort();
+        let expected: Vec<_> = outputs.iter().map(|o| o.violation().clone()).collect();
+        assert_eq!(actual, expected);
+    }
 }
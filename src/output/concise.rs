@@ -3,7 +3,7 @@ use core::fmt::{Display, Error, Formatter, Result};
 
 use colored::Colorize as _;
 
-use crate::Violation;
+use crate::{Severity, Violation};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Concise<'a> {
@@ -25,16 +25,24 @@ impl Display for Concise<'_> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         let path = self.violation.path().to_str().ok_or(Error)?;
+        let severity = self.violation.severity();
         write!(
             f,
-            "{}{}{}{}{}{} {} {}",
+            "{}{}{}{}{}{} ",
             path.bold(),
             ":".blue(),
             self.violation.position().start.line,
             ":".blue(),
             self.violation.position().start.column,
             ":".blue(),
-            self.violation.name().red().bold(),
+        )?;
+        if severity != Severity::Error {
+            write!(f, "{} ", severity.as_str().color(severity.color()))?;
+        }
+        write!(
+            f,
+            "{} {}",
+            self.violation.name().color(severity.color()).bold(),
             self.violation.description()
         )
     }
@@ -69,6 +77,9 @@ mod tests {
         name: "name",
         description: "description",
         aliases: &["alias"],
+        rationale: "rationale",
+        bad_example: "bad",
+        good_example: "good",
         tags: &[Tag::Atx],
     };
 
@@ -81,4 +92,14 @@ mod tests {
         let expected = "\u{1b}[1mfile.md\u{1b}[0m\u{1b}[34m:\u{1b}[0m0\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mname\u{1b}[0m description";
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn display_fmt_warning() {
+        let path = Path::new("file.md").to_path_buf();
+        let position = Sourcepos::from((0, 1, 3, 5));
+        let violation = Violation::new(path, &METADATA, position).with_severity(Severity::Warning);
+        let actual = Concise::new(&violation).to_string();
+        let expected = "\u{1b}[1mfile.md\u{1b}[0m\u{1b}[34m:\u{1b}[0m0\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[33mwarning\u{1b}[0m \u{1b}[1;33mname\u{1b}[0m description";
+        assert_eq!(actual, expected);
+    }
 }
@@ -0,0 +1,326 @@
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::cmp::Ordering;
+use core::error::Error as StdError;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::iter;
+
+use colored::control::SHOULD_COLORIZE;
+
+use miette::{
+    Diagnostic, GraphicalReportHandler, GraphicalTheme, LabeledSpan, MietteError,
+    MietteSpanContents, SourceCode, SourceSpan, SpanContents,
+};
+
+use crate::{Severity, Source, Violation};
+
+/// A violation as a miette diagnostic with source snippets
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Full<'a> {
+    violation: &'a Violation,
+    source: &'a Arc<Source>,
+    color: bool,
+}
+
+impl<'a> Full<'a> {
+    #[inline]
+    #[must_use]
+    pub fn new(violation: &'a Violation, source: &'a Arc<Source>) -> Self {
+        Self {
+            violation,
+            source,
+            color: SHOULD_COLORIZE.should_colorize(),
+        }
+    }
+
+    fn to_diagnostic(&self) -> ViolationDiagnostic<'a> {
+        let position = self.violation.position();
+        let start = self
+            .source
+            .offset(position.start.line, position.start.column);
+        // Sourcepos end columns are inclusive
+        let end = self
+            .source
+            .offset(position.end.line, position.end.column + 1)
+            .max(start);
+        let path = self.violation.path();
+        let name = path.strip_prefix(".").unwrap_or(path).to_string_lossy();
+
+        let help = if self.violation.is_fixable() {
+            Some("Run with `--fix` to fix this violation".to_owned())
+        } else if self.violation.name().starts_with("MD") {
+            Some(format!(
+                "Run `mado rule {}` for the rationale and examples",
+                self.violation.name()
+            ))
+        } else {
+            None
+        };
+
+        ViolationDiagnostic {
+            violation: self.violation,
+            snippet: Snippet {
+                name: name.into_owned(),
+                source: Arc::clone(self.source),
+            },
+            span: SourceSpan::from(start..end),
+            help,
+        }
+    }
+}
+
+impl Display for Full<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let theme = if self.color {
+            GraphicalTheme::unicode()
+        } else {
+            GraphicalTheme::unicode_nocolor()
+        };
+        let handler = GraphicalReportHandler::new_themed(theme);
+        let mut output = String::new();
+        handler.render_report(&mut output, &self.to_diagnostic())?;
+        write!(f, "{}", output.trim_end())
+    }
+}
+
+impl PartialOrd for Full<'_> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Full<'_> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.violation.cmp(other.violation)
+    }
+}
+
+struct ViolationDiagnostic<'a> {
+    violation: &'a Violation,
+    snippet: Snippet,
+    span: SourceSpan,
+    help: Option<String>,
+}
+
+/// A named source shared by violations of a file
+struct Snippet {
+    name: String,
+    source: Arc<Source>,
+}
+
+impl SourceCode for Snippet {
+    // Reads lines around the span only instead of scanning the whole text
+    #[inline]
+    fn read_span<'a>(
+        &'a self,
+        span: &SourceSpan,
+        context_lines_before: usize,
+        context_lines_after: usize,
+    ) -> Result<Box<dyn SpanContents<'a> + 'a>, MietteError> {
+        let first_line = self
+            .source
+            .line_index(span.offset())
+            .saturating_sub(context_lines_before);
+        let last_line = self.source.line_index(span.offset() + span.len());
+        let start = self.source.line_start(first_line);
+        // One more line to find the end of the context
+        let end = self
+            .source
+            .line_start(last_line + context_lines_after + 2)
+            .max(span.offset() + span.len());
+        let window = self
+            .source
+            .text()
+            .get(start..end)
+            .ok_or(MietteError::OutOfBounds)?;
+        let window_span = SourceSpan::new((span.offset() - start).into(), span.len());
+        let contents = window.read_span(&window_span, context_lines_before, context_lines_after)?;
+        Ok(Box::new(MietteSpanContents::new_named(
+            self.name.clone(),
+            contents.data(),
+            SourceSpan::new(
+                (contents.span().offset() + start).into(),
+                contents.span().len(),
+            ),
+            contents.line() + first_line,
+            contents.column(),
+            contents.line_count() + first_line,
+        )))
+    }
+}
+
+impl Debug for ViolationDiagnostic<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.violation, f)
+    }
+}
+
+impl Display for ViolationDiagnostic<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.violation.description())
+    }
+}
+
+impl StdError for ViolationDiagnostic<'_> {}
+
+impl Diagnostic for ViolationDiagnostic<'_> {
+    #[inline]
+    fn code<'b>(&'b self) -> Option<Box<dyn Display + 'b>> {
+        let mut code = self.violation.name().to_owned();
+        for alias in self.violation.aliases() {
+            if *alias != self.violation.name() {
+                code.push('/');
+                code.push_str(alias);
+            }
+        }
+        Some(Box::new(code))
+    }
+
+    #[inline]
+    fn severity(&self) -> Option<miette::Severity> {
+        match self.violation.severity() {
+            Severity::Warning => Some(miette::Severity::Warning),
+            Severity::Info | Severity::Off => Some(miette::Severity::Advice),
+            Severity::Error => Some(miette::Severity::Error),
+        }
+    }
+
+    #[inline]
+    fn help<'b>(&'b self) -> Option<Box<dyn Display + 'b>> {
+        self.help
+            .as_ref()
+            .map(|help| Box::new(help) as Box<dyn Display>)
+    }
+
+    #[inline]
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.snippet)
+    }
+
+    #[inline]
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let label = LabeledSpan::new_primary_with_span(None, self.span);
+        Some(Box::new(iter::once(label)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use comrak::nodes::Sourcepos;
+    use pretty_assertions::assert_eq;
+
+    use crate::rule::{Metadata, Tag};
+    use crate::Edit;
+
+    use super::*;
+
+    const METADATA: Metadata = Metadata {
+        name: "MD999",
+        description: "description",
+        aliases: &["alias"],
+        rationale: "rationale",
+        bad_example: "bad",
+        good_example: "good",
+        tags: &[Tag::Atx],
+    };
+
+    #[test]
+    fn display_fmt() {
+        let path = Path::new("./file.md").to_path_buf();
+        let position = Sourcepos::from((2, 3, 2, 5));
+        let violation = Violation::new(path, &METADATA, position);
+        let source = Arc::new(Source::new(
+            violation.path().clone(),
+            "# Title\n\nfoo bar baz\n".to_owned(),
+        ));
+        let full = Full {
+            violation: &violation,
+            source: &source,
+            color: false,
+        };
+        let actual = full.to_string();
+        let expected = concat!(
+            "MD999/alias\n",
+            "\n",
+            "  × description\n",
+            "   ╭─[file.md:2:1]\n",
+            " 1 │ # Title\n",
+            " 2 │ \n",
+            "   · ▲\n",
+            " 3 │ foo bar baz\n",
+            "   ╰────\n",
+            "  help: Run `mado rule MD999` for the rationale and examples",
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn display_fmt_fixable_warning() {
+        let path = Path::new("file.md").to_path_buf();
+        let position = Sourcepos::from((3, 5, 3, 7));
+        let violation = Violation::new(path, &METADATA, position)
+            .with_edits(vec![Edit::deletion(0..1)])
+            .with_severity(Severity::Warning);
+        let source = Arc::new(Source::new(
+            violation.path().clone(),
+            "# Title\n\nfoo bar baz\n".to_owned(),
+        ));
+        let full = Full {
+            violation: &violation,
+            source: &source,
+            color: false,
+        };
+        let actual = full.to_string();
+        let expected = concat!(
+            "MD999/alias\n",
+            "\n",
+            "  ⚠ description\n",
+            "   ╭─[file.md:3:5]\n",
+            " 2 │ \n",
+            " 3 │ foo bar baz\n",
+            "   ·     ───\n",
+            "   ╰────\n",
+            "  help: Run with `--fix` to fix this violation",
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn display_fmt_long_source() {
+        let path = Path::new("file.md").to_path_buf();
+        let position = Sourcepos::from((50, 1, 50, 7));
+        let violation = Violation::new(path, &METADATA, position);
+        let text = (1..=100).fold(String::new(), |mut text, i| {
+            text.push_str(&format!("line {i:03}\n"));
+            text
+        });
+        let source = Arc::new(Source::new(violation.path().clone(), text));
+        let full = Full {
+            violation: &violation,
+            source: &source,
+            color: false,
+        };
+        let actual = full.to_string();
+        let expected = concat!(
+            "MD999/alias\n",
+            "\n",
+            "  × description\n",
+            "    ╭─[file.md:50:1]\n",
+            " 49 │ line 049\n",
+            " 50 │ line 050\n",
+            "    · ───────\n",
+            " 51 │ line 051\n",
+            "    ╰────\n",
+            "  help: Run `mado rule MD999` for the rationale and examples",
+        );
+        assert_eq!(actual, expected);
+    }
+}
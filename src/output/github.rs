@@ -0,0 +1,116 @@
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter, Result};
+
+use crate::{Severity, Violation};
+
+/// A violation as a workflow command of GitHub Actions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Github<'a> {
+    violation: &'a Violation,
+}
+
+impl<'a> Github<'a> {
+    pub const fn new(violation: &'a Violation) -> Self {
+        Self { violation }
+    }
+
+    #[cfg(test)]
+    pub const fn violation(&self) -> &'a Violation {
+        self.violation
+    }
+
+    fn escape_data(data: &str) -> String {
+        data.replace('%', "%25")
+            .replace('\r', "%0D")
+            .replace('\n', "%0A")
+    }
+
+    fn escape_property(property: &str) -> String {
+        Self::escape_data(property)
+            .replace(':', "%3A")
+            .replace(',', "%2C")
+    }
+}
+
+impl Display for Github<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let path = self.violation.path();
+        let path = path.strip_prefix(".").unwrap_or(path).to_string_lossy();
+        let position = self.violation.position();
+        let command = match self.violation.severity() {
+            Severity::Warning => "warning",
+            Severity::Info | Severity::Off => "notice",
+            Severity::Error => "error",
+        };
+        write!(
+            f,
+            "::{command} file={},line={},col={},endLine={},endColumn={},title={}::{}",
+            Self::escape_property(&path),
+            position.start.line,
+            position.start.column,
+            position.end.line,
+            position.end.column,
+            Self::escape_property(self.violation.name()),
+            Self::escape_data(self.violation.description())
+        )
+    }
+}
+
+impl PartialOrd for Github<'_> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Github<'_> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.violation.cmp(other.violation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use comrak::nodes::Sourcepos;
+    use pretty_assertions::assert_eq;
+
+    use crate::rule::{Metadata, Tag};
+
+    use super::*;
+
+    const METADATA: Metadata = Metadata {
+        name: "name",
+        description: "description",
+        aliases: &["alias"],
+        rationale: "rationale",
+        bad_example: "bad",
+        good_example: "good",
+        tags: &[Tag::Atx],
+    };
+
+    #[test]
+    fn display_fmt() {
+        let path = Path::new("./docs/file,1.md").to_path_buf();
+        let position = Sourcepos::from((1, 2, 3, 5));
+        let violation = Violation::new(path, &METADATA, position);
+        let actual = Github::new(&violation).to_string();
+        let expected =
+            "::error file=docs/file%2C1.md,line=1,col=2,endLine=3,endColumn=5,title=name::description";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn display_fmt_info() {
+        let path = Path::new("file.md").to_path_buf();
+        let position = Sourcepos::from((1, 2, 3, 5));
+        let violation = Violation::new(path, &METADATA, position).with_severity(Severity::Info);
+        let actual = Github::new(&violation).to_string();
+        let expected =
+            "::notice file=file.md,line=1,col=2,endLine=3,endColumn=5,title=name::description";
+        assert_eq!(actual, expected);
+    }
+}
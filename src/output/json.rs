@@ -0,0 +1,271 @@
+use core::cmp::Ordering;
+use core::fmt::{Display, Error, Formatter, Result};
+use core::result;
+use std::path::Path;
+
+use serde::ser::{SerializeStruct as _, Serializer};
+use serde::Serialize;
+
+use crate::rule::Tag;
+use crate::{Severity, Violation};
+
+/// A violation in JSON. See `pkg/json-schema/output.json` for the schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Json<'a> {
+    violation: &'a Violation,
+}
+
+impl<'a> Json<'a> {
+    pub const fn new(violation: &'a Violation) -> Self {
+        Self { violation }
+    }
+
+    #[cfg(test)]
+    pub const fn violation(&self) -> &'a Violation {
+        self.violation
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+struct Point {
+    line: usize,
+    column: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+struct Position {
+    start: Point,
+    end: Point,
+}
+
+impl Serialize for Json<'_> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> result::Result<S::Ok, S::Error> {
+        let position = self.violation.position();
+        let position = Position {
+            start: Point {
+                line: position.start.line,
+                column: position.start.column,
+            },
+            end: Point {
+                line: position.end.line,
+                column: position.end.column,
+            },
+        };
+
+        let mut state = serializer.serialize_struct("Violation", 8)?;
+        state.serialize_field::<Path>("path", self.violation.path())?;
+        state.serialize_field("rule", self.violation.name())?;
+        state.serialize_field("aliases", self.violation.aliases())?;
+        state.serialize_field("description", self.violation.description())?;
+        state.serialize_field("severity", &self.violation.severity())?;
+        state.serialize_field::<[Tag]>("tags", self.violation.tags())?;
+        state.serialize_field("position", &position)?;
+        state.serialize_field("fixable", &self.violation.is_fixable())?;
+        state.end()
+    }
+}
+
+impl Display for Json<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let json = serde_json::to_string(self).map_err(|_err| Error)?;
+        write!(f, "{json}")
+    }
+}
+
+impl PartialOrd for Json<'_> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Json<'_> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.violation.cmp(other.violation)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Summary {
+    /// Number of violations found including fixed ones
+    violations: usize,
+    fixed: usize,
+    remaining: usize,
+    /// Numbers of remaining violations by severity
+    errors: usize,
+    warnings: usize,
+    info: usize,
+}
+
+impl Summary {
+    pub fn new(num_fixed: usize, remaining: &[Violation]) -> Self {
+        let count = |severity| {
+            remaining
+                .iter()
+                .filter(|violation| violation.severity() == severity)
+                .count()
+        };
+
+        Self {
+            violations: num_fixed + remaining.len(),
+            fixed: num_fixed,
+            remaining: remaining.len(),
+            errors: count(Severity::Error),
+            warnings: count(Severity::Warning),
+            info: count(Severity::Info),
+        }
+    }
+}
+
+/// The whole output of `--output-format json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct JsonReport<'a> {
+    version: u32,
+    violations: Vec<Json<'a>>,
+    summary: Summary,
+}
+
+impl<'a> JsonReport<'a> {
+    const VERSION: u32 = 1;
+
+    pub fn new(violations: &'a [Violation], summary: Summary) -> Self {
+        Self {
+            version: Self::VERSION,
+            violations: violations.iter().map(Json::new).collect(),
+            summary,
+        }
+    }
+}
+
+impl Display for JsonReport<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let json = serde_json::to_string_pretty(self).map_err(|_err| Error)?;
+        write!(f, "{json}")
+    }
+}
+
+/// A line of `--output-format json-lines`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum JsonLine<'a> {
+    Violation(Json<'a>),
+    Summary(Summary),
+}
+
+impl Display for JsonLine<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let json = serde_json::to_string(self).map_err(|_err| Error)?;
+        write!(f, "{json}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use comrak::nodes::Sourcepos;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use crate::rule::Metadata;
+    use crate::Edit;
+
+    use super::*;
+
+    const METADATA: Metadata = Metadata {
+        name: "name",
+        description: "description",
+        aliases: &["alias"],
+        rationale: "rationale",
+        bad_example: "bad",
+        good_example: "good",
+        tags: &[Tag::Atx, Tag::HardTab],
+    };
+
+    #[test]
+    fn display_fmt() {
+        let path = Path::new("file.md").to_path_buf();
+        let position = Sourcepos::from((0, 1, 3, 5));
+        let violation = Violation::new(path, &METADATA, position);
+        let actual = Json::new(&violation).to_string();
+        let expected = r#"{"path":"file.md","rule":"name","aliases":["alias"],"description":"description","severity":"error","tags":["atx","hard-tab"],"position":{"start":{"line":0,"column":1},"end":{"line":3,"column":5}},"fixable":false}"#;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn json_report_display_fmt() {
+        let path = Path::new("file.md").to_path_buf();
+        let position = Sourcepos::from((1, 1, 1, 5));
+        let violation =
+            Violation::new(path, &METADATA, position).with_edits(vec![Edit::deletion(0..1)]);
+        let violations = [violation];
+        let actual = JsonReport::new(&violations, Summary::new(1, &violations)).to_string();
+        let expected = indoc! {r#"
+            {
+              "version": 1,
+              "violations": [
+                {
+                  "path": "file.md",
+                  "rule": "name",
+                  "aliases": [
+                    "alias"
+                  ],
+                  "description": "description",
+                  "severity": "error",
+                  "tags": [
+                    "atx",
+                    "hard-tab"
+                  ],
+                  "position": {
+                    "start": {
+                      "line": 1,
+                      "column": 1
+                    },
+                    "end": {
+                      "line": 1,
+                      "column": 5
+                    }
+                  },
+                  "fixable": true
+                }
+              ],
+              "summary": {
+                "violations": 2,
+                "fixed": 1,
+                "remaining": 1,
+                "errors": 1,
+                "warnings": 0,
+                "info": 0
+              }
+            }"#};
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn json_line_violation_display_fmt() {
+        let path = Path::new("file.md").to_path_buf();
+        let position = Sourcepos::from((1, 1, 1, 5));
+        let violation = Violation::new(path, &METADATA, position);
+        let actual = JsonLine::Violation(Json::new(&violation)).to_string();
+        let expected = r#"{"type":"violation","path":"file.md","rule":"name","aliases":["alias"],"description":"description","severity":"error","tags":["atx","hard-tab"],"position":{"start":{"line":1,"column":1},"end":{"line":1,"column":5}},"fixable":false}"#;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn json_line_summary_display_fmt() {
+        let path = Path::new("file.md").to_path_buf();
+        let position = Sourcepos::from((1, 1, 1, 5));
+        let violations = [
+            Violation::new(path.clone(), &METADATA, position).with_severity(Severity::Warning),
+            Violation::new(path, &METADATA, position).with_severity(Severity::Info),
+        ];
+        let actual = JsonLine::Summary(Summary::new(0, &violations)).to_string();
+        let expected = r#"{"type":"summary","violations":2,"fixed":0,"remaining":2,"errors":0,"warnings":1,"info":1}"#;
+        assert_eq!(actual, expected);
+    }
+}
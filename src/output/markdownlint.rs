@@ -3,7 +3,7 @@ use core::fmt::{Display, Error, Formatter, Result};
 
 use colored::Colorize as _;
 
-use crate::Violation;
+use crate::{Severity, Violation};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Markdownlint<'a> {
@@ -26,19 +26,26 @@ impl Display for Markdownlint<'_> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         let path = self.violation.path().to_str().ok_or(Error)?;
+        let severity = self.violation.severity();
+        let label = if severity == Severity::Error {
+            String::new()
+        } else {
+            format!("{severity} ")
+        };
         write!(
             f,
             "{}",
             format!(
-                "{}:{}:{} {}/{} {}",
+                "{}:{}:{} {}{}/{} {}",
                 path,
                 self.violation.position().start.line,
                 self.violation.position().start.column,
+                label,
                 self.violation.name(),
                 self.violation.alias(),
                 self.violation.description()
             )
-            .red()
+            .color(severity.color())
         )
     }
 }
@@ -72,6 +79,9 @@ mod tests {
         name: "name",
         description: "description",
         aliases: &["alias"],
+        rationale: "rationale",
+        bad_example: "bad",
+        good_example: "good",
         tags: &[Tag::Atx],
     };
 
@@ -84,4 +94,14 @@ mod tests {
         let expected = "\u{1b}[31mfile.md:0:1 name/alias description\u{1b}[0m";
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn display_fmt_warning() {
+        let path = Path::new("file.md").to_path_buf();
+        let position = Sourcepos::from((0, 1, 3, 5));
+        let violation = Violation::new(path, &METADATA, position).with_severity(Severity::Warning);
+        let actual = Markdownlint::new(&violation).to_string();
+        let expected = "\u{1b}[33mfile.md:0:1 warning name/alias description\u{1b}[0m";
+        assert_eq!(actual, expected);
+    }
 }
@@ -0,0 +1,304 @@
+use core::fmt::{Display, Error, Formatter, Result};
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::rule::{Metadata, Tag};
+use crate::{Rule, Severity, Violation};
+
+/// Violations in SARIF 2.1.0
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sarif<'a> {
+    violations: &'a [Violation],
+}
+
+impl<'a> Sarif<'a> {
+    const SCHEMA: &'static str = "https://json.schemastore.org/sarif-2.1.0.json";
+    const VERSION: &'static str = "2.1.0";
+    const INFORMATION_URI: &'static str = "https://github.com/akiomik/mado";
+
+    pub const fn new(violations: &'a [Violation]) -> Self {
+        Self { violations }
+    }
+
+    fn to_log(&self) -> Log<'a> {
+        // All rules and rules that are not in the catalogue such as unused-directive
+        let mut catalogue: Vec<&'static Metadata> =
+            Rule::all().iter().map(Rule::metadata).collect();
+        for violation in self.violations {
+            if !catalogue
+                .iter()
+                .any(|metadata| metadata.name == violation.name())
+            {
+                catalogue.push(violation.metadata());
+            }
+        }
+
+        let results = self
+            .violations
+            .iter()
+            .map(|violation| {
+                let rule_index = catalogue
+                    .iter()
+                    .position(|metadata| metadata.name == violation.name())
+                    .unwrap_or_default();
+                SarifResult::new(violation, rule_index)
+            })
+            .collect();
+        let rules = catalogue
+            .into_iter()
+            .map(ReportingDescriptor::new)
+            .collect();
+
+        Log {
+            schema: Self::SCHEMA,
+            version: Self::VERSION,
+            runs: vec![Run {
+                tool: Tool {
+                    driver: Driver {
+                        name: "mado",
+                        version: env!("CARGO_PKG_VERSION"),
+                        information_uri: Self::INFORMATION_URI,
+                        rules,
+                    },
+                },
+                results,
+            }],
+        }
+    }
+}
+
+impl Display for Sarif<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let json = serde_json::to_string_pretty(&self.to_log()).map_err(|_err| Error)?;
+        write!(f, "{json}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct Log<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct Run<'a> {
+    tool: Tool,
+    results: Vec<SarifResult<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<ReportingDescriptor>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingDescriptor {
+    id: &'static str,
+    name: &'static str,
+    short_description: Message<'static>,
+    properties: RuleProperties,
+}
+
+impl ReportingDescriptor {
+    fn new(metadata: &'static Metadata) -> Self {
+        Self {
+            id: metadata.name,
+            name: metadata.aliases.first().copied().unwrap_or(metadata.name),
+            short_description: Message {
+                text: metadata.description,
+            },
+            properties: RuleProperties {
+                aliases: metadata.aliases,
+                tags: metadata.tags,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct RuleProperties {
+    aliases: &'static [&'static str],
+    tags: &'static [Tag],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct Message<'a> {
+    text: &'a str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult<'a> {
+    rule_id: &'a str,
+    rule_index: usize,
+    level: &'static str,
+    message: Message<'a>,
+    locations: Vec<Location>,
+}
+
+impl<'a> SarifResult<'a> {
+    fn new(violation: &'a Violation, rule_index: usize) -> Self {
+        let position = violation.position();
+        let start_line = position.start.line.max(1);
+        let start_column = position.start.column.max(1);
+        let region = Region {
+            start_line,
+            start_column,
+            end_line: position.end.line.max(start_line),
+            // SARIF end columns are exclusive
+            end_column: position.end.column + 1,
+        };
+
+        Self {
+            rule_id: violation.name(),
+            rule_index,
+            level: match violation.severity() {
+                Severity::Warning => "warning",
+                Severity::Info | Severity::Off => "note",
+                Severity::Error => "error",
+            },
+            message: Message {
+                text: violation.description(),
+            },
+            locations: vec![Location {
+                physical_location: PhysicalLocation {
+                    artifact_location: ArtifactLocation {
+                        uri: to_uri(violation.path()),
+                    },
+                    region,
+                },
+            }],
+        }
+    }
+}
+
+// Converts a path to a relative URI reference with forward slashes
+fn to_uri(path: &Path) -> String {
+    let path = path.strip_prefix(".").unwrap_or(path);
+    path.to_string_lossy().replace('\\', "/")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use comrak::nodes::Sourcepos;
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value};
+
+    use crate::rule::RuleLike as _;
+    use crate::rule::MD026;
+
+    use super::*;
+
+    const METADATA: Metadata = Metadata {
+        name: "name",
+        description: "description",
+        aliases: &["alias"],
+        rationale: "rationale",
+        bad_example: "bad",
+        good_example: "good",
+        tags: &[Tag::Atx],
+    };
+
+    #[test]
+    fn display_fmt() -> serde_json::Result<()> {
+        let path = Path::new("./docs/file.md").to_path_buf();
+        let md026_violation =
+            MD026::default().to_violation(path.clone(), Sourcepos::from((2, 1, 2, 9)));
+        let other_violation = Violation::new(path, &METADATA, Sourcepos::from((0, 1, 0, 1)))
+            .with_severity(Severity::Warning);
+        let violations = [md026_violation, other_violation];
+        let actual: Value = serde_json::from_str(&Sarif::new(&violations).to_string())?;
+
+        let rules = actual["runs"][0]["tool"]["driver"]["rules"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+        assert_eq!(rules.len(), Rule::all().len() + 1);
+        let md026_index = rules
+            .iter()
+            .position(|rule| rule["id"] == "MD026")
+            .unwrap_or_default();
+        assert_eq!(
+            rules[md026_index],
+            json!({
+                "id": "MD026",
+                "name": "no-trailing-punctuation",
+                "shortDescription": { "text": "Trailing punctuation in header" },
+                "properties": { "aliases": ["no-trailing-punctuation"], "tags": ["headers"] },
+            })
+        );
+
+        let expected_results = json!([
+            {
+                "ruleId": "MD026",
+                "ruleIndex": md026_index,
+                "level": "error",
+                "message": { "text": "Trailing punctuation in header" },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": "docs/file.md" },
+                        "region": { "startLine": 2, "startColumn": 1, "endLine": 2, "endColumn": 10 },
+                    },
+                }],
+            },
+            {
+                "ruleId": "name",
+                "ruleIndex": rules.len() - 1,
+                "level": "warning",
+                "message": { "text": "description" },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": "docs/file.md" },
+                        "region": { "startLine": 1, "startColumn": 1, "endLine": 1, "endColumn": 2 },
+                    },
+                }],
+            },
+        ]);
+        assert_eq!(actual["runs"][0]["results"], expected_results);
+        assert_eq!(actual["version"], "2.1.0");
+        Ok(())
+    }
+}
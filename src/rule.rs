@@ -3,7 +3,7 @@ use std::path::PathBuf;
 use comrak::nodes::Sourcepos;
 use miette::Result;
 
-use crate::{violation::Violation, Document};
+use crate::{violation::Violation, Document, Edit};
 
 mod helper;
 mod md001;
@@ -42,8 +42,14 @@ mod md038;
 mod md039;
 mod md040;
 mod md041;
+mod md042;
+mod md045;
 pub mod md046;
 mod md047;
+pub mod md048;
+pub mod md049;
+mod md050;
+mod md051;
 mod metadata;
 mod tag;
 
@@ -86,11 +92,69 @@ pub enum Rule {
     MD039(MD039),
     MD040(MD040),
     MD041(MD041),
+    MD042(MD042),
+    MD045(MD045),
     MD046(MD046),
     MD047(MD047),
+    MD048(MD048),
+    MD049(MD049),
+    MD050(MD050),
+    MD051(MD051),
 }
 
 impl Rule {
+    /// Returns all rules with default options
+    #[inline]
+    #[must_use]
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::MD001(MD001::new()),
+            Self::MD002(MD002::default()),
+            Self::MD003(MD003::default()),
+            Self::MD004(MD004::default()),
+            Self::MD005(MD005::new()),
+            Self::MD006(MD006::new()),
+            Self::MD007(MD007::default()),
+            Self::MD009(MD009::new()),
+            Self::MD010(MD010::new()),
+            Self::MD012(MD012::new()),
+            Self::MD013(MD013::default()),
+            Self::MD014(MD014::new()),
+            Self::MD018(MD018::new()),
+            Self::MD019(MD019::new()),
+            Self::MD020(MD020::new()),
+            Self::MD021(MD021::new()),
+            Self::MD022(MD022::new()),
+            Self::MD023(MD023::new()),
+            Self::MD024(MD024::default()),
+            Self::MD025(MD025::default()),
+            Self::MD026(MD026::default()),
+            Self::MD027(MD027::new()),
+            Self::MD028(MD028::new()),
+            Self::MD029(MD029::default()),
+            Self::MD030(MD030::default()),
+            Self::MD031(MD031::new()),
+            Self::MD032(MD032::new()),
+            Self::MD033(MD033::default()),
+            Self::MD034(MD034::new()),
+            Self::MD035(MD035::default()),
+            Self::MD036(MD036::default()),
+            Self::MD037(MD037::new()),
+            Self::MD038(MD038::new()),
+            Self::MD039(MD039::new()),
+            Self::MD040(MD040::new()),
+            Self::MD041(MD041::default()),
+            Self::MD042(MD042::new()),
+            Self::MD045(MD045::default()),
+            Self::MD046(MD046::default()),
+            Self::MD047(MD047::new()),
+            Self::MD048(MD048::default()),
+            Self::MD049(MD049::default()),
+            Self::MD050(MD050::default()),
+            Self::MD051(MD051::default()),
+        ]
+    }
+
     #[inline]
     pub fn check(&self, doc: &Document) -> Result<Vec<Violation>> {
         match self {
@@ -130,14 +194,20 @@ impl Rule {
             Self::MD039(rule) => rule.check(doc),
             Self::MD040(rule) => rule.check(doc),
             Self::MD041(rule) => rule.check(doc),
+            Self::MD042(rule) => rule.check(doc),
+            Self::MD045(rule) => rule.check(doc),
             Self::MD046(rule) => rule.check(doc),
             Self::MD047(rule) => rule.check(doc),
+            Self::MD048(rule) => rule.check(doc),
+            Self::MD049(rule) => rule.check(doc),
+            Self::MD050(rule) => rule.check(doc),
+            Self::MD051(rule) => rule.check(doc),
         }
     }
 
     #[inline]
     #[must_use]
-    pub fn metadata(&self) -> &Metadata {
+    pub fn metadata(&self) -> &'static Metadata {
         match self {
             Self::MD001(rule) => rule.metadata(),
             Self::MD002(rule) => rule.metadata(),
@@ -175,8 +245,65 @@ impl Rule {
             Self::MD039(rule) => rule.metadata(),
             Self::MD040(rule) => rule.metadata(),
             Self::MD041(rule) => rule.metadata(),
+            Self::MD042(rule) => rule.metadata(),
+            Self::MD045(rule) => rule.metadata(),
             Self::MD046(rule) => rule.metadata(),
             Self::MD047(rule) => rule.metadata(),
+            Self::MD048(rule) => rule.metadata(),
+            Self::MD049(rule) => rule.metadata(),
+            Self::MD050(rule) => rule.metadata(),
+            Self::MD051(rule) => rule.metadata(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn fix(&self, doc: &Document, violation: &Violation) -> Vec<Edit> {
+        match self {
+            Self::MD001(rule) => rule.fix(doc, violation),
+            Self::MD002(rule) => rule.fix(doc, violation),
+            Self::MD003(rule) => rule.fix(doc, violation),
+            Self::MD004(rule) => rule.fix(doc, violation),
+            Self::MD005(rule) => rule.fix(doc, violation),
+            Self::MD006(rule) => rule.fix(doc, violation),
+            Self::MD007(rule) => rule.fix(doc, violation),
+            Self::MD009(rule) => rule.fix(doc, violation),
+            Self::MD010(rule) => rule.fix(doc, violation),
+            Self::MD012(rule) => rule.fix(doc, violation),
+            Self::MD013(rule) => rule.fix(doc, violation),
+            Self::MD014(rule) => rule.fix(doc, violation),
+            Self::MD018(rule) => rule.fix(doc, violation),
+            Self::MD019(rule) => rule.fix(doc, violation),
+            Self::MD020(rule) => rule.fix(doc, violation),
+            Self::MD021(rule) => rule.fix(doc, violation),
+            Self::MD022(rule) => rule.fix(doc, violation),
+            Self::MD023(rule) => rule.fix(doc, violation),
+            Self::MD024(rule) => rule.fix(doc, violation),
+            Self::MD025(rule) => rule.fix(doc, violation),
+            Self::MD026(rule) => rule.fix(doc, violation),
+            Self::MD027(rule) => rule.fix(doc, violation),
+            Self::MD028(rule) => rule.fix(doc, violation),
+            Self::MD029(rule) => rule.fix(doc, violation),
+            Self::MD030(rule) => rule.fix(doc, violation),
+            Self::MD031(rule) => rule.fix(doc, violation),
+            Self::MD032(rule) => rule.fix(doc, violation),
+            Self::MD033(rule) => rule.fix(doc, violation),
+            Self::MD034(rule) => rule.fix(doc, violation),
+            Self::MD035(rule) => rule.fix(doc, violation),
+            Self::MD036(rule) => rule.fix(doc, violation),
+            Self::MD037(rule) => rule.fix(doc, violation),
+            Self::MD038(rule) => rule.fix(doc, violation),
+            Self::MD039(rule) => rule.fix(doc, violation),
+            Self::MD040(rule) => rule.fix(doc, violation),
+            Self::MD041(rule) => rule.fix(doc, violation),
+            Self::MD042(rule) => rule.fix(doc, violation),
+            Self::MD045(rule) => rule.fix(doc, violation),
+            Self::MD046(rule) => rule.fix(doc, violation),
+            Self::MD047(rule) => rule.fix(doc, violation),
+            Self::MD048(rule) => rule.fix(doc, violation),
+            Self::MD049(rule) => rule.fix(doc, violation),
+            Self::MD050(rule) => rule.fix(doc, violation),
+            Self::MD051(rule) => rule.fix(doc, violation),
         }
     }
 }
@@ -187,6 +314,13 @@ pub trait RuleLike: Send {
 
     fn check(&self, doc: &Document) -> Result<Vec<Violation>>;
 
+    /// Returns edits fixing the violation, or an empty list if it cannot be fixed safely
+    #[inline]
+    #[must_use]
+    fn fix(&self, _doc: &Document, _violation: &Violation) -> Vec<Edit> {
+        vec![]
+    }
+
     #[inline]
     fn to_violation(&self, path: PathBuf, position: Sourcepos) -> Violation {
         Violation::new(path, self.metadata(), position)
@@ -229,7 +363,46 @@ pub use md038::MD038;
 pub use md039::MD039;
 pub use md040::MD040;
 pub use md041::MD041;
+pub use md042::MD042;
+pub use md045::MD045;
 pub use md046::MD046;
 pub use md047::MD047;
+pub use md048::MD048;
+pub use md049::MD049;
+pub use md050::MD050;
+pub use md051::MD051;
 pub use metadata::Metadata;
 pub use tag::Tag;
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use comrak::Arena;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn check(rule: &Rule, text: &str) -> Result<usize> {
+        let arena = Arena::new();
+        let path = Path::new("test.md").to_path_buf();
+        let doc = Document::new(&arena, path, text.to_owned())?;
+        Ok(rule.check(&doc)?.len())
+    }
+
+    #[test]
+    fn metadata_examples() -> Result<()> {
+        let mut failures = vec![];
+        for rule in Rule::all() {
+            let metadata = rule.metadata();
+            if check(&rule, metadata.bad_example)? == 0 {
+                failures.push(format!("{}: bad example", metadata.name));
+            }
+            if check(&rule, metadata.good_example)? > 0 {
+                failures.push(format!("{}: good example", metadata.name));
+            }
+        }
+        assert_eq!(failures, Vec::<String>::new());
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
-use comrak::nodes::{AstNode, NodeValue};
+use comrak::nodes::{AstNode, NodeValue, Sourcepos};
+
+use crate::{Document, Edit};
 
 pub fn inline_text_of<'a>(root: &'a AstNode<'a>) -> String {
     let texts: Vec<String> = root
@@ -13,6 +15,65 @@ pub fn inline_text_of<'a>(root: &'a AstNode<'a>) -> String {
     texts.join("")
 }
 
+/// Returns the marker character (`*` or `_`) of emphasis or strong emphasis at the position
+pub fn emphasis_marker_of(doc: &Document, position: &Sourcepos) -> Option<char> {
+    let line = doc.lines.get(position.start.line.checked_sub(1)?)?;
+    line.get(position.start.column.checked_sub(1)?..)?
+        .chars()
+        .next()
+        .filter(|c| matches!(c, '*' | '_'))
+}
+
+/// Returns edits replacing the markers of emphasis or strong emphasis at the position,
+/// or an empty list if the markers cannot be replaced safely
+pub fn replace_emphasis_markers(
+    doc: &Document,
+    position: &Sourcepos,
+    len: usize,
+    marker: char,
+) -> Vec<Edit> {
+    let (Some(start), Some(end)) = (
+        doc.offset(position.start.line, position.start.column),
+        doc.offset(position.end.line, position.end.column + 1),
+    ) else {
+        return vec![];
+    };
+    let (Some(opening), Some(closing)) = (
+        doc.text.get(start..start + len),
+        end.checked_sub(len).and_then(|i| doc.text.get(i..end)),
+    ) else {
+        return vec![];
+    };
+    if start + len > end - len
+        || opening != closing
+        || !opening
+            .chars()
+            .all(|c| matches!(c, '*' | '_') && c != marker)
+    {
+        return vec![];
+    }
+
+    let is_alphanumeric = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    let before = doc.text[..start].chars().next_back();
+    let after = doc.text[end..].chars().next();
+    let inner_first = doc.text[(start + len)..].chars().next();
+    let inner_last = doc.text[..(end - len)].chars().next_back();
+
+    // Underscores inside words are not parsed as emphasis
+    let is_intraword = marker == '_' && (is_alphanumeric(before) || is_alphanumeric(after));
+    // Adjacent markers would be merged with the replaced ones
+    let is_adjacent = [before, after, inner_first, inner_last].contains(&Some(marker));
+    if is_intraword || is_adjacent {
+        return vec![];
+    }
+
+    let replacement = marker.to_string().repeat(len);
+    vec![
+        Edit::new(start..(start + len), replacement.clone()),
+        Edit::new((end - len)..end, replacement),
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use comrak::{parse_document, Arena, Options};
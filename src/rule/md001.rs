@@ -15,6 +15,9 @@ impl MD001 {
         description: "Header levels should only increment by one level at a time",
         tags: &[Tag::Headers],
         aliases: &["header-increment"],
+        rationale: "Headers represent the structure of a document. Skipping levels makes the outline confusing for readers and for tools such as screen readers.",
+        bad_example: "# Header 1\n\n### Header 3\n",
+        good_example: "# Header 1\n\n## Header 2\n\n### Header 3\n",
     };
 
     #[inline]
@@ -18,6 +18,9 @@ impl MD002 {
         description: "First header should be a top level header",
         tags: &[Tag::Headers],
         aliases: &["first-header-h1"],
+        rationale: "The first header is usually the title of the document, so it should be a top level header. The level of the title can be configured with `level`.",
+        bad_example: "## This isn't a H1 header\n\n### Another header\n",
+        good_example: "# Start with a H1 header\n\n## Then use a H2 for subsections\n",
     };
 
     pub const DEFAULT_LEVEL: u8 = 1;
@@ -30,6 +30,9 @@ impl MD003 {
         description: "Header style",
         tags: &[Tag::Headers],
         aliases: &["header-style"],
+        rationale: "A consistent header style makes documents easier to read and to edit. The style can be configured with `style`.",
+        bad_example: "# ATX style H1\n\nSetext style H2\n---------------\n",
+        good_example: "# ATX style H1\n\n## ATX style H2\n",
     };
 
     pub const DEFAULT_HEADING_STYLE: HeadingStyle = HeadingStyle::Consistent;
@@ -33,6 +33,9 @@ impl MD004 {
         description: "Unordered list style",
         tags: &[Tag::Bullet, Tag::Ul],
         aliases: &["ul-style"],
+        rationale: "A consistent list marker makes lists easier to read. The marker can be configured with `style`.",
+        bad_example: "* Item 1\n+ Item 2\n- Item 3\n",
+        good_example: "* Item 1\n* Item 2\n* Item 3\n",
     };
 
     pub const DEFAULT_LIST_STYLE: ListStyle = ListStyle::Consistent;
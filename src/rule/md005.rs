@@ -18,6 +18,9 @@ impl MD005 {
         description: "Inconsistent indentation for list items at the same level",
         tags: &[Tag::Bullet, Tag::Ul, Tag::Indentation],
         aliases: &["list-indent"],
+        rationale: "List items at the same level should be indented by the same number of spaces. Items indented differently may be rendered as nested lists or break the list, depending on the parser.",
+        bad_example: "* Item 1\n    * Nested item 1\n   * Nested item 2\n",
+        good_example: "* Item 1\n  * Nested item 1\n  * Nested item 2\n",
     };
 
     #[inline]
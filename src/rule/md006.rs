@@ -15,6 +15,9 @@ impl MD006 {
         description: "Consider starting bulleted lists at the beginning of the line",
         tags: &[Tag::Bullet, Tag::Ul, Tag::Indentation],
         aliases: &["ul-start-left"],
+        rationale: "Top level lists should start at the beginning of the line. Indenting them is unnecessary, and an indentation of four or more spaces makes the list a code block.",
+        bad_example: "Some text\n\n  * List item\n  * List item\n",
+        good_example: "Some text\n\n* List item\n* List item\n",
     };
 
     #[inline]
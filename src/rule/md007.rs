@@ -17,6 +17,9 @@ impl MD007 {
         description: "Unordered list indentation",
         tags: &[Tag::Bullet, Tag::Ul, Tag::Indentation],
         aliases: &["ul-indent"],
+        rationale: "Consistent indentation of nested lists makes them readable as plain text. The indentation can be configured with `indent`.",
+        bad_example: "* List item\n  * Nested list item indented by 2 spaces\n",
+        good_example: "* List item\n    * Nested list item indented by 4 spaces\n",
     };
 
     pub const DEFAULT_INDENT: usize = 4;
@@ -1,7 +1,7 @@
 use comrak::nodes::Sourcepos;
 use miette::Result;
 
-use crate::{violation::Violation, Document};
+use crate::{violation::Violation, Document, Edit};
 
 use super::{Metadata, RuleLike, Tag};
 
@@ -15,6 +15,9 @@ impl MD009 {
         description: "Trailing spaces",
         tags: &[Tag::Whitespace],
         aliases: &["no-trailing-spaces"],
+        rationale: "Trailing spaces are invisible and easy to add by mistake, and they make diffs noisy. Two or more of them are rendered as a hard line break, which is clearer when written with a backslash.",
+        bad_example: "Some text with trailing spaces   \n",
+        good_example: "Some text without trailing spaces\n",
     };
 
     #[inline]
@@ -46,6 +49,17 @@ impl RuleLike for MD009 {
 
         Ok(violations)
     }
+
+    #[inline]
+    fn fix(&self, doc: &Document, violation: &Violation) -> Vec<Edit> {
+        let position = violation.position();
+        let start = doc.offset(position.start.line, position.start.column);
+        let end = doc.offset(position.end.line, position.end.column + 1);
+        match (start, end) {
+            (Some(start), Some(end)) => vec![Edit::deletion(start..end)],
+            _ => vec![],
+        }
+    }
 }
 
 #[cfg(test)]
@@ -78,6 +92,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn fix() -> Result<()> {
+        let text = indoc! {"
+            Text with a trailing space 
+            And text with some trailing spaces   
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD009::new();
+        let actual: Vec<_> = rule
+            .check(&doc)?
+            .iter()
+            .map(|violation| rule.fix(&doc, violation))
+            .collect();
+        let expected = vec![vec![Edit::deletion(26..27)], vec![Edit::deletion(62..65)]];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
     #[test]
     fn check_no_errors() -> Result<()> {
         let text = "Text with no trailing spaces".to_owned();
@@ -2,7 +2,7 @@ use comrak::nodes::Sourcepos;
 use miette::Result;
 
 use crate::violation::Violation;
-use crate::Document;
+use crate::{Document, Edit};
 
 use super::{Metadata, RuleLike, Tag};
 
@@ -16,13 +16,34 @@ impl MD010 {
         description: "Hard tabs",
         tags: &[Tag::Whitespace, Tag::HardTab],
         aliases: &["no-hard-tabs"],
+        rationale: "Hard tabs are rendered with different widths by different editors and tools, which breaks the alignment of text and code. Spaces are rendered the same everywhere and should be used for indentation instead.",
+        bad_example: "Some text\n\n\t* hard tab character used to indent the list item\n",
+        good_example: "Some text\n\n    * spaces used to indent the list item instead\n",
     };
 
+    const TAB_WIDTH: usize = 4;
+
     #[inline]
     #[must_use]
     pub const fn new() -> Self {
         Self {}
     }
+
+    // Expands tabs to the next tab stop so that the rendered indentation is preserved
+    fn expand_tabs(text: &str, mut column: usize) -> String {
+        let mut expanded = String::with_capacity(text.len());
+        for c in text.chars() {
+            if c == '\t' {
+                let width = (column + 1).next_multiple_of(Self::TAB_WIDTH) - column;
+                expanded.push_str(&" ".repeat(width));
+                column += width;
+            } else {
+                expanded.push(c);
+                column += 1;
+            }
+        }
+        expanded
+    }
 }
 
 impl RuleLike for MD010 {
@@ -45,6 +66,28 @@ impl RuleLike for MD010 {
 
         Ok(violations)
     }
+
+    #[inline]
+    fn fix(&self, doc: &Document, violation: &Violation) -> Vec<Edit> {
+        let lineno = violation.position().start.line;
+        let (Some(line), Some(line_offset)) = (
+            lineno.checked_sub(1).and_then(|i| doc.lines.get(i)),
+            doc.line_offset(lineno),
+        ) else {
+            return vec![];
+        };
+
+        match (line.find('\t'), line.rfind('\t')) {
+            (Some(first), Some(last)) => {
+                let column = line[..first].chars().count();
+                let expanded = Self::expand_tabs(&line[first..=last], column);
+                let start = line_offset + first;
+                let end = line_offset + last + 1;
+                vec![Edit::new(start..end, expanded)]
+            }
+            _ => vec![],
+        }
+    }
 }
 
 #[cfg(test)]
@@ -75,6 +118,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn fix() -> Result<()> {
+        let text = indoc! {"
+            Some text
+
+            \t* hard tab character used to indent the list item
+
+            Text\twith\ttabs
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD010::new();
+        let actual: Vec<_> = rule
+            .check(&doc)?
+            .iter()
+            .map(|violation| rule.fix(&doc, violation))
+            .collect();
+        let expected = vec![
+            vec![Edit::new(11..12, "    ".to_owned())],
+            vec![Edit::new(67..73, "    with    ".to_owned())],
+        ];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
     #[test]
     fn check_no_errors() -> Result<()> {
         let text = indoc! {"
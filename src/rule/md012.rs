@@ -1,7 +1,7 @@
 use comrak::nodes::{NodeValue, Sourcepos};
 use miette::Result;
 
-use crate::{collection::RangeSet, violation::Violation, Document};
+use crate::{collection::RangeSet, violation::Violation, Document, Edit};
 
 use super::{Metadata, RuleLike, Tag};
 
@@ -15,6 +15,9 @@ impl MD012 {
         description: "Multiple consecutive blank lines",
         tags: &[Tag::Whitespace, Tag::BlankLines],
         aliases: &["no-multiple-blanks"],
+        rationale: "Multiple consecutive blank lines are rendered the same as a single blank line. They only add noise to the source and make the spacing between blocks inconsistent.",
+        bad_example: "Some text here\n\n\nSome more text here\n",
+        good_example: "Some text here\n\nSome more text here\n",
     };
 
     #[inline]
@@ -61,6 +64,17 @@ impl RuleLike for MD012 {
 
         Ok(violations)
     }
+
+    #[inline]
+    fn fix(&self, doc: &Document, violation: &Violation) -> Vec<Edit> {
+        let lineno = violation.position().start.line;
+        let Some(start) = doc.line_offset(lineno) else {
+            return vec![];
+        };
+
+        let end = doc.line_offset(lineno + 1).unwrap_or(doc.text.len());
+        vec![Edit::deletion(start..end)]
+    }
 }
 
 #[cfg(test)]
@@ -113,6 +127,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn fix() -> Result<()> {
+        let text = indoc! {"
+            Some text here
+
+
+
+            Some more text here
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD012::new();
+        let actual: Vec<_> = rule
+            .check(&doc)?
+            .iter()
+            .map(|violation| rule.fix(&doc, violation))
+            .collect();
+        let expected = vec![vec![Edit::deletion(16..17)], vec![Edit::deletion(17..18)]];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
     #[test]
     fn check_no_errors() -> Result<()> {
         let text = indoc! {"
@@ -22,6 +22,9 @@ impl MD013 {
         description: "Line length",
         tags: &[Tag::LineLength],
         aliases: &["line-length"],
+        rationale: "Long lines are hard to read and to review in diffs. The maximum length can be configured with `line-length`, and code blocks and tables can be excluded.",
+        bad_example: "This line is long enough to exceed the default limit of eighty characters, so it should be wrapped.\n",
+        good_example: "This line is short enough to fit\nwithin the default limit.\n",
     };
 
     pub const DEFAULT_LINE_LENGTH: usize = 80;
@@ -15,6 +15,9 @@ impl MD014 {
         description: "Dollar signs used before commands without showing output",
         tags: &[Tag::Code],
         aliases: &["commands-show-output"],
+        rationale: "Dollar signs before commands make them harder to copy and paste into a terminal. They are only useful to tell commands from their output, so they are unnecessary when no output is shown.",
+        bad_example: "```sh\n$ ls\n$ cat foo\n```\n",
+        good_example: "```sh\nls\ncat foo\n```\n",
     };
 
     #[inline]
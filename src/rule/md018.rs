@@ -2,7 +2,7 @@ use comrak::nodes::NodeValue;
 use miette::Result;
 
 use crate::violation::Violation;
-use crate::Document;
+use crate::{Document, Edit};
 
 use super::{Metadata, RuleLike, Tag};
 
@@ -16,6 +16,9 @@ impl MD018 {
         description: "No space after hash on atx style header",
         tags: &[Tag::Headers, Tag::Atx, Tag::Spaces],
         aliases: &["no-missing-space-atx"],
+        rationale: "Without a space after the hash, the line is not parsed as a header by CommonMark and many other parsers. It is rendered as a paragraph starting with the hash instead.",
+        bad_example: "#Header 1\n\n##Header 2\n",
+        good_example: "# Header 1\n\n## Header 2\n",
     };
 
     #[inline]
@@ -54,6 +57,25 @@ impl RuleLike for MD018 {
 
         Ok(violations)
     }
+
+    #[inline]
+    fn fix(&self, doc: &Document, violation: &Violation) -> Vec<Edit> {
+        let lineno = violation.position().start.line;
+        let (Some(line), Some(line_offset)) = (
+            lineno.checked_sub(1).and_then(|i| doc.lines.get(i)),
+            doc.line_offset(lineno),
+        ) else {
+            return vec![];
+        };
+
+        // A space does not make a heading if there are more than 6 hashes
+        let level = line.len() - line.trim_start_matches('#').len();
+        if (1..=6).contains(&level) {
+            vec![Edit::insertion(line_offset + level, " ".to_owned())]
+        } else {
+            vec![]
+        }
+    }
 }
 
 #[cfg(test)]
@@ -87,6 +109,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn fix() -> Result<()> {
+        let text = indoc! {"
+            #Header 1
+
+            ##Header 2
+
+            #######Not a header
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD018::new();
+        let actual: Vec<_> = rule
+            .check(&doc)?
+            .iter()
+            .map(|violation| rule.fix(&doc, violation))
+            .collect();
+        let expected = vec![
+            vec![Edit::insertion(1, " ".to_owned())],
+            vec![Edit::insertion(13, " ".to_owned())],
+            vec![],
+        ];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
     #[test]
     fn check_no_errors() -> Result<()> {
         let text = indoc! {"
@@ -2,7 +2,7 @@ use comrak::nodes::{NodeHeading, NodeValue};
 use miette::Result;
 
 use crate::violation::Violation;
-use crate::Document;
+use crate::{Document, Edit};
 
 use super::{Metadata, RuleLike, Tag};
 
@@ -16,6 +16,9 @@ impl MD019 {
         description: "Multiple spaces after hash on atx style header",
         tags: &[Tag::Headers, Tag::Atx, Tag::Spaces],
         aliases: &["no-multiple-space-atx"],
+        rationale: "Extra spaces between the hash and the header text are unnecessary. They are ignored by parsers and make the header inconsistent with other headers.",
+        bad_example: "#  Header 1\n\n##  Header 2\n",
+        good_example: "# Header 1\n\n## Header 2\n",
     };
 
     #[inline]
@@ -65,6 +68,34 @@ impl RuleLike for MD019 {
 
         Ok(violations)
     }
+
+    #[inline]
+    fn fix(&self, doc: &Document, violation: &Violation) -> Vec<Edit> {
+        let position = violation.position();
+        let (Some(line), Some(start)) = (
+            position
+                .start
+                .line
+                .checked_sub(1)
+                .and_then(|i| doc.lines.get(i)),
+            doc.offset(position.start.line, position.start.column),
+        ) else {
+            return vec![];
+        };
+
+        let Some(heading) = line.get((position.start.column - 1)..) else {
+            return vec![];
+        };
+        let content = heading.trim_start_matches('#');
+        let level = heading.len() - content.len();
+        let spaces = content.len() - content.trim_start_matches([' ', '\t']).len();
+        if spaces > 1 {
+            let range = (start + level)..(start + level + spaces);
+            vec![Edit::new(range, " ".to_owned())]
+        } else {
+            vec![]
+        }
+    }
 }
 
 #[cfg(test)]
@@ -98,6 +129,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn fix() -> Result<()> {
+        let text = indoc! {"
+            #  Header 1
+
+            ##  \tHeader 2
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD019::new();
+        let actual: Vec<_> = rule
+            .check(&doc)?
+            .iter()
+            .map(|violation| rule.fix(&doc, violation))
+            .collect();
+        let expected = vec![
+            vec![Edit::new(1..3, " ".to_owned())],
+            vec![Edit::new(15..18, " ".to_owned())],
+        ];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
     #[test]
     fn check_no_errors() -> Result<()> {
         let text = indoc! {"
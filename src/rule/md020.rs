@@ -16,6 +16,9 @@ impl MD020 {
         description: "No space inside hashes on closed atx style header",
         tags: &[Tag::Headers, Tag::AtxClosed, Tag::Spaces],
         aliases: &["no-missing-space-closed-atx"],
+        rationale: "Closed atx style headers need spaces between the hashes and the header text. Without them, the line is not parsed as a header by many parsers, or the closing hashes are rendered as part of the text.",
+        bad_example: "#Header 1#\n\n## Header 2##\n",
+        good_example: "# Header 1 #\n\n## Header 2 ##\n",
     };
 
     #[inline]
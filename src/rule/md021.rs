@@ -16,6 +16,9 @@ impl MD021 {
         description: "Multiple spaces inside hashes on closed atx style header",
         tags: &[Tag::Headers, Tag::AtxClosed, Tag::Spaces],
         aliases: &["no-multiple-space-closed-atx"],
+        rationale: "Extra spaces between the hashes and the text of closed atx style headers are unnecessary. They are ignored by parsers and make the header inconsistent with other headers.",
+        bad_example: "#  Header 1  #\n\n##  Header 2  ##\n",
+        good_example: "# Header 1 #\n\n## Header 2 ##\n",
     };
 
     #[inline]
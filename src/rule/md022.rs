@@ -15,6 +15,9 @@ impl MD022 {
         description: "Headers should be surrounded by blank lines",
         tags: &[Tag::Headers, Tag::BlankLines],
         aliases: &["blanks-around-headers"],
+        rationale: "Headers should be surrounded by blank lines. Without them, headers may not be parsed as headers by some parsers, and the document is harder to read as plain text.",
+        bad_example: "# Header 1\nSome text\n\nSome more text\n## Header 2\n",
+        good_example: "# Header 1\n\nSome text\n\nSome more text\n\n## Header 2\n",
     };
 
     #[inline]
@@ -1,7 +1,7 @@
 use comrak::nodes::NodeValue;
 use miette::Result;
 
-use crate::{violation::Violation, Document};
+use crate::{violation::Violation, Document, Edit};
 
 use super::{Metadata, RuleLike, Tag};
 
@@ -15,6 +15,9 @@ impl MD023 {
         description: "Headers must start at the beginning of the line",
         tags: &[Tag::Headers, Tag::Spaces],
         aliases: &["header-start-left"],
+        rationale: "Headers should start at the beginning of the line. Indented headers may not be parsed as headers by some parsers, and an indentation of four or more spaces makes the header a code block.",
+        bad_example: "Some text\n\n  # Indented header\n",
+        good_example: "Some text\n\n# Header\n",
     };
 
     #[inline]
@@ -46,6 +49,23 @@ impl RuleLike for MD023 {
 
         Ok(violations)
     }
+
+    #[inline]
+    fn fix(&self, doc: &Document, violation: &Violation) -> Vec<Edit> {
+        let position = violation.position();
+        (position.start.line..=position.end.line)
+            .filter_map(|lineno| {
+                let line = doc.lines.get(lineno.checked_sub(1)?)?;
+                let indent = line.len() - line.trim_start_matches(' ').len();
+                if indent == 0 {
+                    return None;
+                }
+
+                let offset = doc.line_offset(lineno)?;
+                Some(Edit::deletion(offset..(offset + indent)))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -76,6 +96,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn fix() -> Result<()> {
+        let text = indoc! {"
+            Some text
+
+              # Indented header
+
+              Setext header
+              -------------
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD023::new();
+        let actual: Vec<_> = rule
+            .check(&doc)?
+            .iter()
+            .map(|violation| rule.fix(&doc, violation))
+            .collect();
+        let expected = vec![
+            vec![Edit::deletion(11..13)],
+            vec![Edit::deletion(32..34), Edit::deletion(48..50)],
+        ];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
     #[test]
     fn check_no_errors() -> Result<()> {
         let text = indoc! {"
@@ -18,6 +18,9 @@ impl MD024 {
         description: "Multiple headers with the same content",
         tags: &[Tag::Headers],
         aliases: &["no-duplicate-header"],
+        rationale: "Some parsers generate anchors from header contents, so duplicate headers make anchors ambiguous. Headers under different parents can be allowed with `allow-different-nesting`.",
+        bad_example: "# Some text\n\n## Some text\n",
+        good_example: "# Some text\n\n## Some more text\n",
     };
 
     #[inline]
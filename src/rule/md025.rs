@@ -17,6 +17,9 @@ impl MD025 {
         description: "Multiple top level headers in the same document",
         tags: &[Tag::Headers],
         aliases: &["single-h1"],
+        rationale: "A top level header is the title of the document, and a document should have only one title. The level of the title can be configured with `level`.",
+        bad_example: "# Top level header\n\n# Another top level header\n",
+        good_example: "# Title\n\n## Header\n\n## Another header\n",
     };
 
     pub const DEFAULT_LEVEL: u8 = 1;
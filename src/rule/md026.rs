@@ -1,9 +1,9 @@
 use comrak::nodes::NodeValue;
 use miette::Result;
 
-use crate::{violation::Violation, Document};
+use crate::{violation::Violation, Document, Edit};
 
-use super::{Metadata, RuleLike, Tag};
+use super::{helper::inline_text_of, Metadata, RuleLike, Tag};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
@@ -17,6 +17,9 @@ impl MD026 {
         description: "Trailing punctuation in header",
         tags: &[Tag::Headers],
         aliases: &["no-trailing-punctuation"],
+        rationale: "Headers are not sentences, so punctuation at the end is unnecessary. The punctuation characters can be configured with `punctuation`.",
+        bad_example: "# This is a header.\n",
+        good_example: "# This is a header\n",
     };
 
     pub const DEFAULT_PUNCTUATION: &str = ".,;:!?";
@@ -65,6 +68,44 @@ impl RuleLike for MD026 {
 
         Ok(violations)
     }
+
+    #[inline]
+    fn fix(&self, doc: &Document, violation: &Violation) -> Vec<Edit> {
+        let Some(node) = doc
+            .ast
+            .children()
+            .find(|node| node.data.borrow().sourcepos == *violation.position())
+        else {
+            return vec![];
+        };
+
+        // Skip headings with only punctuation
+        let heading_text = inline_text_of(node);
+        if heading_text
+            .trim_end_matches(|c| self.punctuation.contains(c))
+            .is_empty()
+        {
+            return vec![];
+        }
+
+        if let Some(child) = node.last_child() {
+            if let NodeValue::Text(text) = &child.data.borrow().value {
+                let trimmed = text.trim_end_matches(|c| self.punctuation.contains(c));
+                let suffix = &text[trimmed.len()..];
+                let end = child.data.borrow().sourcepos.end;
+                if let Some(end) = doc.offset(end.line, end.column + 1) {
+                    let start = end.saturating_sub(suffix.len());
+
+                    // Skip escaped or entity-encoded punctuation
+                    if doc.text.get(start..end) == Some(suffix) {
+                        return vec![Edit::deletion(start..end)];
+                    }
+                }
+            }
+        }
+
+        vec![]
+    }
 }
 
 #[cfg(test)]
@@ -72,6 +113,7 @@ mod tests {
     use std::path::Path;
 
     use comrak::{nodes::Sourcepos, Arena};
+    use indoc::indoc;
     use pretty_assertions::assert_eq;
 
     use super::*;
@@ -89,6 +131,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn fix() -> Result<()> {
+        let text = indoc! {"
+            # This is a header!?
+
+            This is a header too.
+            ---------------------
+
+            # [This is a header](http://example.com).
+
+            # This is not fixable&period;
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD026::default();
+        let actual: Vec<_> = rule
+            .check(&doc)?
+            .iter()
+            .map(|violation| rule.fix(&doc, violation))
+            .collect();
+        let expected = vec![
+            vec![Edit::deletion(18..20)],
+            vec![Edit::deletion(42..43)],
+            vec![Edit::deletion(107..108)],
+            vec![],
+        ];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
     #[test]
     fn check_errors_with_link() -> Result<()> {
         let text = "# [This is a header](http://example.com).".to_owned();
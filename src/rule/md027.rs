@@ -2,7 +2,7 @@ use comrak::nodes::NodeValue;
 use miette::Result;
 use rustc_hash::FxHashSet;
 
-use crate::{violation::Violation, Document};
+use crate::{violation::Violation, Document, Edit};
 
 use super::{Metadata, RuleLike, Tag};
 
@@ -16,6 +16,9 @@ impl MD027 {
         description: "Multiple spaces after blockquote symbol",
         tags: &[Tag::Blockquote, Tag::Whitespace, Tag::Indentation],
         aliases: &["no-multiple-space-blockquote"],
+        rationale: "A single space after the blockquote symbol is enough. Extra spaces are unnecessary, and four or more of them make the text an indented code block inside the blockquote.",
+        bad_example: ">  This is a blockquote with bad indentation\n>  there should only be one.\n",
+        good_example: "> This is a blockquote with correct\n> indentation.\n",
     };
 
     #[inline]
@@ -87,6 +90,36 @@ impl RuleLike for MD027 {
 
         Ok(violations)
     }
+
+    #[inline]
+    fn fix(&self, doc: &Document, violation: &Violation) -> Vec<Edit> {
+        let position = violation.position();
+        let (Some(line), Some(line_offset)) = (
+            position
+                .start
+                .line
+                .checked_sub(1)
+                .and_then(|i| doc.lines.get(i)),
+            doc.line_offset(position.start.line),
+        ) else {
+            return vec![];
+        };
+
+        let Some(prefix) = line.get(..(position.start.column - 1)) else {
+            return vec![];
+        };
+
+        if let Some(marker) = prefix.rfind('>') {
+            let spaces = &prefix[(marker + 1)..];
+            if spaces.len() > 1 && spaces.bytes().all(|b| b == b' ') {
+                let start = line_offset + marker + 2;
+                let end = line_offset + prefix.len();
+                return vec![Edit::deletion(start..end)];
+            }
+        }
+
+        vec![]
+    }
 }
 
 #[cfg(test)]
@@ -128,6 +161,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn fix() -> Result<()> {
+        let text = indoc! {"
+            >  Indented text
+            > Not indented
+
+            >   * List item
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD027::new();
+        let actual: Vec<_> = rule
+            .check(&doc)?
+            .iter()
+            .map(|violation| rule.fix(&doc, violation))
+            .collect();
+        let expected = vec![vec![Edit::deletion(2..3)], vec![Edit::deletion(35..37)]];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
     #[test]
     fn check_errors_list() -> Result<()> {
         let text = indoc! {"
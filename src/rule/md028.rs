@@ -17,6 +17,9 @@ impl MD028 {
         description: "Blank line inside blockquote",
         tags: &[Tag::Blockquote, Tag::Whitespace],
         aliases: &["no-blanks-blockquote"],
+        rationale: "Some parsers treat blockquotes separated by a blank line as a single blockquote, and others treat them as separate blockquotes. Separate blockquotes with other text such as a comment, or continue a single blockquote with `>` on the blank line.",
+        bad_example: "> This is a blockquote\n> which is immediately followed by\n\n> this blockquote.\n",
+        good_example: "> This is a blockquote.\n\nAnd Jimmy also said:\n\n> This too is a blockquote.\n",
     };
 
     #[inline]
@@ -28,6 +28,9 @@ impl MD029 {
         description: "Ordered list item prefix",
         tags: &[Tag::Ol],
         aliases: &["ol-prefix"],
+        rationale: "Consistent prefixes of ordered list items make lists easier to maintain. The style can be configured with `style`.",
+        bad_example: "1. Do this.\n2. Do that.\n2. Done.\n",
+        good_example: "1. Do this.\n1. Do that.\n1. Done.\n",
     };
 
     pub const DEFAULT_STYLE: OrderedListStyle = OrderedListStyle::One;
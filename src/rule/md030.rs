@@ -3,7 +3,7 @@ use std::path::PathBuf;
 use comrak::nodes::{AstNode, ListType, NodeValue};
 use miette::Result;
 
-use crate::{violation::Violation, Document};
+use crate::{violation::Violation, Document, Edit};
 
 use super::{Metadata, RuleLike, Tag};
 
@@ -22,6 +22,9 @@ impl MD030 {
         description: "Spaces after list markers",
         tags: &[Tag::Ol, Tag::Ul, Tag::Whitespace],
         aliases: &["list-marker-space"],
+        rationale: "Consistent spaces after list markers make lists easier to read. The number of spaces can be configured for single and multi paragraph lists.",
+        bad_example: "*  Foo\n*  Bar\n",
+        good_example: "* Foo\n* Bar\n",
     };
 
     pub const DEFAULT_UL_SINGLE: usize = 1;
@@ -40,6 +43,33 @@ impl MD030 {
         }
     }
 
+    fn is_multi<'a>(item_node: &'a AstNode<'a>) -> bool {
+        // true if multiple Paragraph
+        if item_node.children().count() > 1 {
+            return true;
+        }
+
+        // Check for single Paragraph with multiple lines
+        if let Some(child_node) = item_node.first_child() {
+            if child_node.data.borrow().value == NodeValue::Paragraph {
+                return child_node
+                    .children()
+                    .any(|inline_node| inline_node.data.borrow().value == NodeValue::SoftBreak);
+            }
+        }
+
+        false
+    }
+
+    const fn spaces(&self, is_multi: bool, list_type: ListType) -> usize {
+        match (is_multi, list_type) {
+            (true, ListType::Bullet) => self.ul_multi,
+            (true, ListType::Ordered) => self.ol_multi,
+            (false, ListType::Bullet) => self.ul_single,
+            (false, ListType::Ordered) => self.ol_single,
+        }
+    }
+
     fn check_recursive<'a>(
         &self,
         root: &'a AstNode<'a>,
@@ -50,27 +80,10 @@ impl MD030 {
             if let NodeValue::List(list) = node.data.borrow().value {
                 for item_node in node.children() {
                     if let NodeValue::Item(item) = item_node.data.borrow().value {
-                        // true if multiple Paragraph
-                        let mut is_multi = item_node.children().count() > 1;
-
-                        // Check for single Paragraph with multiple lines
-                        if !is_multi {
-                            if let Some(child_node) = item_node.first_child() {
-                                if child_node.data.borrow().value == NodeValue::Paragraph {
-                                    for inline_node in child_node.children() {
-                                        if inline_node.data.borrow().value == NodeValue::SoftBreak {
-                                            is_multi = true;
-                                        }
-                                    }
-                                }
-                            }
-                        }
-
-                        let is_violated = match (is_multi, list.list_type) {
-                            (true, ListType::Bullet) => item.padding > self.ul_multi + 1,
-                            (true, ListType::Ordered) => item.padding > self.ol_multi + 2,
-                            (false, ListType::Bullet) => item.padding > self.ul_single + 1,
-                            (false, ListType::Ordered) => item.padding > self.ol_single + 2,
+                        let spaces = self.spaces(Self::is_multi(item_node), list.list_type);
+                        let is_violated = match list.list_type {
+                            ListType::Bullet => item.padding > spaces + 1,
+                            ListType::Ordered => item.padding > spaces + 2,
                         };
 
                         if is_violated {
@@ -113,6 +126,59 @@ impl RuleLike for MD030 {
 
         Ok(violations)
     }
+
+    #[inline]
+    fn fix(&self, doc: &Document, violation: &Violation) -> Vec<Edit> {
+        let position = violation.position();
+        let Some(item_node) = doc.ast.descendants().find(|node| {
+            matches!(node.data.borrow().value, NodeValue::Item(_))
+                && node.data.borrow().sourcepos == *position
+        }) else {
+            return vec![];
+        };
+
+        let NodeValue::Item(item) = item_node.data.borrow().value else {
+            return vec![];
+        };
+
+        let (Some(line), Some(start)) = (
+            position
+                .start
+                .line
+                .checked_sub(1)
+                .and_then(|i| doc.lines.get(i)),
+            doc.offset(position.start.line, position.start.column),
+        ) else {
+            return vec![];
+        };
+
+        let Some(marker_and_content) = line.get((position.start.column - 1)..) else {
+            return vec![];
+        };
+
+        let marker_width = match item.list_type {
+            ListType::Bullet => 1,
+            ListType::Ordered => {
+                marker_and_content.len()
+                    - marker_and_content
+                        .trim_start_matches(|c: char| c.is_ascii_digit())
+                        .len()
+                    + 1
+            }
+        };
+        let Some(content) = marker_and_content.get(marker_width..) else {
+            return vec![];
+        };
+
+        let actual = content.len() - content.trim_start_matches(' ').len();
+        let expected = self.spaces(Self::is_multi(item_node), item.list_type);
+        if actual == content.len() || actual <= expected {
+            return vec![];
+        }
+
+        let range = (start + marker_width)..(start + marker_width + actual);
+        vec![Edit::new(range, " ".repeat(expected))]
+    }
 }
 
 #[cfg(test)]
@@ -125,6 +191,33 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn fix() -> Result<()> {
+        let text = indoc! {"
+            *   Foo
+            *   Bar
+
+            10.  Baz
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD030::default();
+        let actual: Vec<_> = rule
+            .check(&doc)?
+            .iter()
+            .map(|violation| rule.fix(&doc, violation))
+            .collect();
+        let expected = vec![
+            vec![Edit::new(1..4, " ".to_owned())],
+            vec![Edit::new(9..12, " ".to_owned())],
+            vec![Edit::new(20..22, " ".to_owned())],
+        ];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
     #[test]
     fn check_errors_ul() -> Result<()> {
         let text = indoc! {"
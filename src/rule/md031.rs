@@ -15,6 +15,9 @@ impl MD031 {
         description: "Fenced code blocks should be surrounded by blank lines",
         tags: &[Tag::Code, Tag::BlankLines],
         aliases: &["blanks-around-fences"],
+        rationale: "Fenced code blocks should be surrounded by blank lines. Without them, code blocks may not be parsed correctly by some parsers, and the document is harder to read as plain text.",
+        bad_example: "Some text\n```\nCode block\n```\nSome more text\n",
+        good_example: "Some text\n\n```\nCode block\n```\n\nSome more text\n",
     };
 
     #[inline]
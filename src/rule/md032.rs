@@ -15,6 +15,9 @@ impl MD032 {
         description: "Lists should be surrounded by blank lines",
         tags: &[Tag::Bullet, Tag::Ul, Tag::Ol, Tag::BlankLines],
         aliases: &["blanks-around-lists"],
+        rationale: "Lists should be surrounded by blank lines. Without them, lists may not be parsed correctly by some parsers, and a list may be merged into the preceding paragraph.",
+        bad_example: "Some text\n* Some\n* List\n",
+        good_example: "Some text\n\n* Some\n* List\n",
     };
 
     #[inline]
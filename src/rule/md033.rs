@@ -20,6 +20,9 @@ impl MD033 {
         description: "Inline HTML",
         tags: &[Tag::Html],
         aliases: &["no-inline-html"],
+        rationale: "Raw HTML may not be rendered by all parsers, and Markdown should be used instead where possible. Allowed elements can be configured with `allowed-elements`.",
+        bad_example: "<h1>Inline HTML header</h1>\n",
+        good_example: "# Markdown header\n",
     };
 
     pub const DEFAULT_ALLOWED_ELEMENTS: Vec<String> = vec![];
@@ -16,6 +16,9 @@ impl MD034 {
         description: "Bare URL used",
         tags: &[Tag::Links, Tag::Url],
         aliases: &["no-bare-urls"],
+        rationale: "Bare URLs may not be converted into links by some parsers. Wrapping URLs in angle brackets makes them links with any parser.",
+        bad_example: "For more information, see https://example.com.\n",
+        good_example: "For more information, see <https://example.com>.\n",
     };
 
     #[inline]
@@ -8,14 +8,26 @@ use crate::{violation::Violation, Document};
 
 use super::{Metadata, RuleLike, Tag};
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-#[serde(rename_all = "lowercase")]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum HorizontalRuleStyle {
     Consistent,
     Custom(String),
 }
 
+impl Serialize for HorizontalRuleStyle {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Consistent => serializer.serialize_str("consistent"),
+            Self::Custom(hr) => serializer.serialize_str(hr),
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for HorizontalRuleStyle {
     #[inline]
     fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
@@ -43,6 +55,9 @@ impl MD035 {
         description: "Horizontal rule style",
         tags: &[Tag::Hr],
         aliases: &["hr-style"],
+        rationale: "A consistent horizontal rule style makes documents easier to read. The style can be configured with `style`.",
+        bad_example: "---\n\n***\n",
+        good_example: "---\n\n---\n",
     };
 
     pub const DEFAULT_STYLE: HorizontalRuleStyle = HorizontalRuleStyle::Consistent;
@@ -17,6 +17,9 @@ impl MD036 {
         description: "Emphasis used instead of a header",
         tags: &[Tag::Headers, Tag::Emphasis],
         aliases: &["no-emphasis-as-header"],
+        rationale: "Emphasized paragraphs used as headers are not part of the document structure. The punctuation characters of sentences can be configured with `punctuation`.",
+        bad_example: "**My document**\n\nLorem ipsum dolor sit amet...\n",
+        good_example: "# My document\n\nLorem ipsum dolor sit amet...\n",
     };
 
     pub const DEFAULT_PUNCTUATION: &str = ".,;:!?";
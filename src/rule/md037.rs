@@ -18,6 +18,9 @@ impl MD037 {
         description: "Spaces inside emphasis markers",
         tags: &[Tag::Whitespace, Tag::Emphasis],
         aliases: &["no-space-in-emphasis"],
+        rationale: "Emphasis markers with spaces between them and the text are not parsed as emphasis. The markers are rendered as literal asterisks or underscores instead.",
+        bad_example: "Here is some ** bold ** text.\n",
+        good_example: "Here is some **bold** text.\n",
     };
 
     #[inline]
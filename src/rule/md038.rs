@@ -15,6 +15,9 @@ impl MD038 {
         description: "Spaces inside code span elements",
         tags: &[Tag::Whitespace, Tag::Code],
         aliases: &["no-space-in-code"],
+        rationale: "Spaces inside code span markers are usually unintentional. Parsers differ in whether they strip the spaces, so the rendered code may differ.",
+        bad_example: "` some text `\n",
+        good_example: "`some text`\n",
     };
 
     #[inline]
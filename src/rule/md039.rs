@@ -15,6 +15,9 @@ impl MD039 {
         description: "Spaces inside link text",
         tags: &[Tag::Whitespace, Tag::Links],
         aliases: &["no-space-in-links"],
+        rationale: "Spaces inside link text are usually unintentional. They are rendered as part of the link, so the link extends beyond the text.",
+        bad_example: "[ a link ](https://www.example.com/)\n",
+        good_example: "[a link](https://www.example.com/)\n",
     };
 
     #[inline]
@@ -15,6 +15,9 @@ impl MD040 {
         description: "Fenced code blocks should have a language specified",
         tags: &[Tag::Code, Tag::Language],
         aliases: &["fenced-code-language"],
+        rationale: "A language specifier enables syntax highlighting of the code. It also tells readers what language the code is written in.",
+        bad_example: "```\n#!/bin/bash\necho Hello world\n```\n",
+        good_example: "```bash\n#!/bin/bash\necho Hello world\n```\n",
     };
 
     #[inline]
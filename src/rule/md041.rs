@@ -17,6 +17,9 @@ impl MD041 {
         description: "First line in file should be a top level header",
         tags: &[Tag::Headers],
         aliases: &["first-line-h1"],
+        rationale: "The first line of a document is usually the title, so it should be a top level header. The level of the title can be configured with `level`.",
+        bad_example: "This is a file without a header\n",
+        good_example: "# File with header\n\nThis is a file with a top level header\n",
     };
 
     pub const DEFAULT_LEVEL: u8 = 1;
@@ -0,0 +1,124 @@
+use comrak::nodes::NodeValue;
+use miette::Result;
+
+use crate::{violation::Violation, Document};
+
+use super::{Metadata, RuleLike, Tag};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MD042;
+
+impl MD042 {
+    const METADATA: Metadata = Metadata {
+        name: "MD042",
+        description: "No empty links",
+        tags: &[Tag::Links],
+        aliases: &["no-empty-links"],
+        rationale: "Empty links do not lead anywhere. They are usually placeholders left by mistake and should be filled in or removed.",
+        bad_example: "[an empty link]()\n",
+        good_example: "[a link](https://www.example.com/)\n",
+    };
+
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl RuleLike for MD042 {
+    #[inline]
+    fn metadata(&self) -> &'static Metadata {
+        &Self::METADATA
+    }
+
+    #[inline]
+    fn check(&self, doc: &Document) -> Result<Vec<Violation>> {
+        let mut violations = vec![];
+
+        for node in doc.ast.descendants() {
+            // NOTE: Destinations of reference links are already resolved by comrak
+            if let NodeValue::Link(link) = &node.data.borrow().value {
+                if link.url.is_empty() || link.url == "#" {
+                    let position = node.data.borrow().sourcepos;
+                    let violation = self.to_violation(doc.path.clone(), position);
+                    violations.push(violation);
+                }
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use comrak::{nodes::Sourcepos, Arena};
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn check_errors() -> Result<()> {
+        let text = indoc! {"
+            [an empty link]()
+            [an empty link](<>)
+            [an empty fragment](#)
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rule = MD042::new();
+        let actual = rule.check(&doc)?;
+        let expected = vec![
+            rule.to_violation(path.clone(), Sourcepos::from((1, 1, 1, 17))),
+            rule.to_violation(path.clone(), Sourcepos::from((2, 1, 2, 19))),
+            rule.to_violation(path, Sourcepos::from((3, 1, 3, 22))),
+        ];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_errors_reference() -> Result<()> {
+        let text = indoc! {"
+            [an empty link][empty]
+
+            [empty]: #
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rule = MD042::new();
+        let actual = rule.check(&doc)?;
+        let expected = vec![rule.to_violation(path, Sourcepos::from((1, 1, 1, 22)))];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_no_errors() -> Result<()> {
+        let text = indoc! {"
+            [a link](https://www.example.com/)
+            [a fragment](#fragment)
+            [a reference][ref]
+
+            [ref]: https://www.example.com/
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD042::new();
+        let actual = rule.check(&doc)?;
+        let expected = vec![];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+use std::path::Path;
+
+use comrak::nodes::{NodeValue, Sourcepos};
+use miette::Result;
+use scraper::Html;
+
+use crate::{violation::Violation, Document};
+
+use super::{helper::inline_text_of, Metadata, RuleLike, Tag};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MD045 {
+    reject_file_name: bool,
+}
+
+impl MD045 {
+    const METADATA: Metadata = Metadata {
+        name: "MD045",
+        description: "Images should have alternate text (alt text)",
+        tags: &[Tag::Accessibility, Tag::Images],
+        aliases: &["no-alt-text"],
+        rationale: "Alternate text is read by screen readers and shown when images cannot be loaded. Alternate text that is just the file name can be rejected with `reject-file-name`.",
+        bad_example: "![](images/diagram.png)\n",
+        good_example: "![Architecture diagram](images/diagram.png)\n",
+    };
+
+    #[inline]
+    #[must_use]
+    pub const fn new(reject_file_name: bool) -> Self {
+        Self { reject_file_name }
+    }
+
+    fn is_valid_alt(&self, alt: &str, src: &str) -> bool {
+        let alt = alt.trim();
+        if alt.is_empty() {
+            return false;
+        }
+
+        if self.reject_file_name {
+            let path = src.split(['?', '#']).next().unwrap_or_default();
+            let file_name = Path::new(path).file_name().and_then(|name| name.to_str());
+            let file_stem = Path::new(path).file_stem().and_then(|stem| stem.to_str());
+            if file_name.is_some_and(|name| alt.eq_ignore_ascii_case(name))
+                || file_stem.is_some_and(|stem| alt.eq_ignore_ascii_case(stem))
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn check_html(
+        &self,
+        html: &str,
+        path: &Path,
+        position: &Sourcepos,
+        violations: &mut Vec<Violation>,
+    ) {
+        let fragment = Html::parse_fragment(html);
+        for element in fragment.root_element().descendent_elements() {
+            let element = element.value();
+            if element.name() != "img" {
+                continue;
+            }
+
+            let alt = element.attr("alt").unwrap_or_default();
+            let src = element.attr("src").unwrap_or_default();
+            if !self.is_valid_alt(alt, src) {
+                let violation = self.to_violation(path.to_path_buf(), *position);
+                violations.push(violation);
+            }
+        }
+    }
+}
+
+impl RuleLike for MD045 {
+    #[inline]
+    fn metadata(&self) -> &'static Metadata {
+        &Self::METADATA
+    }
+
+    #[inline]
+    fn check(&self, doc: &Document) -> Result<Vec<Violation>> {
+        let mut violations = vec![];
+
+        for node in doc.ast.descendants() {
+            let position = node.data.borrow().sourcepos;
+
+            match &node.data.borrow().value {
+                NodeValue::Image(image) => {
+                    let alt = inline_text_of(node);
+                    if !self.is_valid_alt(&alt, &image.url) {
+                        let violation = self.to_violation(doc.path.clone(), position);
+                        violations.push(violation);
+                    }
+                }
+                NodeValue::HtmlInline(html) => {
+                    self.check_html(html, &doc.path, &position, &mut violations);
+                }
+                NodeValue::HtmlBlock(html) => {
+                    // NOTE: Skip non-html elements (e.g. comments)
+                    //       See https://spec.commonmark.org/0.31.2/#html-blocks
+                    if (2..=5).contains(&html.block_type) {
+                        continue;
+                    }
+
+                    self.check_html(&html.literal, &doc.path, &position, &mut violations);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use comrak::{nodes::Sourcepos, Arena};
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn check_errors() -> Result<()> {
+        let text = indoc! {"
+            ![](images/diagram.png)
+            ![ ](images/diagram.png)
+            ![][diagram]
+
+            [diagram]: images/diagram.png
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rule = MD045::default();
+        let actual = rule.check(&doc)?;
+        let expected = vec![
+            rule.to_violation(path.clone(), Sourcepos::from((1, 1, 1, 23))),
+            rule.to_violation(path.clone(), Sourcepos::from((2, 1, 2, 24))),
+            rule.to_violation(path, Sourcepos::from((3, 1, 3, 12))),
+        ];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_errors_html() -> Result<()> {
+        let text = indoc! {r#"
+            <img src="images/diagram.png">
+
+            Text <img src="images/diagram.png" alt=""> text
+        "#}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rule = MD045::default();
+        let actual = rule.check(&doc)?;
+        let expected = vec![
+            rule.to_violation(path.clone(), Sourcepos::from((1, 1, 1, 30))),
+            rule.to_violation(path, Sourcepos::from((3, 6, 3, 42))),
+        ];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_errors_file_name() -> Result<()> {
+        let text = indoc! {r#"
+            ![diagram.png](images/diagram.png)
+            ![Diagram](images/diagram.png?raw=true)
+            <img src="images/diagram.png" alt="diagram">
+        "#}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rule = MD045::new(true);
+        let actual = rule.check(&doc)?;
+        let expected = vec![
+            rule.to_violation(path.clone(), Sourcepos::from((1, 1, 1, 34))),
+            rule.to_violation(path.clone(), Sourcepos::from((2, 1, 2, 39))),
+            rule.to_violation(path, Sourcepos::from((3, 1, 3, 44))),
+        ];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_no_errors() -> Result<()> {
+        let text = indoc! {r#"
+            ![Architecture diagram](images/diagram.png)
+            ![`mado` logo](images/logo.png)
+            ![diagram.png](images/diagram.png)
+
+            <img src="images/diagram.png" alt="Architecture diagram">
+            <!-- <img src="images/diagram.png"> -->
+        "#}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD045::default();
+        let actual = rule.check(&doc)?;
+        let expected = vec![];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -27,6 +27,9 @@ impl MD046 {
         description: "Code block style",
         tags: &[Tag::Code],
         aliases: &["code-block-style"],
+        rationale: "A consistent code block style makes documents easier to read. The style can be configured with `style`.",
+        bad_example: "Some text.\n\n    Code block\n\n```\nCode block\n```\n",
+        good_example: "Some text.\n\n```\nCode block\n```\n\n```\nCode block\n```\n",
     };
 
     pub const DEFAULT_STYLE: CodeBlockStyle = CodeBlockStyle::Fenced;
@@ -1,7 +1,7 @@
 use comrak::nodes::Sourcepos;
 use miette::Result;
 
-use crate::{violation::Violation, Document};
+use crate::{violation::Violation, Document, Edit};
 
 use super::{Metadata, RuleLike, Tag};
 
@@ -15,6 +15,9 @@ impl MD047 {
         description: "File should end with a single newline character",
         tags: &[Tag::BlankLines],
         aliases: &["single-trailing-newline"],
+        rationale: "Files should end with a single newline character. Files without a trailing newline may not be processed correctly by some tools, and the last line shows up as changed in diffs when text is appended.",
+        bad_example: "This file ends without a newline",
+        good_example: "This file ends with a newline\n",
     };
 
     #[inline]
@@ -43,6 +46,11 @@ impl RuleLike for MD047 {
 
         Ok(vec![violation])
     }
+
+    #[inline]
+    fn fix(&self, doc: &Document, _violation: &Violation) -> Vec<Edit> {
+        vec![Edit::insertion(doc.text.len(), "\n".to_owned())]
+    }
 }
 
 #[cfg(test)]
@@ -68,6 +76,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn fix() -> Result<()> {
+        let text = "Some text".to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD047::new();
+        let actual: Vec<_> = rule
+            .check(&doc)?
+            .iter()
+            .map(|violation| rule.fix(&doc, violation))
+            .collect();
+        let expected = vec![vec![Edit::insertion(9, "\n".to_owned())]];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
     #[test]
     fn check_no_errors() -> Result<()> {
         let text = indoc! {"
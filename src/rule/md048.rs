@@ -0,0 +1,214 @@
+use comrak::nodes::NodeValue;
+use miette::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{violation::Violation, Document};
+
+use super::{Metadata, RuleLike, Tag};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum CodeFenceStyle {
+    Consistent,
+    Backtick,
+    Tilde,
+}
+
+impl CodeFenceStyle {
+    const fn fence_char(self) -> Option<u8> {
+        match self {
+            Self::Consistent => None,
+            Self::Backtick => Some(b'`'),
+            Self::Tilde => Some(b'~'),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MD048 {
+    style: CodeFenceStyle,
+    min_length: usize,
+}
+
+impl MD048 {
+    const METADATA: Metadata = Metadata {
+        name: "MD048",
+        description: "Code fence style",
+        tags: &[Tag::Code],
+        aliases: &["code-fence-style"],
+        rationale: "A consistent code fence style makes documents easier to read. The style can be configured with `style`, and longer fences can be required with `min-length` to keep nested examples readable.",
+        bad_example: "```ruby\n# Fenced code\n```\n\n~~~ruby\n# Fenced code\n~~~\n",
+        good_example: "```ruby\n# Fenced code\n```\n\n```ruby\n# Fenced code\n```\n",
+    };
+
+    pub const DEFAULT_STYLE: CodeFenceStyle = CodeFenceStyle::Consistent;
+    /// Minimum length of code fences in `CommonMark`
+    pub const DEFAULT_MIN_LENGTH: usize = 3;
+
+    #[inline]
+    #[must_use]
+    pub const fn new(style: CodeFenceStyle, min_length: usize) -> Self {
+        Self { style, min_length }
+    }
+}
+
+impl Default for MD048 {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            style: Self::DEFAULT_STYLE,
+            min_length: Self::DEFAULT_MIN_LENGTH,
+        }
+    }
+}
+
+impl RuleLike for MD048 {
+    #[inline]
+    fn metadata(&self) -> &'static Metadata {
+        &Self::METADATA
+    }
+
+    #[inline]
+    fn check(&self, doc: &Document) -> Result<Vec<Violation>> {
+        let mut violations = vec![];
+        let mut maybe_expected_char = self.style.fence_char();
+
+        for node in doc.ast.descendants() {
+            if let NodeValue::CodeBlock(code) = &node.data.borrow().value {
+                if !code.fenced {
+                    continue;
+                }
+
+                let expected_char = *maybe_expected_char.get_or_insert(code.fence_char);
+                if code.fence_char != expected_char || code.fence_length < self.min_length {
+                    let position = node.data.borrow().sourcepos;
+                    let violation = self.to_violation(doc.path.clone(), position);
+                    violations.push(violation);
+                }
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use comrak::{nodes::Sourcepos, Arena};
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn check_errors_with_consistent() -> Result<()> {
+        let text = indoc! {"
+            ```ruby
+            # Fenced code
+            ```
+
+                Indented code
+
+            ~~~ruby
+            # Fenced code
+            ~~~
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rule = MD048::default();
+        let actual = rule.check(&doc)?;
+        let expected = vec![rule.to_violation(path, Sourcepos::from((7, 1, 9, 3)))];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_errors_with_backtick() -> Result<()> {
+        let text = indoc! {"
+            ~~~ruby
+            # Fenced code
+            ~~~
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rule = MD048::new(CodeFenceStyle::Backtick, MD048::DEFAULT_MIN_LENGTH);
+        let actual = rule.check(&doc)?;
+        let expected = vec![rule.to_violation(path, Sourcepos::from((1, 1, 3, 3)))];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_errors_with_tilde() -> Result<()> {
+        let text = indoc! {"
+            ```ruby
+            # Fenced code
+            ```
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rule = MD048::new(CodeFenceStyle::Tilde, MD048::DEFAULT_MIN_LENGTH);
+        let actual = rule.check(&doc)?;
+        let expected = vec![rule.to_violation(path, Sourcepos::from((1, 1, 3, 3)))];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_errors_with_min_length() -> Result<()> {
+        let text = indoc! {"
+            ````markdown
+            ```ruby
+            # Fenced code
+            ```
+            ````
+
+            ```ruby
+            # Fenced code
+            ```
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rule = MD048::new(CodeFenceStyle::Consistent, 4);
+        let actual = rule.check(&doc)?;
+        let expected = vec![rule.to_violation(path, Sourcepos::from((7, 1, 9, 3)))];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_no_errors() -> Result<()> {
+        let text = indoc! {"
+            ```ruby
+            # Fenced code
+            ```
+
+                Indented code
+
+            ````ruby
+            # Fenced code
+            ````
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD048::default();
+        let actual = rule.check(&doc)?;
+        let expected = vec![];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
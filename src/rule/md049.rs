@@ -0,0 +1,247 @@
+use comrak::nodes::NodeValue;
+use miette::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{violation::Violation, Document, Edit};
+
+use super::{
+    helper::{emphasis_marker_of, replace_emphasis_markers},
+    Metadata, RuleLike, Tag,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum EmphasisStyle {
+    Consistent,
+    Asterisk,
+    Underscore,
+}
+
+impl EmphasisStyle {
+    pub(super) const fn marker(self) -> Option<char> {
+        match self {
+            Self::Consistent => None,
+            Self::Asterisk => Some('*'),
+            Self::Underscore => Some('_'),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MD049 {
+    style: EmphasisStyle,
+}
+
+impl MD049 {
+    const METADATA: Metadata = Metadata {
+        name: "MD049",
+        description: "Emphasis style",
+        tags: &[Tag::Emphasis],
+        aliases: &["emphasis-style"],
+        rationale: "A consistent emphasis style makes documents easier to read. The style can be configured with `style`.",
+        bad_example: "Here is some *italic* text and some _italic_ text.\n",
+        good_example: "Here is some *italic* text and some *italic* text.\n",
+    };
+
+    pub const DEFAULT_STYLE: EmphasisStyle = EmphasisStyle::Consistent;
+
+    #[inline]
+    #[must_use]
+    pub const fn new(style: EmphasisStyle) -> Self {
+        Self { style }
+    }
+}
+
+impl Default for MD049 {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            style: Self::DEFAULT_STYLE,
+        }
+    }
+}
+
+impl RuleLike for MD049 {
+    #[inline]
+    fn metadata(&self) -> &'static Metadata {
+        &Self::METADATA
+    }
+
+    #[inline]
+    fn check(&self, doc: &Document) -> Result<Vec<Violation>> {
+        let mut violations = vec![];
+        let mut maybe_expected_marker = self.style.marker();
+
+        for node in doc.ast.descendants() {
+            if node.data.borrow().value == NodeValue::Emph {
+                let position = node.data.borrow().sourcepos;
+                if let Some(marker) = emphasis_marker_of(doc, &position) {
+                    let expected_marker = *maybe_expected_marker.get_or_insert(marker);
+                    if marker != expected_marker {
+                        let violation = self.to_violation(doc.path.clone(), position);
+                        violations.push(violation);
+                    }
+                }
+            }
+        }
+
+        Ok(violations)
+    }
+
+    #[inline]
+    fn fix(&self, doc: &Document, violation: &Violation) -> Vec<Edit> {
+        let position = violation.position();
+        let Some(marker) = emphasis_marker_of(doc, position) else {
+            return vec![];
+        };
+        let replacement = if marker == '*' { '_' } else { '*' };
+        replace_emphasis_markers(doc, position, 1, replacement)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use comrak::{nodes::Sourcepos, Arena};
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn check_errors_with_consistent() -> Result<()> {
+        let text = indoc! {"
+            Here is some *italic* text.
+
+            Here is some _italic_ text.
+
+            > Here is some _italic_ text
+            > with **bold** text.
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rule = MD049::default();
+        let actual = rule.check(&doc)?;
+        let expected = vec![
+            rule.to_violation(path.clone(), Sourcepos::from((3, 14, 3, 21))),
+            rule.to_violation(path, Sourcepos::from((5, 16, 5, 23))),
+        ];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_errors_with_asterisk() -> Result<()> {
+        let text = indoc! {"
+            Here is some *italic* text.
+
+            Here is some _italic_ text.
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rule = MD049::new(EmphasisStyle::Asterisk);
+        let actual = rule.check(&doc)?;
+        let expected = vec![rule.to_violation(path, Sourcepos::from((3, 14, 3, 21)))];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_errors_with_underscore() -> Result<()> {
+        let text = indoc! {"
+            Here is some *italic* text.
+
+            Here is some _italic_ text.
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rule = MD049::new(EmphasisStyle::Underscore);
+        let actual = rule.check(&doc)?;
+        let expected = vec![rule.to_violation(path, Sourcepos::from((1, 14, 1, 21)))];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_no_errors() -> Result<()> {
+        let text = indoc! {"
+            Here is some *italic* text.
+
+            Here is some **bold** and __bold__ text.
+
+            Here is some snake_case_word and `_code_` text.
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD049::default();
+        let actual = rule.check(&doc)?;
+        let expected = vec![];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn fix() -> Result<()> {
+        let text = indoc! {"
+            Here is some _italic_ text.
+
+            Here is some intra*word*italic text.
+
+            Here is some *_nested_* text.
+
+            Here is some *multi
+            line* text.
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD049::new(EmphasisStyle::Underscore);
+        let actual: Vec<_> = rule
+            .check(&doc)?
+            .iter()
+            .map(|violation| rule.fix(&doc, violation))
+            .collect();
+        let expected = vec![
+            vec![],
+            vec![],
+            vec![
+                Edit::new(111..112, "_".to_owned()),
+                Edit::new(122..123, "_".to_owned()),
+            ],
+        ];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn fix_with_asterisk() -> Result<()> {
+        let text = "Here is some _italic_ and intra_word_italic text.\n".to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD049::new(EmphasisStyle::Asterisk);
+        let actual: Vec<_> = rule
+            .check(&doc)?
+            .iter()
+            .map(|violation| rule.fix(&doc, violation))
+            .collect();
+        let expected = vec![vec![
+            Edit::new(13..14, "*".to_owned()),
+            Edit::new(20..21, "*".to_owned()),
+        ]];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
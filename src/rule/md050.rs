@@ -0,0 +1,204 @@
+use comrak::nodes::NodeValue;
+use miette::Result;
+
+use crate::{violation::Violation, Document, Edit};
+
+use super::{
+    helper::{emphasis_marker_of, replace_emphasis_markers},
+    md049::EmphasisStyle,
+    Metadata, RuleLike, Tag,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MD050 {
+    style: EmphasisStyle,
+}
+
+impl MD050 {
+    const METADATA: Metadata = Metadata {
+        name: "MD050",
+        description: "Strong style",
+        tags: &[Tag::Emphasis],
+        aliases: &["strong-style"],
+        rationale: "A consistent strong style makes documents easier to read. The style can be configured with `style`.",
+        bad_example: "Here is some **bold** text and some __bold__ text.\n",
+        good_example: "Here is some **bold** text and some **bold** text.\n",
+    };
+
+    pub const DEFAULT_STYLE: EmphasisStyle = EmphasisStyle::Consistent;
+
+    #[inline]
+    #[must_use]
+    pub const fn new(style: EmphasisStyle) -> Self {
+        Self { style }
+    }
+}
+
+impl Default for MD050 {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            style: Self::DEFAULT_STYLE,
+        }
+    }
+}
+
+impl RuleLike for MD050 {
+    #[inline]
+    fn metadata(&self) -> &'static Metadata {
+        &Self::METADATA
+    }
+
+    #[inline]
+    fn check(&self, doc: &Document) -> Result<Vec<Violation>> {
+        let mut violations = vec![];
+        let mut maybe_expected_marker = self.style.marker();
+
+        for node in doc.ast.descendants() {
+            if node.data.borrow().value == NodeValue::Strong {
+                let position = node.data.borrow().sourcepos;
+                if let Some(marker) = emphasis_marker_of(doc, &position) {
+                    let expected_marker = *maybe_expected_marker.get_or_insert(marker);
+                    if marker != expected_marker {
+                        let violation = self.to_violation(doc.path.clone(), position);
+                        violations.push(violation);
+                    }
+                }
+            }
+        }
+
+        Ok(violations)
+    }
+
+    #[inline]
+    fn fix(&self, doc: &Document, violation: &Violation) -> Vec<Edit> {
+        let position = violation.position();
+        let Some(marker) = emphasis_marker_of(doc, position) else {
+            return vec![];
+        };
+        let replacement = if marker == '*' { '_' } else { '*' };
+        replace_emphasis_markers(doc, position, 2, replacement)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use comrak::{nodes::Sourcepos, Arena};
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn check_errors_with_consistent() -> Result<()> {
+        let text = indoc! {"
+            Here is some **bold** text.
+
+            Here is some __bold__ text.
+
+            - Here is some __bold__ text
+              with *italic* text.
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rule = MD050::default();
+        let actual = rule.check(&doc)?;
+        let expected = vec![
+            rule.to_violation(path.clone(), Sourcepos::from((3, 14, 3, 21))),
+            rule.to_violation(path, Sourcepos::from((5, 16, 5, 23))),
+        ];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_errors_with_asterisk() -> Result<()> {
+        let text = indoc! {"
+            Here is some **bold** text.
+
+            Here is some __bold__ text.
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rule = MD050::new(EmphasisStyle::Asterisk);
+        let actual = rule.check(&doc)?;
+        let expected = vec![rule.to_violation(path, Sourcepos::from((3, 14, 3, 21)))];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_errors_with_underscore() -> Result<()> {
+        let text = indoc! {"
+            Here is some **bold** text.
+
+            Here is some __bold__ text.
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rule = MD050::new(EmphasisStyle::Underscore);
+        let actual = rule.check(&doc)?;
+        let expected = vec![rule.to_violation(path, Sourcepos::from((1, 14, 1, 21)))];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_no_errors() -> Result<()> {
+        let text = indoc! {"
+            Here is some **bold** text.
+
+            Here is some *italic* and _italic_ text.
+
+            Here is some snake__case__word and `__code__` text.
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD050::new(EmphasisStyle::Asterisk);
+        let actual = rule.check(&doc)?;
+        let expected = vec![];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn fix() -> Result<()> {
+        let text = indoc! {"
+            Here is some __bold__ text.
+
+            Here is some intra**word**bold text.
+
+            Here is some **bold** text.
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD050::new(EmphasisStyle::Underscore);
+        let actual: Vec<_> = rule
+            .check(&doc)?
+            .iter()
+            .map(|violation| rule.fix(&doc, violation))
+            .collect();
+        let expected = vec![
+            vec![],
+            vec![
+                Edit::new(80..82, "__".to_owned()),
+                Edit::new(86..88, "__".to_owned()),
+            ],
+        ];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,286 @@
+use core::str;
+
+use comrak::nodes::NodeValue;
+use miette::Result;
+use rustc_hash::{FxHashMap, FxHashSet};
+use scraper::Html;
+
+use crate::{violation::Violation, Document};
+
+use super::{helper::inline_text_of, Metadata, RuleLike, Tag};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MD051 {
+    ignore_case: bool,
+}
+
+impl MD051 {
+    const METADATA: Metadata = Metadata {
+        name: "MD051",
+        description: "Link fragments should be valid",
+        tags: &[Tag::Links],
+        aliases: &["link-fragments"],
+        rationale: "Links to fragments that do not match any heading or HTML anchor in the document are broken. Fragments are compared case-sensitively unless `ignore-case` is enabled.",
+        bad_example: "# Installation\n\nSee [setup](#instalation).\n",
+        good_example: "# Installation\n\nSee [setup](#installation).\n",
+    };
+
+    /// Fragment scrolling to the top of the document on GitHub
+    const TOP_FRAGMENT: &str = "top";
+
+    #[inline]
+    #[must_use]
+    pub const fn new(ignore_case: bool) -> Self {
+        Self { ignore_case }
+    }
+
+    /// Returns the anchor of a heading generated in the same way as GitHub
+    fn slug_of(text: &str) -> String {
+        text.to_lowercase()
+            .chars()
+            .filter(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+            .map(|c| if c == ' ' { '-' } else { c })
+            .collect()
+    }
+
+    fn collect_html_anchors(html: &str, anchors: &mut FxHashSet<String>) {
+        let fragment = Html::parse_fragment(html);
+        for element in fragment.root_element().descendent_elements() {
+            let element = element.value();
+            if let Some(id) = element.attr("id") {
+                anchors.insert(id.to_owned());
+            }
+
+            if element.name() == "a" {
+                if let Some(name) = element.attr("name") {
+                    anchors.insert(name.to_owned());
+                }
+            }
+        }
+    }
+
+    fn anchors_of(doc: &Document) -> FxHashSet<String> {
+        let mut anchors = FxHashSet::default();
+        let mut occurrences: FxHashMap<String, usize> = FxHashMap::default();
+
+        for node in doc.ast.descendants() {
+            match &node.data.borrow().value {
+                NodeValue::Heading(_) => {
+                    // NOTE: Duplicate headings are suffixed with `-1`, `-2` and so on
+                    let slug = Self::slug_of(&inline_text_of(node));
+                    let mut anchor = slug.clone();
+                    while occurrences.contains_key(&anchor) {
+                        let count = occurrences.entry(slug.clone()).or_default();
+                        *count += 1;
+                        anchor = format!("{slug}-{count}");
+                    }
+                    occurrences.insert(anchor.clone(), 0);
+                    anchors.insert(anchor);
+                }
+                NodeValue::HtmlInline(html) => Self::collect_html_anchors(html, &mut anchors),
+                NodeValue::HtmlBlock(html) => {
+                    // NOTE: Skip non-html elements (e.g. comments)
+                    //       See https://spec.commonmark.org/0.31.2/#html-blocks
+                    if !(2..=5).contains(&html.block_type) {
+                        Self::collect_html_anchors(&html.literal, &mut anchors);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        anchors
+    }
+
+    /// Decodes percent-encoded bytes, or returns the fragment as is if it is not valid UTF-8
+    fn decode(fragment: &str) -> String {
+        let bytes = fragment.as_bytes();
+        let mut decoded = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            let hex = bytes
+                .get(i + 1..i + 3)
+                .and_then(|hex| str::from_utf8(hex).ok())
+                .filter(|_| bytes[i] == b'%')
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+            if let Some(byte) = hex {
+                decoded.push(byte);
+                i += 3;
+            } else {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+
+        String::from_utf8(decoded).unwrap_or_else(|_| fragment.to_owned())
+    }
+}
+
+impl RuleLike for MD051 {
+    #[inline]
+    fn metadata(&self) -> &'static Metadata {
+        &Self::METADATA
+    }
+
+    #[inline]
+    fn check(&self, doc: &Document) -> Result<Vec<Violation>> {
+        let mut violations = vec![];
+        let mut anchors = Self::anchors_of(doc);
+        if self.ignore_case {
+            anchors = anchors.iter().map(|anchor| anchor.to_lowercase()).collect();
+        }
+
+        for node in doc.ast.descendants() {
+            if let NodeValue::Link(link) = &node.data.borrow().value {
+                let Some(fragment) = link.url.strip_prefix('#') else {
+                    continue;
+                };
+
+                // NOTE: Empty fragments are reported by MD042
+                if fragment.is_empty() || fragment == Self::TOP_FRAGMENT {
+                    continue;
+                }
+
+                let mut fragment = Self::decode(fragment);
+                if self.ignore_case {
+                    fragment = fragment.to_lowercase();
+                }
+
+                if !anchors.contains(&fragment) {
+                    let position = node.data.borrow().sourcepos;
+                    let violation = self.to_violation(doc.path.clone(), position);
+                    violations.push(violation);
+                }
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use comrak::{nodes::Sourcepos, Arena};
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn check_errors() -> Result<()> {
+        let text = indoc! {"
+            # Installation
+
+            See [setup](#instalation) and [usage][usage].
+
+            Go to [Installation](#Installation).
+
+            [usage]: #usage
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rule = MD051::default();
+        let actual = rule.check(&doc)?;
+        let expected = vec![
+            rule.to_violation(path.clone(), Sourcepos::from((3, 5, 3, 25))),
+            rule.to_violation(path.clone(), Sourcepos::from((3, 31, 3, 44))),
+            rule.to_violation(path, Sourcepos::from((5, 7, 5, 35))),
+        ];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_errors_with_duplicate_headings() -> Result<()> {
+        let text = indoc! {"
+            # Usage
+
+            ## Usage
+
+            See [usage](#usage), [usage](#usage-1) and [usage](#usage-2).
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rule = MD051::default();
+        let actual = rule.check(&doc)?;
+        let expected = vec![rule.to_violation(path, Sourcepos::from((5, 44, 5, 60)))];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_no_errors() -> Result<()> {
+        let text = indoc! {"
+            # Getting Started: `mado` & Rust!
+
+            ## Setup
+
+            Setup
+            -----
+
+            ## Café au lait
+
+            <a id=\"custom-id\"></a>
+
+            <a name=\"custom-name\"></a>
+
+            <div id=\"custom-div\">
+
+            Text
+
+            </div>
+
+            - [Getting Started](#getting-started-mado--rust)
+            - [Setup](#setup) and [Setup](#setup-1)
+            - [Café](#café-au-lait) and [Café](#caf%C3%A9-au-lait)
+            - [HTML](#custom-id), [HTML](#custom-name) and [HTML](#custom-div)
+            - [Top](#top), [Empty](#) and [External](https://example.com/#foo)
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD051::default();
+        let actual = rule.check(&doc)?;
+        let expected = vec![];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_no_errors_with_ignore_case() -> Result<()> {
+        let text = indoc! {"
+            # Installation
+
+            <a id=\"Custom-ID\"></a>
+
+            Go to [Installation](#Installation) and [HTML](#custom-id).
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path, text)?;
+        let rule = MD051::new(true);
+        let actual = rule.check(&doc)?;
+        let expected = vec![];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn slug_of() {
+        assert_eq!(MD051::slug_of("Hello, World!"), "hello-world");
+        assert_eq!(
+            MD051::slug_of("snake_case and kebab-case"),
+            "snake_case-and-kebab-case"
+        );
+        assert_eq!(MD051::slug_of("`code` & text"), "code--text");
+    }
+}
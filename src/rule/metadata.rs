@@ -7,4 +7,10 @@ pub struct Metadata {
     pub description: &'static str,
     pub tags: &'static [Tag],
     pub aliases: &'static [&'static str],
+    /// Why the rule exists
+    pub rationale: &'static str,
+    /// Markdown violating the rule
+    pub bad_example: &'static str,
+    /// Markdown following the rule
+    pub good_example: &'static str,
 }
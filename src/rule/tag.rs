@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "kebab-case")]
 #[non_exhaustive]
 pub enum Tag {
+    Accessibility,
     Atx,
     AtxClosed,
     BlankLines,
@@ -15,6 +16,7 @@ pub enum Tag {
     Headers,
     Hr,
     Html,
+    Images,
     Indentation,
     Language,
     LineLength,
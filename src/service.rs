@@ -1,6 +1,11 @@
+pub mod cache;
+pub mod diff;
+pub mod filter;
+pub mod fixer;
 mod linter;
 pub mod runner;
 pub mod visitor;
 pub mod walker;
 
 pub use linter::Linter;
+pub use linter::LinterResolver;
@@ -0,0 +1,258 @@
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::hash::{Hash as _, Hasher as _};
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use comrak::nodes::Sourcepos;
+use etcetera::choose_base_strategy;
+use etcetera::BaseStrategy as _;
+use miette::{IntoDiagnostic as _, Result};
+use rustc_hash::FxHasher;
+use serde::{Deserialize, Serialize};
+
+use super::Linter;
+use crate::directive::Directive;
+use crate::rule::Metadata;
+use crate::{Edit, Rule, Severity, Violation};
+
+/// On-disk cache of violations of each file.
+/// Entries are invalidated when the file content, the linter or the version of mado changes.
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+    catalogue: Vec<&'static Metadata>,
+    /// Whether a failure to write the cache has been reported.
+    /// The cache is best-effort, and failures never fail linting.
+    warned: Arc<AtomicBool>,
+}
+
+impl Cache {
+    /// Returns the user cache directory of mado such as `~/.cache/mado`
+    #[inline]
+    #[must_use]
+    pub fn root() -> Option<PathBuf> {
+        let strategy = choose_base_strategy().ok()?;
+        Some(strategy.cache_dir().join("mado"))
+    }
+
+    /// Creates the cache of the current directory under the root,
+    /// removing caches of other versions of mado
+    #[inline]
+    pub fn new(root: &Path) -> Result<Self> {
+        let version_dir = root.join(env!("CARGO_PKG_VERSION"));
+        fs::create_dir_all(&version_dir).into_diagnostic()?;
+        Self::prune(root, &version_dir);
+
+        // Separate caches of projects sharing relative paths
+        let mut hasher = FxHasher::default();
+        env::current_dir().into_diagnostic()?.hash(&mut hasher);
+        let dir = version_dir.join(format!("{:016x}", hasher.finish()));
+        fs::create_dir_all(&dir).into_diagnostic()?;
+
+        let catalogue = Rule::all()
+            .iter()
+            .map(Rule::metadata)
+            .chain([&Directive::UNUSED_METADATA])
+            .collect();
+        Ok(Self {
+            dir,
+            catalogue,
+            warned: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    // Removes directories of other versions ignoring errors
+    fn prune(root: &Path, version_dir: &Path) {
+        let Ok(entries) = fs::read_dir(root) else {
+            return;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path != version_dir && path.is_dir() {
+                let _ = fs::remove_dir_all(path);
+            }
+        }
+    }
+
+    /// Returns the key of the file content linted by the linter
+    #[inline]
+    #[must_use]
+    pub fn key(text: &str, linter: &Linter) -> u64 {
+        let mut hasher = FxHasher::default();
+        text.hash(&mut hasher);
+        linter.fingerprint().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns cached violations of the file if the key matches
+    #[inline]
+    #[must_use]
+    pub fn get(&self, path: &Path, key: u64) -> Option<Vec<Violation>> {
+        let text = fs::read_to_string(self.entry_path(path)).ok()?;
+        let entry: Entry = serde_json::from_str(&text).ok()?;
+        if entry.key != key {
+            return None;
+        }
+
+        entry
+            .violations
+            .into_iter()
+            .map(|cached| {
+                let metadata = self
+                    .catalogue
+                    .iter()
+                    .find(|metadata| metadata.name == cached.rule)?;
+                let violation = Violation::new(
+                    path.to_path_buf(),
+                    metadata,
+                    Sourcepos::from(cached.position),
+                )
+                .with_severity(cached.severity)
+                .with_edits(cached.edits);
+                Some(violation)
+            })
+            .collect()
+    }
+
+    /// Caches violations of the file, reporting the first failure to stderr only
+    #[inline]
+    pub fn set(&self, path: &Path, key: u64, violations: &[Violation]) {
+        if let Err(err) = self.write(path, key, violations) {
+            if !self.warned.swap(true, Ordering::Relaxed) {
+                eprintln!("Failed to write the cache: {err}");
+            }
+        }
+    }
+
+    fn write(&self, path: &Path, key: u64, violations: &[Violation]) -> Result<()> {
+        let entry = Entry {
+            key,
+            violations: violations.iter().map(CachedViolation::from).collect(),
+        };
+        let text = serde_json::to_string(&entry).into_diagnostic()?;
+        fs::write(self.entry_path(path), text).into_diagnostic()
+    }
+
+    fn entry_path(&self, path: &Path) -> PathBuf {
+        let mut hasher = FxHasher::default();
+        path.hash(&mut hasher);
+        self.dir.join(format!("{:016x}.json", hasher.finish()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Entry {
+    key: u64,
+    violations: Vec<CachedViolation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CachedViolation {
+    rule: String,
+    /// Start line, start column, end line and end column
+    position: (usize, usize, usize, usize),
+    severity: Severity,
+    edits: Vec<Edit>,
+}
+
+impl From<&Violation> for CachedViolation {
+    #[inline]
+    fn from(violation: &Violation) -> Self {
+        let position = violation.position();
+        Self {
+            rule: violation.name().to_owned(),
+            position: (
+                position.start.line,
+                position.start.column,
+                position.end.line,
+                position.end.column,
+            ),
+            severity: violation.severity(),
+            edits: violation.edits().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    use crate::rule::RuleLike as _;
+    use crate::rule::MD026;
+
+    use super::*;
+
+    #[test]
+    fn get_and_set() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let cache = Cache::new(tmp_dir.path())?;
+        let path = Path::new("docs/README.md");
+        let violations = vec![
+            MD026::default()
+                .to_violation(path.to_path_buf(), Sourcepos::from((1, 1, 1, 8)))
+                .with_edits(vec![Edit::deletion(7..8)])
+                .with_severity(Severity::Warning),
+            Violation::new(
+                path.to_path_buf(),
+                &Directive::UNUSED_METADATA,
+                Sourcepos::from((3, 1, 3, 30)),
+            ),
+        ];
+
+        assert_eq!(cache.get(path, 1), None);
+        cache.set(path, 1, &violations);
+        assert_eq!(cache.get(path, 1), Some(violations));
+        assert_eq!(cache.get(path, 2), None);
+        assert_eq!(cache.get(Path::new("README.md"), 1), None);
+        tmp_dir.close().into_diagnostic()
+    }
+
+    #[test]
+    fn new_prunes_other_versions() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let old_dir = tmp_dir.path().join("0.0.0");
+        fs::create_dir_all(&old_dir).into_diagnostic()?;
+        Cache::new(tmp_dir.path())?;
+        assert!(!fs::exists(&old_dir).into_diagnostic()?);
+        let version_dir = tmp_dir.path().join(env!("CARGO_PKG_VERSION"));
+        assert!(fs::exists(version_dir).into_diagnostic()?);
+        tmp_dir.close().into_diagnostic()
+    }
+
+    #[test]
+    fn set_without_dir() -> Result<()> {
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let cache = Cache::new(tmp_dir.path())?;
+        fs::remove_dir_all(tmp_dir.path()).into_diagnostic()?;
+
+        // Failures are reported but not returned
+        let path = Path::new("README.md");
+        cache.set(path, 1, &[]);
+        assert_eq!(cache.get(path, 1), None);
+        Ok(())
+    }
+
+    #[test]
+    fn key() {
+        let linter = Linter::new(vec![Rule::MD026(MD026::default())]);
+        let other_linter = Linter::new(vec![]);
+        assert_eq!(
+            Cache::key("# Hello", &linter),
+            Cache::key("# Hello", &linter)
+        );
+        assert_ne!(
+            Cache::key("# Hello", &linter),
+            Cache::key("# Hello.", &linter)
+        );
+        assert_ne!(
+            Cache::key("# Hello", &linter),
+            Cache::key("# Hello", &other_linter)
+        );
+    }
+}
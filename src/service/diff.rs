@@ -0,0 +1,332 @@
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use core::iter;
+use core::ops::Range;
+use std::env;
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+
+use miette::{miette, IntoDiagnostic as _, Result};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::Violation;
+
+/// Changed files and lines relative to a git ref
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Diff {
+    current_dir: PathBuf,
+    /// Changed 1-based line ranges by absolute paths, or None if the whole file is new
+    files: FxHashMap<PathBuf, Option<Vec<Range<usize>>>>,
+}
+
+impl Diff {
+    /// Computes changes of the working tree relative to the ref with the git command
+    #[inline]
+    pub fn from_git(base: &str) -> Result<Self> {
+        // Non-ASCII paths are not quoted with `core.quotePath=false`
+        let diff = Self::git(&[
+            "-c",
+            "core.quotePath=false",
+            "diff",
+            "--unified=0",
+            "--no-color",
+            "--no-ext-diff",
+            "--relative",
+            base,
+            "--",
+        ])?;
+        let untracked = Self::git(&["ls-files", "-z", "--others", "--exclude-standard"])?;
+        let current_dir = env::current_dir().into_diagnostic()?;
+        Ok(Self::parse(&current_dir, &diff, &untracked))
+    }
+
+    fn git(args: &[&str]) -> Result<String> {
+        let output = Command::new("git")
+            .args(args)
+            .output()
+            .map_err(|err| miette!("Failed to run git: {err}"))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(miette!("git {} failed: {}", args.join(" "), stderr.trim()));
+        }
+
+        String::from_utf8(output.stdout).into_diagnostic()
+    }
+
+    /// Parses `git diff --unified=0` and a NUL-separated list of untracked files.
+    /// Paths are relative to the current directory.
+    fn parse(current_dir: &Path, diff: &str, untracked: &str) -> Self {
+        let mut files: FxHashMap<PathBuf, Option<Vec<Range<usize>>>> = FxHashMap::default();
+        let mut current_path = None;
+        for line in diff.lines() {
+            if let Some(new_file) = line.strip_prefix("+++ ") {
+                // Paths with spaces end with a tab, and deleted files are `+++ /dev/null`
+                let new_file = unquote(new_file.strip_suffix('\t').unwrap_or(new_file));
+                current_path = new_file
+                    .strip_prefix("b/")
+                    .map(|relative_path| normalize(&current_dir.join(relative_path)));
+                if let Some(path) = &current_path {
+                    files.insert(path.clone(), Some(vec![]));
+                }
+            } else if let (Some(hunk), Some(path)) = (line.strip_prefix("@@ "), &current_path) {
+                if let Some(range) = parse_hunk(hunk) {
+                    if let Some(Some(ranges)) = files.get_mut(path) {
+                        ranges.push(range);
+                    }
+                }
+            }
+        }
+
+        for path in untracked.split('\0').filter(|path| !path.is_empty()) {
+            files.insert(normalize(&current_dir.join(path)), None);
+        }
+
+        Self {
+            current_dir: current_dir.to_path_buf(),
+            files,
+        }
+    }
+
+    /// Returns changed files under the patterns as paths joined to the patterns.
+    /// Files under overlapping patterns are returned once for the first pattern.
+    #[inline]
+    #[must_use]
+    pub fn paths(&self, patterns: &[PathBuf]) -> Vec<PathBuf> {
+        let mut seen = FxHashSet::default();
+        let mut paths: Vec<_> = patterns
+            .iter()
+            .flat_map(|pattern| {
+                let base = normalize(&self.current_dir.join(pattern));
+                self.files.iter().filter_map(move |(path, _)| {
+                    let relative_path = path.strip_prefix(&base).ok()?;
+                    Some((path, pattern.join(relative_path)))
+                })
+            })
+            .filter(|(path, _)| seen.insert(*path))
+            .map(|(_, joined_path)| joined_path)
+            .collect();
+        paths.sort();
+        paths
+    }
+
+    /// Returns true if the violation intersects changed lines
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, violation: &Violation) -> bool {
+        let path = normalize(&self.current_dir.join(violation.path()));
+        match self.files.get(&path) {
+            Some(Some(ranges)) => {
+                let position = violation.position();
+                ranges.iter().any(|range| {
+                    range.start <= position.end.line && position.start.line < range.end
+                })
+            }
+            Some(None) => true,
+            None => false,
+        }
+    }
+}
+
+// Removes `.` components
+fn normalize(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| *component != Component::CurDir)
+        .collect()
+}
+
+// Unquotes a path quoted by git in the C style such as `"caf\303\251.md"`
+fn unquote(path: &str) -> Cow<'_, str> {
+    let Some(quoted) = path
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+    else {
+        return Cow::Borrowed(path);
+    };
+
+    let mut bytes = Vec::with_capacity(quoted.len());
+    let mut chars = quoted.chars();
+    while let Some(char) = chars.next() {
+        if char != '\\' {
+            let mut buffer = [0; 4];
+            bytes.extend_from_slice(char.encode_utf8(&mut buffer).as_bytes());
+            continue;
+        }
+
+        match chars.next() {
+            Some('a') => bytes.push(0x07),
+            Some('b') => bytes.push(0x08),
+            Some('f') => bytes.push(0x0c),
+            Some('n') => bytes.push(b'\n'),
+            Some('r') => bytes.push(b'\r'),
+            Some('t') => bytes.push(b'\t'),
+            Some('v') => bytes.push(0x0b),
+            // Octal escapes of bytes such as `\303`
+            Some(digit @ '0'..='3') => {
+                let octal: String = iter::once(digit).chain(chars.by_ref().take(2)).collect();
+                bytes.push(u8::from_str_radix(&octal, 8).unwrap_or_default());
+            }
+            Some(other) => {
+                let mut buffer = [0; 4];
+                bytes.extend_from_slice(other.encode_utf8(&mut buffer).as_bytes());
+            }
+            None => {}
+        }
+    }
+
+    Cow::Owned(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+// Returns the range of new lines from a hunk header such as `-1,2 +3,4 @@`
+fn parse_hunk(hunk: &str) -> Option<Range<usize>> {
+    let new_range = hunk.split_whitespace().nth(1)?.strip_prefix('+')?;
+    let (start, count) = match new_range.split_once(',') {
+        Some((start, count)) => (start.parse::<usize>().ok()?, count.parse::<usize>().ok()?),
+        None => (new_range.parse::<usize>().ok()?, 1),
+    };
+
+    // Hunks only with deletions have no new lines
+    (count > 0).then_some(start..start + count)
+}
+
+#[cfg(test)]
+mod tests {
+    use comrak::nodes::Sourcepos;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use crate::rule::RuleLike as _;
+    use crate::rule::MD026;
+
+    use super::*;
+
+    const DIFF: &str = indoc! {"
+        diff --git a/docs/guide.md b/docs/guide.md
+        index 1111111..2222222 100644
+        --- a/docs/guide.md
+        +++ b/docs/guide.md
+        @@ -3 +3 @@ Intro
+        -old
+        +new
+        @@ -10,0 +11,2 @@ Usage
+        +added
+        +added
+        @@ -20,2 +21,0 @@
+        -removed
+        -removed
+        diff --git a/README.md b/README.md
+        deleted file mode 100644
+        --- a/README.md
+        +++ /dev/null
+        @@ -1 +0,0 @@
+        -# Hello
+    "};
+
+    fn diff() -> Diff {
+        Diff::parse(Path::new("/repo"), DIFF, "docs/new.md\0")
+    }
+
+    #[test]
+    fn parse() {
+        let actual = diff();
+        assert_eq!(actual.files.len(), 2);
+        assert_eq!(
+            actual.files[Path::new("/repo/docs/guide.md")],
+            Some(vec![3..4, 11..13])
+        );
+        assert_eq!(actual.files[Path::new("/repo/docs/new.md")], None);
+    }
+
+    #[test]
+    fn paths() {
+        let patterns = vec![PathBuf::from("."), PathBuf::from("docs/new.md")];
+        let actual = diff().paths(&patterns);
+        let expected = vec![
+            PathBuf::from("./docs/guide.md"),
+            PathBuf::from("./docs/new.md"),
+        ];
+        assert_eq!(actual, expected);
+
+        let patterns_with_prefix = vec![PathBuf::from("docs"), PathBuf::from("./docs/new.md")];
+        let actual_with_prefix = diff().paths(&patterns_with_prefix);
+        let expected_with_prefix =
+            vec![PathBuf::from("docs/guide.md"), PathBuf::from("docs/new.md")];
+        assert_eq!(actual_with_prefix, expected_with_prefix);
+
+        let actual_in_src = diff().paths(&[PathBuf::from("src")]);
+        assert_eq!(actual_in_src, Vec::<PathBuf>::new());
+    }
+
+    #[test]
+    fn parse_quoted_paths() {
+        let diff = indoc! {"
+            diff --git \"a/caf\\303\\251.md\" \"b/caf\\303\\251.md\"
+            --- \"a/caf\\303\\251.md\"
+            +++ \"b/caf\\303\\251.md\"
+            @@ -1 +1 @@
+            -old
+            +new
+            @@ -3 +3 @@
+            -old
+            +new
+            diff --git a/my notes.md b/my notes.md
+            --- a/my notes.md\t
+            +++ b/my notes.md\t
+            @@ -2 +2 @@
+            -old
+            +new
+            @@ -5 +5 @@
+            -old
+            +new
+        "};
+        let actual = Diff::parse(
+            Path::new("/repo"),
+            diff,
+            "docs/\u{65e5}\u{672c}.md\0a\nb.md\0",
+        );
+        assert_eq!(actual.files.len(), 4);
+        assert_eq!(
+            actual.files[Path::new("/repo/caf\u{e9}.md")],
+            Some(vec![1..2, 3..4])
+        );
+        assert_eq!(
+            actual.files[Path::new("/repo/my notes.md")],
+            Some(vec![2..3, 5..6])
+        );
+        assert_eq!(
+            actual.files[Path::new("/repo/docs/\u{65e5}\u{672c}.md")],
+            None
+        );
+        assert_eq!(actual.files[Path::new("/repo/a\nb.md")], None);
+    }
+
+    #[test]
+    fn unquote_path() {
+        assert_eq!(unquote("docs/a.md"), "docs/a.md");
+        assert_eq!(unquote("\"b/caf\\303\\251.md\""), "b/caf\u{e9}.md");
+        assert_eq!(unquote("\"b/a\\tb\\\"c\\\\d.md\""), "b/a\tb\"c\\d.md");
+    }
+
+    #[test]
+    fn contains() {
+        let diff = diff();
+        let md026 = MD026::default();
+        let path = PathBuf::from("./docs/guide.md");
+        assert!(diff.contains(&md026.to_violation(path.clone(), Sourcepos::from((3, 1, 3, 5)))));
+        assert!(diff.contains(&md026.to_violation(path.clone(), Sourcepos::from((10, 1, 11, 5)))));
+        assert!(!diff.contains(&md026.to_violation(path.clone(), Sourcepos::from((4, 1, 10, 5)))));
+        assert!(!diff.contains(&md026.to_violation(path, Sourcepos::from((21, 1, 21, 5)))));
+
+        let new_path = PathBuf::from("docs/new.md");
+        assert!(diff.contains(&md026.to_violation(new_path, Sourcepos::from((100, 1, 100, 5)))));
+        let other_path = PathBuf::from("docs/other.md");
+        assert!(!diff.contains(&md026.to_violation(other_path, Sourcepos::from((1, 1, 1, 5)))));
+    }
+
+    #[test]
+    fn parse_hunk_count() {
+        assert_eq!(parse_hunk("-1 +2 @@"), Some(2..3));
+        assert_eq!(parse_hunk("-1,3 +2,4 @@ heading"), Some(2..6));
+        assert_eq!(parse_hunk("-1,3 +0,0 @@"), None);
+    }
+}
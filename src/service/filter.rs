@@ -0,0 +1,140 @@
+use std::path::Path;
+
+use globset::GlobSet;
+use ignore::types::{Types, TypesBuilder};
+use ignore::DirEntry;
+use miette::{IntoDiagnostic as _, Result};
+
+use crate::config::Lint;
+
+/// Selects files to lint by `lint.extensions`, `lint.include` and `lint.exclude`
+#[derive(Debug, Clone)]
+pub struct FileFilter {
+    extensions: Vec<String>,
+    inclusion: GlobSet,
+    exclusion: GlobSet,
+}
+
+impl FileFilter {
+    #[inline]
+    pub fn new(lint: &Lint) -> Result<Self> {
+        Ok(Self {
+            extensions: lint.extensions.clone(),
+            inclusion: lint.include_set()?,
+            exclusion: lint.exclude_set()?,
+        })
+    }
+
+    /// Returns true if the file has one of the extensions or matches `lint.include`,
+    /// and does not match `lint.exclude`
+    #[inline]
+    #[must_use]
+    pub fn is_match(&self, path: &Path) -> bool {
+        let path = normalize(path);
+        let is_included = path.extension().is_some_and(|extension| {
+            self.extensions
+                .iter()
+                .any(|expected| extension == expected.as_str())
+        }) || self.inclusion.is_match(path);
+        is_included && !self.exclusion.is_match(path)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_excluded(&self, path: &Path) -> bool {
+        self.exclusion.is_match(normalize(path))
+    }
+
+    /// Returns true if the entry is a file to lint.
+    /// Files given explicitly are linted regardless of extensions.
+    #[inline]
+    #[must_use]
+    pub fn is_match_entry(&self, entry: &DirEntry) -> bool {
+        let path = entry.path();
+        if !path.is_file() {
+            return false;
+        }
+
+        if entry.depth() == 0 {
+            !self.is_excluded(path)
+        } else {
+            self.is_match(path)
+        }
+    }
+
+    /// Returns file types to skip other files while walking directories,
+    /// or None if any file may match `lint.include`
+    #[inline]
+    pub fn types(&self) -> Result<Option<Types>> {
+        if !self.inclusion.is_empty() || self.extensions.is_empty() {
+            return Ok(None);
+        }
+
+        let mut builder = TypesBuilder::new();
+        for extension in &self.extensions {
+            builder
+                .add("mado", &format!("*.{extension}"))
+                .into_diagnostic()?;
+        }
+        let types = builder.select("mado").build().into_diagnostic()?;
+        Ok(Some(types))
+    }
+}
+
+// Ignores the leading `./` to match patterns regardless of how files are specified
+fn normalize(path: &Path) -> &Path {
+    path.strip_prefix(".").unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use globset::Glob;
+    use ignore::WalkBuilder;
+
+    use super::*;
+
+    #[test]
+    fn is_match() -> Result<()> {
+        let lint = Lint {
+            extensions: vec!["md".to_owned(), "mdx".to_owned()],
+            include: vec![Glob::new("docs/*.txt").into_diagnostic()?],
+            exclude: vec![Glob::new("docs/ignored.*").into_diagnostic()?],
+            ..Lint::default()
+        };
+        let filter = FileFilter::new(&lint)?;
+        assert!(filter.is_match(Path::new("README.md")));
+        assert!(filter.is_match(Path::new("page.mdx")));
+        assert!(filter.is_match(Path::new("docs/notes.txt")));
+        assert!(filter.is_match(Path::new("./docs/notes.txt")));
+        assert!(!filter.is_match(Path::new("notes.txt")));
+        assert!(!filter.is_match(Path::new("page.markdown")));
+        assert!(!filter.is_match(Path::new("docs/ignored.md")));
+        assert!(!filter.is_match(Path::new("./docs/ignored.md")));
+        assert!(filter.is_excluded(Path::new("./docs/ignored.md")));
+        Ok(())
+    }
+
+    #[test]
+    fn is_match_entry_explicit_file() -> Result<()> {
+        let filter = FileFilter::new(&Lint::default())?;
+        let walk = WalkBuilder::new("mado.toml").build();
+        let entries = walk.collect::<Result<Vec<_>, _>>().into_diagnostic()?;
+        assert!(entries.iter().all(|entry| filter.is_match_entry(entry)));
+        Ok(())
+    }
+
+    #[test]
+    fn types() -> Result<()> {
+        let filter = FileFilter::new(&Lint::default())?;
+        let types = filter.types()?.ok_or_else(|| miette::miette!("no types"))?;
+        assert!(types.matched("README.md", false).is_whitelist());
+        assert!(types.matched("mado.toml", false).is_ignore());
+
+        let lint = Lint {
+            include: vec![Glob::new("*.txt").into_diagnostic()?],
+            ..Lint::default()
+        };
+        assert!(FileFilter::new(&lint)?.types()?.is_none());
+        Ok(())
+    }
+}
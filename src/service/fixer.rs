@@ -0,0 +1,130 @@
+use std::fs;
+use std::path::PathBuf;
+
+use miette::IntoDiagnostic as _;
+use miette::Result;
+use rustc_hash::FxHashMap;
+
+use crate::{Edit, Violation};
+
+#[non_exhaustive]
+pub struct Fixer;
+
+impl Fixer {
+    /// Applies edits of violations that do not overlap with each other.
+    /// Returns the fixed text and the number of fixed violations.
+    #[inline]
+    #[must_use]
+    pub fn apply(text: &str, violations: &[&Violation]) -> (String, usize) {
+        let mut fixable: Vec<Vec<&Edit>> = violations
+            .iter()
+            .filter(|violation| violation.is_fixable())
+            .map(|violation| {
+                let mut edits: Vec<_> = violation.edits().iter().collect();
+                edits.sort();
+                edits
+            })
+            .collect();
+        fixable.sort();
+
+        let mut applied: Vec<&Edit> = vec![];
+        let mut num_fixed = 0;
+        for edits in fixable {
+            // Edits of a violation are applied all together or not at all
+            let mut last = applied.last().copied();
+            let is_applicable = edits.iter().all(|edit| {
+                let range = edit.range();
+                let is_valid = range.start <= range.end
+                    && text.is_char_boundary(range.start)
+                    && text.is_char_boundary(range.end);
+                let overlaps = last.is_some_and(|prev| {
+                    range.start < prev.range().end || (range.is_empty() && prev.range() == range)
+                });
+                last = Some(edit);
+                is_valid && !overlaps
+            });
+
+            if is_applicable {
+                applied.extend(edits);
+                num_fixed += 1;
+            }
+        }
+
+        (Edit::apply_all(text, &applied), num_fixed)
+    }
+
+    /// Rewrites files in place with edits of violations.
+    /// Returns the fixed files and the number of fixed violations.
+    #[inline]
+    pub fn fix_files(violations: &[Violation]) -> Result<(Vec<PathBuf>, usize)> {
+        let mut violations_by_path: FxHashMap<&PathBuf, Vec<&Violation>> = FxHashMap::default();
+        for violation in violations.iter().filter(|violation| violation.is_fixable()) {
+            violations_by_path
+                .entry(violation.path())
+                .or_default()
+                .push(violation);
+        }
+
+        let mut paths = vec![];
+        let mut num_fixed = 0;
+        for (path, path_violations) in violations_by_path {
+            let text = fs::read_to_string(path).into_diagnostic()?;
+            let (fixed_text, num_fixed_in_file) = Self::apply(&text, &path_violations);
+            if num_fixed_in_file > 0 && fixed_text != text {
+                fs::write(path, fixed_text).into_diagnostic()?;
+                paths.push(path.clone());
+                num_fixed += num_fixed_in_file;
+            }
+        }
+
+        paths.sort();
+        Ok((paths, num_fixed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use comrak::nodes::Sourcepos;
+    use pretty_assertions::assert_eq;
+
+    use crate::rule::RuleLike as _;
+    use crate::rule::{MD009, MD010, MD047};
+
+    use super::*;
+
+    #[test]
+    fn apply() {
+        let text = "Text \nText\tbody";
+        let path = Path::new("test.md").to_path_buf();
+        let violation1 = MD009::new()
+            .to_violation(path.clone(), Sourcepos::from((1, 5, 1, 5)))
+            .with_edits(vec![Edit::deletion(4..5)]);
+        let violation2 = MD010::new()
+            .to_violation(path.clone(), Sourcepos::from((2, 5, 2, 5)))
+            .with_edits(vec![Edit::new(10..11, "    ".to_owned())]);
+        let violation3 = MD047::new()
+            .to_violation(path, Sourcepos::from((2, 1, 2, 10)))
+            .with_edits(vec![Edit::insertion(15, "\n".to_owned())]);
+        let actual = Fixer::apply(text, &[&violation3, &violation1, &violation2]);
+        let expected = ("Text\nText    body\n".to_owned(), 3);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn apply_overlapping() {
+        let text = "Text\t  ";
+        let path = Path::new("test.md").to_path_buf();
+        let violation1 = MD009::new()
+            .to_violation(path.clone(), Sourcepos::from((1, 6, 1, 7)))
+            .with_edits(vec![Edit::deletion(5..7)]);
+        let violation2 = MD010::new()
+            .to_violation(path.clone(), Sourcepos::from((1, 5, 1, 5)))
+            .with_edits(vec![Edit::new(4..7, "    ".to_owned())]);
+        let violation3 = MD047::new().to_violation(path, Sourcepos::from((1, 1, 1, 8)));
+        let actual = Fixer::apply(text, &[&violation1, &violation2, &violation3]);
+        let expected = ("Text    ".to_owned(), 1);
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,43 +1,345 @@
-use miette::Result;
+use core::hash::{Hash as _, Hasher as _};
+use core::mem;
+use std::collections::hash_map::Entry;
+use std::env;
+use std::path::{Component, Path, PathBuf};
 
-use crate::config::Config;
+use globset::GlobSet;
+use miette::{IntoDiagnostic as _, Result};
+use rustc_hash::{FxHashMap, FxHasher};
+
+use crate::config::{Config, Lint};
+use crate::directive::{Directive, Directives};
 use crate::violation::Violation;
 use crate::Document;
 use crate::Rule;
+use crate::Severity;
 
-#[derive(Default)]
+#[derive(Debug, Clone, Default)]
 pub struct Linter {
     rules: Vec<Rule>,
+    /// Severities of `rules` in the same order
+    severities: Vec<Severity>,
+    report_unused_directives: bool,
+    unused_directive_severity: Severity,
+    /// Whether to compute edits of violations
+    fix: bool,
+    /// Hash of the rules and options to invalidate caches
+    fingerprint: u64,
 }
 
 impl Linter {
     #[inline]
     #[must_use]
-    pub const fn new(rules: Vec<Rule>) -> Self {
-        Self { rules }
+    pub fn new(rules: Vec<Rule>) -> Self {
+        let severities = vec![Severity::Error; rules.len()];
+        Self {
+            rules,
+            severities,
+            report_unused_directives: false,
+            unused_directive_severity: Severity::Error,
+            fix: false,
+            fingerprint: 0,
+        }
+        .with_fingerprint()
+    }
+
+    /// Computes edits of violations, which are skipped by default as they are costly
+    #[inline]
+    #[must_use]
+    pub fn with_fix(self, fix: bool) -> Self {
+        Self { fix, ..self }.with_fingerprint()
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    fn with_fingerprint(self) -> Self {
+        let mut hasher = FxHasher::default();
+        format!(
+            "{:?}{:?}{}{:?}{}",
+            self.rules,
+            self.severities,
+            self.report_unused_directives,
+            self.unused_directive_severity,
+            self.fix
+        )
+        .hash(&mut hasher);
+        Self {
+            fingerprint: hasher.finish(),
+            ..self
+        }
     }
 
     #[inline]
     pub fn check(&self, doc: &Document) -> Result<Vec<Violation>> {
-        // Iterate rules while unrolling Vec<Result<Vec<..>>> to Result<Vec<..>>
-        self.rules.iter().try_fold(vec![], |mut unrolled, rule| {
-            let result = rule.check(doc);
-            unrolled.extend(result?);
-            Ok(unrolled)
-        })
+        let mut violations = vec![];
+        for (rule, &severity) in self.rules.iter().zip(&self.severities) {
+            for violation in rule.check(doc)? {
+                let violation = if self.fix {
+                    let edits = rule.fix(doc, &violation);
+                    violation.with_edits(edits)
+                } else {
+                    violation
+                };
+                violations.push(violation.with_severity(severity));
+            }
+        }
+
+        let directives = Directives::from_document(doc);
+        if !directives.is_empty() {
+            let unused = directives.apply(&mut violations, doc);
+            if self.report_unused_directives {
+                let severity = self.unused_directive_severity;
+                violations.extend(
+                    unused
+                        .into_iter()
+                        .map(|violation| violation.with_severity(severity)),
+                );
+            }
+        }
+
+        Ok(violations)
     }
     #[inline]
     #[must_use]
     pub fn from_config(config: &Config) -> Self {
-        let rules = config.lint.to_rules();
-        Self { rules }
+        let (rules, severities) = config
+            .lint
+            .to_rules()
+            .into_iter()
+            .map(|rule| {
+                let severity = config.lint.severity_of(rule.metadata());
+                (rule, severity)
+            })
+            .filter(|(_, severity)| *severity != Severity::Off)
+            .unzip();
+        let unused_directive_severity = config.lint.severity_of(&Directive::UNUSED_METADATA);
+        Self {
+            rules,
+            severities,
+            report_unused_directives: config.lint.report_unused_directives
+                && unused_directive_severity != Severity::Off,
+            unused_directive_severity,
+            fix: config.lint.needs_edits(),
+            fingerprint: 0,
+        }
+        .with_fingerprint()
     }
 }
 
 // Note: prefer `Linter::from_config(&Config)` over From<&Config> impl.
 
+/// Linters for files under a config file with `lint.overrides` applied.
+/// Linters are cached by the set of matching overrides.
+#[derive(Debug, Clone)]
+struct ScopedLinters {
+    lint: Lint,
+    /// Whether to compute edits, decided by the root config regardless of config files
+    fix: bool,
+    /// Directory that override patterns are relative to
+    base_dir: PathBuf,
+    override_sets: Vec<GlobSet>,
+    linters: FxHashMap<Vec<usize>, Linter>,
+}
+
+impl ScopedLinters {
+    fn new(config: &Config, base_dir: PathBuf, fix: bool) -> Result<Self> {
+        let lint = config.lint.clone();
+        let override_sets = lint
+            .overrides
+            .iter()
+            .enumerate()
+            .map(|(i, overrides)| {
+                // Report invalid overrides before linting any files
+                lint.with_overrides(&[i])?;
+                overrides.file_set()
+            })
+            .collect::<Result<_>>()?;
+        let mut linters = FxHashMap::default();
+        linters.insert(vec![], Linter::from_config(config).with_fix(fix));
+
+        Ok(Self {
+            lint,
+            fix,
+            base_dir,
+            override_sets,
+            linters,
+        })
+    }
+
+    fn resolve(&mut self, path: &Path) -> Result<&Linter> {
+        let path = path.strip_prefix(&self.base_dir).unwrap_or(path);
+        let indices: Vec<_> = self
+            .override_sets
+            .iter()
+            .enumerate()
+            .filter(|(_, set)| set.is_match(path))
+            .map(|(i, _)| i)
+            .collect();
+
+        match self.linters.entry(indices) {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => {
+                let config = Config {
+                    lint: self.lint.with_overrides(entry.key())?,
+                };
+                Ok(entry.insert(Linter::from_config(&config).with_fix(self.fix)))
+            }
+        }
+    }
+}
+
+/// Resolves linters for files.
+/// Without a config file given explicitly, the nearest config file of each file is used,
+/// and the given config is used for files without any config files.
+#[derive(Debug, Clone)]
+pub struct LinterResolver {
+    root: ScopedLinters,
+    current_dir: PathBuf,
+    discover: bool,
+    config_paths: FxHashMap<PathBuf, Option<PathBuf>>,
+    scoped: FxHashMap<PathBuf, ScopedLinters>,
+    /// Warnings of discovered config files not taken yet
+    warnings: Vec<String>,
+}
+
+impl LinterResolver {
+    /// Creates a resolver of the config loaded from `config_path`, or None to discover
+    /// config files. Override patterns of the config are relative to its directory.
+    #[inline]
+    pub fn new(config: &Config, config_path: Option<&Path>) -> Result<Self> {
+        let current_dir = env::current_dir().into_diagnostic()?;
+        let base_dir = normalize(
+            &current_dir.join(
+                config_path
+                    .and_then(Path::parent)
+                    .unwrap_or_else(|| Path::new("")),
+            ),
+        );
+        let root = ScopedLinters::new(config, base_dir, config.lint.needs_edits())?;
+
+        Ok(Self {
+            root,
+            current_dir,
+            discover: config_path.is_none(),
+            config_paths: FxHashMap::default(),
+            scoped: FxHashMap::default(),
+            warnings: vec![],
+        })
+    }
+
+    #[inline]
+    pub fn resolve(&mut self, path: &Path) -> Result<&Linter> {
+        let absolute_path = normalize(&self.current_dir.join(path));
+        let Some(config_path) = self.config_path(path)? else {
+            return self.root.resolve(&absolute_path);
+        };
+
+        let scoped = match self.scoped.entry(config_path) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let (config, warnings) = Config::load(entry.key())?;
+                self.warnings.extend(warnings);
+                let base_dir = entry
+                    .key()
+                    .parent()
+                    .unwrap_or_else(|| Path::new(""))
+                    .to_path_buf();
+                entry.insert(ScopedLinters::new(&config, base_dir, self.root.fix)?)
+            }
+        };
+        scoped.resolve(&absolute_path)
+    }
+
+    /// Returns warnings of config files discovered since the last call
+    #[inline]
+    pub fn take_warnings(&mut self) -> Vec<String> {
+        mem::take(&mut self.warnings)
+    }
+
+    /// Returns the config file discovered for the file, or None if the given config is used
+    #[inline]
+    pub fn config_path(&mut self, path: &Path) -> Result<Option<PathBuf>> {
+        if !self.discover {
+            return Ok(None);
+        }
+
+        normalize(&self.current_dir.join(path))
+            .parent()
+            .map_or_else(|| Ok(None), |dir| self.find_config(dir))
+    }
+
+    // Returns the nearest config file from the directory
+    fn find_config(&mut self, dir: &Path) -> Result<Option<PathBuf>> {
+        if let Some(config_path) = self.config_paths.get(dir) {
+            return Ok(config_path.clone());
+        }
+
+        let config_path = match Config::find(dir)? {
+            Some(config_path) => Some(config_path),
+            None => match dir.parent() {
+                Some(parent) => self.find_config(parent)?,
+                None => None,
+            },
+        };
+        self.config_paths
+            .insert(dir.to_path_buf(), config_path.clone());
+        Ok(config_path)
+    }
+}
+
+impl From<Linter> for LinterResolver {
+    #[inline]
+    fn from(linter: Linter) -> Self {
+        let fix = linter.fix;
+        let mut linters = FxHashMap::default();
+        linters.insert(vec![], linter);
+        let root = ScopedLinters {
+            lint: Lint::default(),
+            fix,
+            base_dir: PathBuf::from("."),
+            override_sets: vec![],
+            linters,
+        };
+
+        Self {
+            root,
+            current_dir: PathBuf::new(),
+            discover: false,
+            config_paths: FxHashMap::default(),
+            scoped: FxHashMap::default(),
+            warnings: vec![],
+        }
+    }
+}
+
+// Removes `.` components and resolves `..` components lexically
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) =>
+            {
+                normalized.pop();
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
 #[cfg(test)]
 mod tests {
+    use std::fs;
     use std::path::Path;
 
     use comrak::{nodes::Sourcepos, Arena};
@@ -45,8 +347,10 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use crate::config::lint::RuleSet;
+    use crate::directive::Directive;
     use crate::rule::RuleLike as _;
-    use crate::rule::MD026;
+    use crate::rule::{MD009, MD013, MD026};
+    use crate::Edit;
 
     use super::*;
 
@@ -68,7 +372,41 @@ mod tests {
         let rules = vec![Rule::MD026(md026.clone())];
         let linter = Linter::new(rules);
         let actual = linter.check(&doc)?;
-        let expected = vec![md026.to_violation(path, Sourcepos::from((6, 1, 6, 19)))];
+        let expected = vec![md026.to_violation(path.clone(), Sourcepos::from((6, 1, 6, 19)))];
+        assert_eq!(actual, expected);
+
+        let fix_linter = linter.with_fix(true);
+        let fix_actual = fix_linter.check(&doc)?;
+        let fix_expected = vec![md026
+            .to_violation(path, Sourcepos::from((6, 1, 6, 19)))
+            .with_edits(vec![Edit::deletion(66..67)])];
+        assert_eq!(fix_actual, fix_expected);
+        Ok(())
+    }
+
+    #[test]
+    fn check_with_directives() -> Result<()> {
+        let text = indoc! {"
+            <!-- mado-disable-next-line MD026 -->
+            # This is a header.
+
+            <!-- mado-disable-line MD026 -->
+        "}
+        .to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let rules = vec![Rule::MD026(MD026::default())];
+        let mut linter = Linter::new(rules);
+        assert_eq!(linter.check(&doc)?, vec![]);
+
+        linter.report_unused_directives = true;
+        let actual = linter.check(&doc)?;
+        let expected = vec![Violation::new(
+            path,
+            &Directive::UNUSED_METADATA,
+            Sourcepos::from((4, 1, 4, 32)),
+        )];
         assert_eq!(actual, expected);
         Ok(())
     }
@@ -83,4 +421,140 @@ mod tests {
         let expected = vec![Rule::MD026(md026)];
         assert_eq!(linter.rules, expected);
     }
+
+    #[test]
+    fn from_config_with_severity() {
+        let mut config = Config::default();
+        config.lint.rules = vec![RuleSet::MD013, RuleSet::MD026];
+        config.lint.severity = [
+            ("MD013".to_owned(), Severity::Warning),
+            ("MD026".to_owned(), Severity::Off),
+        ]
+        .into();
+        let linter = Linter::from_config(&config);
+        assert_eq!(linter.rules, vec![Rule::MD013(MD013::default())]);
+        assert_eq!(linter.severities, vec![Severity::Warning]);
+    }
+
+    #[test]
+    fn check_with_severity() -> Result<()> {
+        let text = "# This is a header.\n".to_owned();
+        let path = Path::new("test.md").to_path_buf();
+        let arena = Arena::new();
+        let doc = Document::new(&arena, path.clone(), text)?;
+        let mut config = Config::default();
+        config.lint.rules = vec![RuleSet::MD026];
+        config.lint.severity = [("no-trailing-punctuation".to_owned(), Severity::Info)].into();
+        let linter = Linter::from_config(&config);
+        let actual = linter.check(&doc)?;
+        let expected = vec![MD026::default()
+            .to_violation(path, Sourcepos::from((1, 1, 1, 19)))
+            .with_severity(Severity::Info)];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn linter_resolver_resolve() -> Result<()> {
+        let text = indoc! {r#"
+            [lint]
+            rules = ["MD026"]
+
+            [[lint.overrides]]
+            files = ["docs/**/*.md"]
+            rules = ["MD009"]
+
+            [[lint.overrides]]
+            files = ["**/blog/*.md"]
+            enable = ["MD013"]
+        "#};
+        let config: Config = toml::from_str(text).map_err(|err| miette::miette!(err))?;
+        let mut resolver = LinterResolver::new(&config, Some(Path::new("mado.toml")))?;
+
+        let readme_linter = resolver.resolve(Path::new("README.md"))?;
+        let readme_expected = vec![Rule::MD026(MD026::default())];
+        assert_eq!(readme_linter.rules, readme_expected);
+
+        let docs_linter = resolver.resolve(Path::new("./docs/index.md"))?;
+        let docs_expected = vec![Rule::MD009(MD009::new())];
+        assert_eq!(docs_linter.rules, docs_expected);
+
+        let blog_linter = resolver.resolve(Path::new("docs/blog/post.md"))?;
+        let blog_expected = vec![Rule::MD009(MD009::new()), Rule::MD013(MD013::default())];
+        assert_eq!(blog_linter.rules, blog_expected);
+        assert_eq!(resolver.root.linters.len(), 3);
+
+        // Patterns are relative to the directory of the config file
+        let mut sub_resolver = LinterResolver::new(&config, Some(Path::new("./sub/mado.toml")))?;
+        let sub_docs_linter = sub_resolver.resolve(Path::new("sub/docs/index.md"))?;
+        assert_eq!(sub_docs_linter.rules, docs_expected);
+        let sub_readme_linter = sub_resolver.resolve(Path::new("sub/../docs/index.md"))?;
+        assert_eq!(sub_readme_linter.rules, readme_expected);
+        Ok(())
+    }
+
+    #[test]
+    fn linter_resolver_new_with_invalid_override() -> Result<()> {
+        let text = indoc! {r#"
+            [[lint.overrides]]
+            files = ["*.md"]
+            output-format = "mdl"
+        "#};
+        let config: Config = toml::from_str(text).map_err(|err| miette::miette!(err))?;
+        assert!(LinterResolver::new(&config, Some(Path::new("mado.toml"))).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn linter_resolver_resolve_with_discovery() -> Result<()> {
+        let tmp_dir = tempfile::tempdir().into_diagnostic()?;
+        let root_text = indoc! {r#"
+            [lint]
+            rules = ["MD026"]
+        "#};
+        fs::write(tmp_dir.path().join("mado.toml"), root_text).into_diagnostic()?;
+        let package_dir = tmp_dir.path().join("package");
+        fs::create_dir_all(package_dir.join("docs")).into_diagnostic()?;
+        let package_text = indoc! {r#"
+            [lint]
+            rules = ["MD009"]
+
+            [[lint.overrides]]
+            files = ["docs/*.md"]
+            enable = ["MD013"]
+        "#};
+        fs::write(package_dir.join("mado.toml"), package_text).into_diagnostic()?;
+
+        let mut resolver = LinterResolver::new(&Config::default(), None)?;
+
+        let root_linter = resolver.resolve(&tmp_dir.path().join("README.md"))?;
+        let root_expected = vec![Rule::MD026(MD026::default())];
+        assert_eq!(root_linter.rules, root_expected);
+
+        let package_linter = resolver.resolve(&package_dir.join("README.md"))?;
+        let package_expected = vec![Rule::MD009(MD009::new())];
+        assert_eq!(package_linter.rules, package_expected);
+
+        let docs_linter = resolver.resolve(&package_dir.join("docs").join("index.md"))?;
+        let docs_expected = vec![Rule::MD009(MD009::new()), Rule::MD013(MD013::default())];
+        assert_eq!(docs_linter.rules, docs_expected);
+        assert_eq!(resolver.scoped.len(), 2);
+
+        let docs_config_path = resolver.config_path(&package_dir.join("docs").join("index.md"))?;
+        assert_eq!(docs_config_path, Some(package_dir.join("mado.toml")));
+        tmp_dir.close().into_diagnostic()
+    }
+
+    #[test]
+    fn normalize_path() {
+        assert_eq!(
+            normalize(Path::new("/repo/./docs/a.md")),
+            Path::new("/repo/docs/a.md")
+        );
+        assert_eq!(
+            normalize(Path::new("/repo/sub/../a.md")),
+            Path::new("/repo/a.md")
+        );
+        assert_eq!(normalize(Path::new("../a.md")), Path::new("../a.md"));
+    }
 }
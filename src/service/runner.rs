@@ -2,19 +2,93 @@ extern crate alloc;
 
 use alloc::sync::Arc;
 use comrak::Arena;
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 use std::sync::{mpsc, Mutex};
 use std::thread;
 
 use ignore::WalkParallel;
 use miette::miette;
 use miette::{IntoDiagnostic as _, Result};
+use rustc_hash::{FxHashMap, FxHashSet};
 
+use super::filter::FileFilter;
 use super::visitor::MarkdownLintVisitorFactory;
 use super::walker::WalkParallelBuilder;
-use super::Linter;
+use super::{Linter, LinterResolver};
 use crate::config::Config;
-use crate::{Document, Violation};
+use crate::{Document, Source, Violation};
+
+/// Options of a lint run given on the command line rather than in config files
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[allow(clippy::exhaustive_structs)]
+pub struct RunOptions {
+    /// Config file given explicitly, or None to discover the nearest config file for each file
+    pub config_path: Option<PathBuf>,
+    /// Directory to cache violations, or None to disable caching
+    pub cache_dir: Option<PathBuf>,
+    /// Git ref to lint only files and lines changed since
+    pub diff_base: Option<String>,
+    /// Baseline file of violations to ignore
+    pub baseline: Option<PathBuf>,
+    /// Baseline file to write violations to instead of reporting them
+    pub write_baseline: Option<PathBuf>,
+    /// Virtual path of stdin input for outputs and per-path configuration
+    pub stdin_filename: Option<PathBuf>,
+    /// Whether to keep sources of files with violations to render snippets
+    pub keep_sources: bool,
+}
+
+/// Violations found by a lint run
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Report {
+    pub violations: Vec<Violation>,
+    /// Sources of files with violations by paths if `RunOptions::keep_sources` is set
+    pub sources: FxHashMap<PathBuf, Arc<Source>>,
+    /// Paths of all linted files
+    pub paths: FxHashSet<PathBuf>,
+    /// Warnings of config files discovered while linting
+    pub warnings: Vec<String>,
+}
+
+impl Report {
+    /// Creates a report of a linted file
+    #[inline]
+    #[must_use]
+    pub fn new(path: PathBuf, violations: Vec<Violation>, source: Option<Source>) -> Self {
+        let sources = source
+            .map(|src| (src.path().to_path_buf(), Arc::new(src)))
+            .into_iter()
+            .collect();
+        Self {
+            violations,
+            sources,
+            paths: FxHashSet::from_iter([path]),
+            warnings: vec![],
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_warnings(mut self, warnings: Vec<String>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+
+    /// Merges the other report. The same config file may be loaded in each thread,
+    /// so duplicate warnings are ignored.
+    #[inline]
+    pub fn extend(&mut self, other: Self) {
+        self.violations.extend(other.violations);
+        self.sources.extend(other.sources);
+        self.paths.extend(other.paths);
+        for warning in other.warnings {
+            if !self.warnings.contains(&warning) {
+                self.warnings.push(warning);
+            }
+        }
+    }
+}
 
 #[non_exhaustive]
 pub enum LintRunner {
@@ -24,7 +98,7 @@ pub enum LintRunner {
 
 impl LintRunner {
     #[inline]
-    pub fn run(self) -> Result<Vec<Violation>> {
+    pub fn run(self) -> Result<Report> {
         match self {
             Self::Parallel(runner) => (*runner).run(),
             Self::String(runner) => runner.run(),
@@ -35,21 +109,29 @@ impl LintRunner {
 pub struct ParallelLintRunner {
     walker: WalkParallel,
     config: Config,
+    options: RunOptions,
     capacity: usize,
 }
 
 impl ParallelLintRunner {
     #[inline]
-    pub fn new(patterns: &[PathBuf], config: Config, capacity: usize) -> Result<Self> {
+    pub fn new(
+        patterns: &[PathBuf],
+        config: Config,
+        options: RunOptions,
+        capacity: usize,
+    ) -> Result<Self> {
         let walker = WalkParallelBuilder::build(
             patterns,
             config.lint.respect_ignore,
             config.lint.respect_gitignore,
+            FileFilter::new(&config.lint)?.types()?,
         )?;
 
         Ok(Self {
             walker,
             config,
+            options,
             capacity,
         })
     }
@@ -58,21 +140,19 @@ impl ParallelLintRunner {
     // TODO: Don't use expect
     #[expect(clippy::expect_used)]
     #[expect(clippy::unwrap_in_result)]
-    pub fn run(self) -> Result<Vec<Violation>> {
-        let mutex_violations: Arc<Mutex<Vec<Violation>>> = Arc::new(Mutex::new(vec![]));
-        let (tx, rx) = mpsc::sync_channel::<Vec<Violation>>(self.capacity);
+    pub fn run(self) -> Result<Report> {
+        let mutex_report: Arc<Mutex<Report>> = Arc::new(Mutex::new(Report::default()));
+        let (tx, rx) = mpsc::sync_channel::<Report>(self.capacity);
 
-        let local_mutex_violations = Arc::clone(&mutex_violations);
+        let local_mutex_report = Arc::clone(&mutex_report);
         let thread = thread::spawn(move || {
-            for violations in rx {
-                let mut acquired_violations = local_mutex_violations
-                    .lock()
-                    .expect("lock must be acquired");
-                acquired_violations.extend(violations);
+            for report in rx {
+                let mut acquired_report = local_mutex_report.lock().expect("lock must be acquired");
+                acquired_report.extend(report);
             }
         });
 
-        let mut builder = MarkdownLintVisitorFactory::new(self.config, tx)?;
+        let mut builder = MarkdownLintVisitorFactory::new(&self.config, &self.options, tx)?;
         self.walker.visit(&mut builder);
 
         // Wait for the completion
@@ -81,33 +161,60 @@ impl ParallelLintRunner {
             .join()
             .map_err(|err| miette!("Failed to join thread. {:?}", err))?;
 
-        // Take ownership of violations
-        let lock =
-            Arc::into_inner(mutex_violations).ok_or_else(|| miette!("Failed to unwrap Arc"))?;
+        // Take ownership of the report
+        let lock = Arc::into_inner(mutex_report).ok_or_else(|| miette!("Failed to unwrap Arc"))?;
         lock.into_inner().into_diagnostic()
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StringLintRunner {
     string: String,
     config: Config,
+    options: RunOptions,
 }
 
 impl StringLintRunner {
+    const DEFAULT_PATH: &str = "(stdin)";
+
     #[inline]
     #[must_use]
-    pub const fn new(string: String, config: Config) -> Self {
-        Self { string, config }
+    pub const fn new(string: String, config: Config, options: RunOptions) -> Self {
+        Self {
+            string,
+            config,
+            options,
+        }
     }
 
     #[inline]
-    pub fn run(self) -> Result<Vec<Violation>> {
+    pub fn run(self) -> Result<Report> {
+        // `--stdin-filename` is used for outputs and per-path configuration
+        let Some(path) = self.options.stdin_filename else {
+            let arena = Arena::new();
+            let doc = Document::new(&arena, PathBuf::from(Self::DEFAULT_PATH), self.string)?;
+            let linter = Linter::from_config(&self.config);
+            let violations = linter.check(&doc)?;
+            return Ok(Self::report(violations, doc, self.options.keep_sources));
+        };
+
+        if FileFilter::new(&self.config.lint)?.is_excluded(&path) {
+            return Ok(Report::default());
+        }
+
+        let mut resolver = LinterResolver::new(&self.config, self.options.config_path.as_deref())?;
+        let linter = resolver.resolve(&path)?;
         let arena = Arena::new();
-        let path = Path::new("(stdin)").to_path_buf();
         let doc = Document::new(&arena, path, self.string)?;
-        let linter = Linter::from_config(&self.config);
-        linter.check(&doc)
+        let violations = linter.check(&doc)?;
+        let report = Self::report(violations, doc, self.options.keep_sources);
+        Ok(report.with_warnings(resolver.take_warnings()))
+    }
+
+    fn report(violations: Vec<Violation>, doc: Document<'_>, keep_sources: bool) -> Report {
+        let path = doc.path.clone();
+        let source = (keep_sources && !violations.is_empty()).then(|| Source::from(doc));
+        Report::new(path, violations, source)
     }
 }
 
@@ -119,15 +226,64 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn string_lint_runner_run() -> Result<()> {
+        let runner = StringLintRunner::new(
+            "# Hello.\n".to_owned(),
+            Config::default(),
+            RunOptions::default(),
+        );
+        let actual: Vec<_> = runner
+            .run()?
+            .violations
+            .iter()
+            .map(|violation| (violation.path().clone(), violation.name().to_owned()))
+            .collect();
+        assert_eq!(actual, vec![(PathBuf::from("(stdin)"), "MD026".to_owned())]);
+        Ok(())
+    }
+
+    #[test]
+    fn string_lint_runner_run_with_path() -> Result<()> {
+        let mut config = Config::default();
+        config.lint.exclude = vec![globset::Glob::new("docs/*.md").into_diagnostic()?];
+
+        let options = RunOptions {
+            stdin_filename: Some(PathBuf::from("README.md")),
+            ..RunOptions::default()
+        };
+        let runner = StringLintRunner::new("# Hello.\n".to_owned(), config.clone(), options);
+        let actual: Vec<_> = runner
+            .run()?
+            .violations
+            .iter()
+            .map(|violation| (violation.path().clone(), violation.name().to_owned()))
+            .collect();
+        assert_eq!(
+            actual,
+            vec![(PathBuf::from("README.md"), "MD026".to_owned())]
+        );
+
+        let excluded_options = RunOptions {
+            stdin_filename: Some(PathBuf::from("docs/index.md")),
+            ..RunOptions::default()
+        };
+        let excluded_runner =
+            StringLintRunner::new("# Hello.\n".to_owned(), config, excluded_options);
+        assert_eq!(excluded_runner.run()?, Report::default());
+        Ok(())
+    }
+
     #[test]
     fn parallel_lint_runner_run() -> Result<()> {
         let mut config = Config::default();
         config.lint.rules = vec![];
 
         let patterns = [Path::new(".").to_path_buf()];
-        let runner = ParallelLintRunner::new(&patterns, config, 0)?;
+        let runner = ParallelLintRunner::new(&patterns, config, RunOptions::default(), 0)?;
         let actual = runner.run()?;
-        assert_eq!(actual, vec![]);
+        assert_eq!(actual.violations, vec![]);
+        assert!(!actual.paths.is_empty());
         Ok(())
     }
 }
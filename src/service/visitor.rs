@@ -1,44 +1,71 @@
 use core::result::Result;
+use std::fs;
 use std::sync::mpsc::SyncSender;
 
 use comrak::Arena;
-use globset::GlobSet;
 use ignore::{DirEntry, Error, ParallelVisitor, ParallelVisitorBuilder, WalkState};
 use miette::IntoDiagnostic as _;
 
-use super::Linter;
-use crate::{config::Config, Document, Violation};
+use super::cache::Cache;
+use super::filter::FileFilter;
+use super::runner::{Report, RunOptions};
+use super::LinterResolver;
+use crate::{config::Config, Document, Source};
 
 pub struct MarkdownLintVisitor {
-    linter: Linter,
-    exclusion: GlobSet,
-    tx: SyncSender<Vec<Violation>>,
+    resolver: LinterResolver,
+    filter: FileFilter,
+    cache: Option<Cache>,
+    keep_sources: bool,
+    tx: SyncSender<Report>,
 }
 
 impl MarkdownLintVisitor {
     #[inline]
     #[must_use]
-    pub const fn new(linter: Linter, exclusion: GlobSet, tx: SyncSender<Vec<Violation>>) -> Self {
+    pub const fn new(
+        resolver: LinterResolver,
+        filter: FileFilter,
+        cache: Option<Cache>,
+        keep_sources: bool,
+        tx: SyncSender<Report>,
+    ) -> Self {
         Self {
-            linter,
-            exclusion,
+            resolver,
+            filter,
+            cache,
+            keep_sources,
             tx,
         }
     }
 
-    fn visit_inner(&self, either_entry: Result<DirEntry, Error>) -> miette::Result<()> {
+    fn visit_inner(&mut self, either_entry: Result<DirEntry, Error>) -> miette::Result<()> {
         let entry = either_entry.into_diagnostic()?;
         let path = entry.path();
-        if path.is_file()
-            && path.extension() == Some("md".as_ref())
-            && !self.exclusion.is_match(path)
-        {
-            let arena = Arena::new();
-            let doc = Document::open(&arena, path)?;
-            let violations = self.linter.check(&doc)?;
-            if !violations.is_empty() {
-                self.tx.send(violations).into_diagnostic()?;
-            }
+        if self.filter.is_match_entry(&entry) {
+            let text = fs::read_to_string(path).into_diagnostic()?;
+            let linter = self.resolver.resolve(path)?;
+            let key = Cache::key(&text, linter);
+            let cached = self.cache.as_ref().and_then(|cache| cache.get(path, key));
+            let keep_sources = self.keep_sources;
+            let (violations, source) = if let Some(violations) = cached {
+                let source = (keep_sources && !violations.is_empty())
+                    .then(|| Source::new(path.to_path_buf(), text));
+                (violations, source)
+            } else {
+                let arena = Arena::new();
+                let doc = Document::new(&arena, path.to_path_buf(), text)?;
+                let violations = linter.check(&doc)?;
+                if let Some(cache) = &self.cache {
+                    cache.set(path, key, &violations);
+                }
+                let source = (keep_sources && !violations.is_empty()).then(|| Source::from(doc));
+                (violations, source)
+            };
+            // Sent without violations to record linted paths
+            let report = Report::new(path.to_path_buf(), violations, source)
+                .with_warnings(self.resolver.take_warnings());
+            self.tx.send(report).into_diagnostic()?;
         }
 
         Ok(())
@@ -57,18 +84,38 @@ impl ParallelVisitor for MarkdownLintVisitor {
 }
 
 pub struct MarkdownLintVisitorFactory {
-    config: Config,
-    exclusion: GlobSet,
-    tx: SyncSender<Vec<Violation>>,
+    resolver: LinterResolver,
+    filter: FileFilter,
+    cache: Option<Cache>,
+    keep_sources: bool,
+    tx: SyncSender<Report>,
 }
 
 impl MarkdownLintVisitorFactory {
     #[inline]
-    pub fn new(config: Config, tx: SyncSender<Vec<Violation>>) -> miette::Result<Self> {
-        let exclusion = config.lint.exclude_set()?;
+    pub fn new(
+        config: &Config,
+        options: &RunOptions,
+        tx: SyncSender<Report>,
+    ) -> miette::Result<Self> {
+        let filter = FileFilter::new(&config.lint)?;
+        let resolver = LinterResolver::new(config, options.config_path.as_deref())?;
+        // Lint without the cache if it is not available such as in read-only environments
+        let cache = options
+            .cache_dir
+            .as_deref()
+            .and_then(|dir| match Cache::new(dir) {
+                Ok(cache) => Some(cache),
+                Err(err) => {
+                    eprintln!("Failed to create the cache in {}: {err}", dir.display());
+                    None
+                }
+            });
         Ok(Self {
-            config,
-            exclusion,
+            resolver,
+            filter,
+            cache,
+            keep_sources: options.keep_sources,
             tx,
         })
     }
@@ -77,10 +124,11 @@ impl MarkdownLintVisitorFactory {
 impl<'s> ParallelVisitorBuilder<'s> for MarkdownLintVisitorFactory {
     #[inline]
     fn build(&mut self) -> Box<dyn ParallelVisitor + 's> {
-        let linter = Linter::from_config(&self.config);
         Box::new(MarkdownLintVisitor::new(
-            linter,
-            self.exclusion.clone(),
+            self.resolver.clone(),
+            self.filter.clone(),
+            self.cache.clone(),
+            self.keep_sources,
             self.tx.clone(),
         ))
     }
@@ -91,41 +139,63 @@ mod tests {
     use std::sync::mpsc;
 
     use ignore::Walk;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    use crate::config;
+    use crate::service::Linter;
+    use crate::Violation;
 
     use super::*;
 
     #[test]
     fn markdown_lint_visitor_visit_inner() -> miette::Result<()> {
-        let (tx, rx) = mpsc::sync_channel::<Vec<Violation>>(0);
-        let linter = Linter::new(vec![]);
-        let exclusion = GlobSet::empty();
-        let visitor = MarkdownLintVisitor::new(linter, exclusion, tx);
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let path = tmp_dir.path().join("README.md");
+        fs::write(&path, "# Hello.\n").into_diagnostic()?;
+
+        let (tx, rx) = mpsc::sync_channel::<Report>(1);
+        let resolver = LinterResolver::from(Linter::new(vec![]));
+        let filter = FileFilter::new(&config::Lint::default())?;
+        let mut visitor = MarkdownLintVisitor::new(resolver, filter, None, true, tx);
 
-        for entry in Walk::new(".") {
+        for entry in Walk::new(tmp_dir.path()) {
             visitor.visit_inner(entry)?;
         }
 
         drop(visitor);
-        assert!(rx.recv().is_err()); // Because rx has not received any messages
-        Ok(())
+        // Linted files are reported even without violations
+        let report = rx.recv().into_diagnostic()?;
+        assert_eq!(report, Report::new(path, vec![], None));
+        assert!(rx.recv().is_err());
+        tmp_dir.close().into_diagnostic()
     }
 
     #[test]
     fn markdown_lint_visitor_factory_build() -> miette::Result<()> {
-        let mut config = Config::default();
-        config.lint.rules = vec![];
-
-        let (tx, rx) = mpsc::sync_channel::<Vec<Violation>>(0);
-        let mut factory = MarkdownLintVisitorFactory::new(config, tx)?;
+        let tmp_dir = tempdir().into_diagnostic()?;
+        let path = tmp_dir.path().join("README.md");
+        fs::write(&path, "# Hello.\n").into_diagnostic()?;
+
+        let (tx, rx) = mpsc::sync_channel::<Report>(1);
+        let options = RunOptions {
+            keep_sources: true,
+            ..RunOptions::default()
+        };
+        let mut factory = MarkdownLintVisitorFactory::new(&Config::default(), &options, tx)?;
         let mut visitor = factory.build();
 
-        for entry in Walk::new(".") {
+        for entry in Walk::new(tmp_dir.path()) {
             visitor.visit(entry);
         }
 
         drop(visitor);
         drop(factory);
-        assert!(rx.recv().is_err()); // Because rx has not received any messages
-        Ok(())
+        let report = rx.recv().into_diagnostic()?;
+        let names: Vec<_> = report.violations.iter().map(Violation::name).collect();
+        assert_eq!(names, vec!["MD026"]);
+        assert_eq!(report.sources[&path].text(), "# Hello.\n");
+        assert!(rx.recv().is_err());
+        tmp_dir.close().into_diagnostic()
     }
 }
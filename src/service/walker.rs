@@ -1,10 +1,9 @@
 use std::path::PathBuf;
 
-use ignore::types::TypesBuilder;
+use ignore::types::Types;
 use ignore::WalkBuilder;
-use ignore::WalkParallel;
+use ignore::{Walk, WalkParallel};
 use miette::miette;
-use miette::IntoDiagnostic as _;
 use miette::Result;
 
 #[non_exhaustive]
@@ -16,28 +15,52 @@ impl WalkParallelBuilder {
         patterns: &[PathBuf],
         respect_ignore: bool,
         respect_gitignore: bool,
+        types: Option<Types>,
     ) -> Result<WalkParallel> {
-        let (head_pattern, tail_patterns) = patterns
-            .split_first()
-            .ok_or_else(|| miette!("files must be non-empty"))?;
-        let mut builder = WalkBuilder::new(head_pattern);
-        for pattern in tail_patterns {
-            builder.add(pattern);
-        }
+        let builder = walk_builder(patterns, respect_ignore, respect_gitignore, types)?;
+        Ok(builder.build_parallel())
+    }
+}
 
-        builder.ignore(respect_ignore);
-        builder.git_ignore(respect_gitignore);
+#[non_exhaustive]
+pub struct WalkSequentialBuilder;
 
-        // NOTE: Expect performance improvements with pre-filtering
-        let types = TypesBuilder::new()
-            .add_defaults()
-            .select("markdown")
-            .build()
-            .into_diagnostic()?;
-        builder.types(types);
+impl WalkSequentialBuilder {
+    #[inline]
+    pub fn build(
+        patterns: &[PathBuf],
+        respect_ignore: bool,
+        respect_gitignore: bool,
+        types: Option<Types>,
+    ) -> Result<Walk> {
+        let builder = walk_builder(patterns, respect_ignore, respect_gitignore, types)?;
+        Ok(builder.build())
+    }
+}
 
-        Ok(builder.build_parallel())
+fn walk_builder(
+    patterns: &[PathBuf],
+    respect_ignore: bool,
+    respect_gitignore: bool,
+    types: Option<Types>,
+) -> Result<WalkBuilder> {
+    let (head_pattern, tail_patterns) = patterns
+        .split_first()
+        .ok_or_else(|| miette!("files must be non-empty"))?;
+    let mut builder = WalkBuilder::new(head_pattern);
+    for pattern in tail_patterns {
+        builder.add(pattern);
+    }
+
+    builder.ignore(respect_ignore);
+    builder.git_ignore(respect_gitignore);
+
+    // NOTE: Expect performance improvements with pre-filtering
+    if let Some(types) = types {
+        builder.types(types);
     }
+
+    Ok(builder)
 }
 
 #[cfg(test)]
@@ -54,7 +77,9 @@ mod tests {
     use ignore::{DirEntry, WalkState};
     use pretty_assertions::assert_eq;
 
-    use super::WalkParallelBuilder;
+    use super::{WalkParallelBuilder, WalkSequentialBuilder};
+    use crate::config::Lint;
+    use crate::service::filter::FileFilter;
 
     struct PathCollector {
         paths: Arc<Mutex<Vec<PathBuf>>>,
@@ -94,7 +119,12 @@ mod tests {
             Path::new("mado.toml").to_path_buf(),
             Path::new("README.md").to_path_buf(),
         ];
-        let builder = WalkParallelBuilder::build(&paths, true, true)?;
+        let builder = WalkParallelBuilder::build(
+            &paths,
+            true,
+            true,
+            FileFilter::new(&Lint::default())?.types()?,
+        )?;
         let collector = PathCollector::new();
 
         builder.run(|| Box::new(collector.gen_visitor()));
@@ -111,9 +141,35 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn build_sequential() -> miette::Result<()> {
+        let paths = vec![
+            Path::new("action").to_path_buf(),
+            Path::new("README.md").to_path_buf(),
+        ];
+        let walk = WalkSequentialBuilder::build(
+            &paths,
+            true,
+            true,
+            FileFilter::new(&Lint::default())?.types()?,
+        )?;
+        let mut actual = walk
+            .map(|entry| entry.map(ignore::DirEntry::into_path))
+            .collect::<Result<Vec<_>, _>>()
+            .into_diagnostic()?;
+        actual.sort();
+
+        let expected = vec![
+            Path::new("README.md").to_path_buf(),
+            Path::new("action").to_path_buf(),
+        ];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
     #[test]
     fn build_empty_patterns() {
-        let result = WalkParallelBuilder::build(&[], true, true);
+        let result = WalkParallelBuilder::build(&[], true, true, None);
         assert!(result.is_err());
     }
 }
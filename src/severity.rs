@@ -0,0 +1,75 @@
+use core::fmt::{Display, Formatter, Result};
+
+use colored::Color;
+use serde::{Deserialize, Serialize};
+
+/// Severity of violations of a rule
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+    Info,
+    /// Disables the rule
+    Off,
+}
+
+impl Severity {
+    #[inline]
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Info => "info",
+            Self::Off => "off",
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn color(self) -> Color {
+        match self {
+            Self::Error => Color::Red,
+            Self::Warning => Color::Yellow,
+            Self::Info | Self::Off => Color::Blue,
+        }
+    }
+}
+
+impl Display for Severity {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miette::IntoDiagnostic as _;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn deserialize() -> miette::Result<()> {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            severity: Severity,
+        }
+
+        let actual: Wrapper = toml::from_str(r#"severity = "warning""#).into_diagnostic()?;
+        assert_eq!(actual.severity, Severity::Warning);
+        Ok(())
+    }
+
+    #[test]
+    fn display_fmt() {
+        assert_eq!(Severity::Error.to_string(), "error");
+        assert_eq!(Severity::Info.to_string(), "info");
+    }
+}
@@ -0,0 +1,142 @@
+use core::iter;
+use std::path::{Path, PathBuf};
+
+use crate::Document;
+
+/// Text of a linted file indexed by lines to look up violation positions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Source {
+    path: PathBuf,
+    text: String,
+    /// Byte offsets of the start of each line
+    line_offsets: Vec<usize>,
+}
+
+impl Source {
+    #[inline]
+    #[must_use]
+    pub fn new(path: PathBuf, text: String) -> Self {
+        let line_offsets = line_offsets(&text);
+        Self {
+            path,
+            text,
+            line_offsets,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the 1-based line without line endings
+    #[inline]
+    #[must_use]
+    pub fn line(&self, lineno: usize) -> Option<&str> {
+        let index = lineno.checked_sub(1)?;
+        let start = *self.line_offsets.get(index)?;
+        let end = self
+            .line_offsets
+            .get(index + 1)
+            .copied()
+            .unwrap_or(self.text.len());
+        self.text
+            .get(start..end)
+            .map(|line| line.trim_end_matches(['\n', '\r']))
+    }
+
+    /// Returns the 0-based index of the line containing the byte offset
+    #[inline]
+    #[must_use]
+    pub fn line_index(&self, offset: usize) -> usize {
+        self.line_offsets
+            .partition_point(|line_offset| *line_offset <= offset)
+            .saturating_sub(1)
+    }
+
+    /// Returns the byte offset of the start of the 0-based line, or the end of the text
+    #[inline]
+    #[must_use]
+    pub fn line_start(&self, index: usize) -> usize {
+        self.line_offsets
+            .get(index)
+            .copied()
+            .unwrap_or(self.text.len())
+    }
+
+    /// Returns the byte offset of the given 1-based line and column clamped to the text
+    #[inline]
+    #[must_use]
+    pub fn offset(&self, lineno: usize, column: usize) -> usize {
+        let lineno = lineno.max(1);
+        let Some(line) = self.line(lineno) else {
+            return self.text.len();
+        };
+
+        self.line_start(lineno - 1) + column.saturating_sub(1).min(line.len())
+    }
+}
+
+impl From<Document<'_>> for Source {
+    #[inline]
+    fn from(doc: Document<'_>) -> Self {
+        Self {
+            path: doc.path,
+            text: doc.text,
+            line_offsets: doc.line_offsets,
+        }
+    }
+}
+
+// Returns byte offsets of the start of each line as `str::lines` splits
+pub fn line_offsets(text: &str) -> Vec<usize> {
+    let num_lines = text.lines().count();
+    iter::once(0)
+        .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+        .take(num_lines)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn line() {
+        let source = Source::new(PathBuf::from("test.md"), "foo\r\nbar\n\nbaz".to_owned());
+        assert_eq!(source.line(0), None);
+        assert_eq!(source.line(1), Some("foo"));
+        assert_eq!(source.line(2), Some("bar"));
+        assert_eq!(source.line(3), Some(""));
+        assert_eq!(source.line(4), Some("baz"));
+        assert_eq!(source.line(5), None);
+    }
+
+    #[test]
+    fn line_index() {
+        let source = Source::new(PathBuf::from("test.md"), "ab\ncd\n".to_owned());
+        assert_eq!(source.line_index(0), 0);
+        assert_eq!(source.line_index(2), 0);
+        assert_eq!(source.line_index(3), 1);
+        assert_eq!(source.line_index(6), 1);
+    }
+
+    #[test]
+    fn offset_clamped() {
+        let source = Source::new(PathBuf::from("test.md"), "ab\ncd\n".to_owned());
+        assert_eq!(source.offset(1, 1), 0);
+        assert_eq!(source.offset(2, 2), 4);
+        assert_eq!(source.offset(2, 10), 5);
+        assert_eq!(source.offset(0, 1), 0);
+        assert_eq!(source.offset(5, 1), 6);
+    }
+}
@@ -3,13 +3,16 @@ use std::path::PathBuf;
 
 use comrak::nodes::Sourcepos;
 
-use crate::rule::Metadata;
+use crate::rule::{Metadata, Tag};
+use crate::{Edit, Severity};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Violation {
     path: PathBuf,
     metadata: &'static Metadata,
     position: Sourcepos,
+    severity: Severity,
+    edits: Vec<Edit>,
 }
 
 impl Violation {
@@ -20,15 +23,35 @@ impl Violation {
             path,
             metadata,
             position,
+            severity: Severity::Error,
+            edits: vec![],
         }
     }
 
+    #[inline]
+    #[must_use]
+    pub fn with_severity(self, severity: Severity) -> Self {
+        Self { severity, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_edits(self, edits: Vec<Edit>) -> Self {
+        Self { edits, ..self }
+    }
+
     #[inline]
     #[must_use]
     pub const fn path(&self) -> &PathBuf {
         &self.path
     }
 
+    #[inline]
+    #[must_use]
+    pub const fn metadata(&self) -> &'static Metadata {
+        self.metadata
+    }
+
     #[inline]
     #[must_use]
     pub const fn name(&self) -> &str {
@@ -41,17 +64,47 @@ impl Violation {
         self.metadata.aliases[0]
     }
 
+    #[inline]
+    #[must_use]
+    pub const fn aliases(&self) -> &[&str] {
+        self.metadata.aliases
+    }
+
     #[inline]
     #[must_use]
     pub const fn description(&self) -> &str {
         self.metadata.description
     }
 
+    #[inline]
+    #[must_use]
+    pub const fn tags(&self) -> &[Tag] {
+        self.metadata.tags
+    }
+
     #[inline]
     #[must_use]
     pub const fn position(&self) -> &Sourcepos {
         &self.position
     }
+
+    #[inline]
+    #[must_use]
+    pub const fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn edits(&self) -> &[Edit] {
+        &self.edits
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_fixable(&self) -> bool {
+        !self.edits.is_empty()
+    }
 }
 
 impl PartialOrd for Violation {
@@ -1,6 +1,7 @@
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::Write as _;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process;
 
 use assert_cmd::Command;
 use indoc::formatdoc;
@@ -11,6 +12,13 @@ use miette::IntoDiagnostic as _;
 use miette::Result;
 use tempfile::tempdir;
 
+// Disables GitHub Actions detection so that outputs do not depend on CI
+fn mado() -> Result<Command> {
+    let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
+    cmd.env_remove("GITHUB_ACTIONS");
+    Ok(cmd)
+}
+
 fn with_tmp_file<F>(name: &str, content: &str, f: F) -> Result<()>
 where
     F: FnOnce(PathBuf) -> Result<()>,
@@ -27,7 +35,7 @@ where
 
 #[test]
 fn check() -> Result<()> {
-    let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
+    let mut cmd = mado()?;
     let assert = cmd.args(["check", "."]).assert();
     assert.success().stdout("All checks passed!\n");
     Ok(())
@@ -35,7 +43,7 @@ fn check() -> Result<()> {
 
 #[test]
 fn check_quiet() -> Result<()> {
-    let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
+    let mut cmd = mado()?;
     let assert = cmd.args(["check", "--quiet", "."]).assert();
     assert.success().stdout("");
     Ok(())
@@ -51,7 +59,7 @@ fn check_quiet_with_config() -> Result<()> {
     let content = toml::to_string(&config).into_diagnostic()?;
 
     with_tmp_file("mado.toml", &content, |path| {
-        let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
+        let mut cmd = mado()?;
         let path_str = path.to_str().wrap_err("failed to convert string")?;
         let assert = cmd.args(["--config", path_str, "check", "."]).assert();
         assert.success().stdout("");
@@ -61,8 +69,8 @@ fn check_quiet_with_config() -> Result<()> {
 
 #[test]
 fn check_stdin() -> Result<()> {
-    let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
-    let assert = cmd.write_stdin("#Hello.").args(["check"]).assert();
+    let mut cmd = mado()?;
+    let assert = cmd.write_stdin("#Hello.").args(["check", "-"]).assert();
     assert.failure().stdout(
         indoc! {"
             \u{1b}[1m(stdin)\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD018\u{1b}[0m No space after hash on atx style header
@@ -75,10 +83,166 @@ fn check_stdin() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn check_stdin_with_directives() -> Result<()> {
+    let mut cmd = mado()?;
+    let assert = cmd
+        .write_stdin("<!-- mado-disable-file first-line-h1 -->\n<!-- mado-disable-next-line MD018 -->\n#Hello\n")
+        .args(["check", "-"])
+        .assert();
+    assert.success().stdout("All checks passed!\n");
+    Ok(())
+}
+
+#[test]
+fn check_stdin_json_lines() -> Result<()> {
+    let mut cmd = mado()?;
+    let assert = cmd
+        .write_stdin("# Hello.\n")
+        .args(["check", "--output-format", "json-lines", "-"])
+        .assert();
+    assert.failure().stdout(indoc! {r#"
+        {"type":"violation","path":"(stdin)","rule":"MD026","aliases":["no-trailing-punctuation"],"description":"Trailing punctuation in header","severity":"error","tags":["headers"],"position":{"start":{"line":1,"column":1},"end":{"line":1,"column":8}},"fixable":true}
+        {"type":"summary","violations":1,"fixed":0,"remaining":1,"errors":1,"warnings":0,"info":0}
+    "#});
+    Ok(())
+}
+
+#[test]
+fn check_stdin_json_no_errors() -> Result<()> {
+    let mut cmd = mado()?;
+    let assert = cmd
+        .write_stdin("# Hello\n")
+        .args(["check", "--output-format", "json", "-"])
+        .assert();
+    assert.success().stdout(indoc! {r#"
+        {
+          "version": 1,
+          "violations": [],
+          "summary": {
+            "violations": 0,
+            "fixed": 0,
+            "remaining": 0,
+            "errors": 0,
+            "warnings": 0,
+            "info": 0
+          }
+        }
+    "#});
+    Ok(())
+}
+
+#[test]
+fn check_stdin_sarif() -> Result<()> {
+    let mut cmd = mado()?;
+    let output = cmd
+        .write_stdin("# Hello.\n")
+        .args(["check", "--output-format", "sarif", "-"])
+        .output()
+        .into_diagnostic()?;
+    assert!(!output.status.success());
+    let sarif: serde_json::Value = serde_json::from_slice(&output.stdout).into_diagnostic()?;
+    assert_eq!(sarif["version"], "2.1.0");
+    assert_eq!(sarif["runs"][0]["tool"]["driver"]["name"], "mado");
+    let results = &sarif["runs"][0]["results"];
+    assert_eq!(results[0]["ruleId"], "MD026");
+    assert_eq!(results[1], serde_json::Value::Null);
+    Ok(())
+}
+
+#[test]
+fn check_stdin_github_actions() -> Result<()> {
+    let mut cmd = mado()?;
+    let assert = cmd
+        .env("GITHUB_ACTIONS", "true")
+        .write_stdin("# Hello.\n")
+        .args(["check", "-"])
+        .assert();
+    assert.failure().stdout(indoc! {"
+        ::error file=(stdin),line=1,col=1,endLine=1,endColumn=8,title=MD026::Trailing punctuation in header
+
+        Found 1 error.
+    "});
+    Ok(())
+}
+
+#[test]
+fn check_stdin_github_actions_with_config_format() -> Result<()> {
+    with_tmp_file(
+        "mado.toml",
+        "[lint]\noutput-format = \"concise\"\n",
+        |path| {
+            let path_str = path.to_str().wrap_err("failed to convert string")?;
+            let mut cmd = mado()?;
+            let assert = cmd
+                .env("GITHUB_ACTIONS", "true")
+                .env_remove("CLICOLOR_FORCE")
+                .write_stdin("# Hello.\n")
+                .args(["--config", path_str, "check", "-"])
+                .assert();
+            assert.failure().stdout(indoc! {"
+            (stdin):1:1: MD026 Trailing punctuation in header
+
+            Found 1 error.
+        "});
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn check_stdin_full() -> Result<()> {
+    let mut cmd = mado()?;
+    let assert = cmd
+        .env_remove("CLICOLOR_FORCE")
+        .write_stdin("# Hello.\n")
+        .args(["check", "--output-format", "full", "-"])
+        .assert();
+    assert.failure().stdout(indoc! {"
+        MD026/no-trailing-punctuation
+
+          × Trailing punctuation in header
+           ╭─[(stdin):1:1]
+         1 │ # Hello.
+           · ────────
+           ╰────
+          help: Run with `--fix` to fix this violation
+
+        Found 1 error.
+    "});
+    Ok(())
+}
+
+#[test]
+fn check_full() -> Result<()> {
+    with_tmp_file("test.md", "# Title\n\nText\n\n## Foo.\n", |path| {
+        let mut cmd = mado()?;
+        let path_str = path.to_str().wrap_err("failed to convert string")?;
+        let assert = cmd
+            .env_remove("CLICOLOR_FORCE")
+            .args(["check", "--no-cache", "--output-format", "full", path_str])
+            .assert();
+        assert.failure().stdout(formatdoc! {"
+            MD026/no-trailing-punctuation
+
+              × Trailing punctuation in header
+               ╭─[{path_str}:5:1]
+             4 │ 
+             5 │ ## Foo.
+               · ───────
+               ╰────
+              help: Run with `--fix` to fix this violation
+
+            Found 1 error.
+        "});
+        Ok(())
+    })
+}
+
 #[test]
 fn check_empty_stdin() -> Result<()> {
-    let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
-    let assert = cmd.write_stdin("").args(["check"]).assert();
+    let mut cmd = mado()?;
+    let assert = cmd.write_stdin("").args(["check", "-"]).assert();
     assert.success().stdout("All checks passed!\n");
     Ok(())
 }
@@ -86,7 +250,7 @@ fn check_empty_stdin() -> Result<()> {
 #[test]
 fn check_empty_stdin_with_file() -> Result<()> {
     with_tmp_file("test.md", "#Hello.", |path| {
-        let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
+        let mut cmd = mado()?;
         let path_str = path.to_str().wrap_err("failed to convert string")?;
         let assert = cmd.write_stdin("").args(["check", path_str]).assert();
         assert.failure().stdout(
@@ -103,19 +267,20 @@ fn check_empty_stdin_with_file() -> Result<()> {
 }
 
 #[test]
-fn check_stdin_with_file() -> Result<()> {
+fn check_file_with_stdin() -> Result<()> {
     with_tmp_file("test.md", "#Hello.", |path| {
-        let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
+        let mut cmd = mado()?;
         let path_str = path.to_str().wrap_err("failed to convert string")?;
         let assert = cmd
-            .write_stdin("#Hello.")
+            .write_stdin("# Hello\n")
             .args(["check", path_str])
             .assert();
+        // stdin is not read unless "-" is given
         assert.failure().stdout(
-            indoc! {"
-                \u{1b}[1m(stdin)\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD018\u{1b}[0m No space after hash on atx style header
-                \u{1b}[1m(stdin)\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD041\u{1b}[0m First line in file should be a top level header
-                \u{1b}[1m(stdin)\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD047\u{1b}[0m File should end with a single newline character
+            formatdoc! {"
+                \u{1b}[1m{path_str}\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD018\u{1b}[0m No space after hash on atx style header
+                \u{1b}[1m{path_str}\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD041\u{1b}[0m First line in file should be a top level header
+                \u{1b}[1m{path_str}\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD047\u{1b}[0m File should end with a single newline character
 
                 Found 3 errors.
             "}
@@ -124,13 +289,713 @@ fn check_stdin_with_file() -> Result<()> {
     })
 }
 
+#[test]
+fn check_stdin_with_other_files() -> Result<()> {
+    let mut cmd = mado()?;
+    let assert = cmd
+        .write_stdin("# Hello\n")
+        .args(["check", "-", "README.md"])
+        .assert();
+    assert.failure().stdout("");
+    Ok(())
+}
+
+#[test]
+fn check_stdin_filename() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    let config_text = indoc! {r#"
+        [lint]
+        exclude = ["vendor/**"]
+
+        [[lint.overrides]]
+        files = ["blog/*.md"]
+        disable = ["MD026"]
+    "#};
+    fs::write(tmp_dir.path().join("mado.toml"), config_text).into_diagnostic()?;
+
+    let mut cmd = mado()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .write_stdin("# Hello.\n")
+        .args(["check", "--stdin-filename", "docs/index.md", "-"])
+        .assert();
+    assert.failure().stdout(indoc! {"
+        \u{1b}[1mdocs/index.md\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD026\u{1b}[0m Trailing punctuation in header
+
+        Found 1 error.
+    "});
+
+    for path in ["blog/post.md", "vendor/README.md"] {
+        mado()?
+            .current_dir(tmp_dir.path())
+            .write_stdin("# Hello.\n")
+            .args(["check", "--stdin-filename", path, "-"])
+            .assert()
+            .success()
+            .stdout("All checks passed!\n");
+    }
+    tmp_dir.close().into_diagnostic()
+}
+
 #[test]
 fn check_exclusion() -> Result<()> {
     with_tmp_file("test.md", "#Hello.", |path| {
-        let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
+        let mut cmd = mado()?;
         let path_str = path.to_str().wrap_err("failed to convert string")?;
         let assert = cmd.args(["check", path_str, "--exclude", "*.md"]).assert();
         assert.success().stdout("All checks passed!\n");
         Ok(())
     })
 }
+
+#[test]
+fn check_overrides() -> Result<()> {
+    let config = indoc! {r#"
+        [[lint.overrides]]
+        files = ["**/blog/*.md"]
+        disable = ["MD026"]
+    "#};
+    let tmp_dir = tempdir().into_diagnostic()?;
+    let config_path = tmp_dir.path().join("mado.toml");
+    fs::write(&config_path, config).into_diagnostic()?;
+    let blog_dir = tmp_dir.path().join("blog");
+    fs::create_dir(&blog_dir).into_diagnostic()?;
+    fs::write(blog_dir.join("post.md"), "# Hello!\n").into_diagnostic()?;
+    fs::write(tmp_dir.path().join("README.md"), "# Hello!\n").into_diagnostic()?;
+
+    let mut cmd = mado()?;
+    let config_str = config_path.to_str().wrap_err("failed to convert string")?;
+    let dir_str = tmp_dir
+        .path()
+        .to_str()
+        .wrap_err("failed to convert string")?;
+    let assert = cmd
+        .args(["--config", config_str, "check", dir_str])
+        .assert();
+    let readme_path = tmp_dir.path().join("README.md");
+    let readme_str = readme_path.to_str().wrap_err("failed to convert string")?;
+    assert.failure().stdout(formatdoc! {"
+        \u{1b}[1m{readme_str}\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD026\u{1b}[0m Trailing punctuation in header
+
+        Found 1 error.
+    "});
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_stdin_with_warnings() -> Result<()> {
+    let config = indoc! {r#"
+        [lint]
+        rules = ["MD026", "MD041"]
+
+        [lint.severity]
+        MD026 = "warning"
+        first-line-h1 = "off"
+    "#};
+    let tmp_dir = tempdir().into_diagnostic()?;
+    let config_path = tmp_dir.path().join("mado.toml");
+    fs::write(&config_path, config).into_diagnostic()?;
+    let config_str = config_path.to_str().wrap_err("failed to convert string")?;
+
+    let mut cmd = mado()?;
+    let assert = cmd
+        .write_stdin("# Hello.\n")
+        .args(["--config", config_str, "check", "-"])
+        .assert();
+    assert.success().stdout(indoc! {"
+        \u{1b}[1m(stdin)\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[33mwarning\u{1b}[0m \u{1b}[1;33mMD026\u{1b}[0m Trailing punctuation in header
+
+        Found 1 warning.
+    "});
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_stdin_with_max_warnings() -> Result<()> {
+    let config = indoc! {r#"
+        [lint]
+        rules = ["MD026", "MD041"]
+
+        [lint.severity]
+        MD026 = "warning"
+        first-line-h1 = "off"
+    "#};
+    let tmp_dir = tempdir().into_diagnostic()?;
+    let config_path = tmp_dir.path().join("mado.toml");
+    fs::write(&config_path, config).into_diagnostic()?;
+    let config_str = config_path.to_str().wrap_err("failed to convert string")?;
+
+    let mut cmd = mado()?;
+    let assert = cmd
+        .write_stdin("# Hello.\n")
+        .args(["--config", config_str, "check", "--max-warnings", "0", "-"])
+        .assert();
+    assert.failure().stdout(indoc! {"
+        \u{1b}[1m(stdin)\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[33mwarning\u{1b}[0m \u{1b}[1;33mMD026\u{1b}[0m Trailing punctuation in header
+
+        Found 1 warning.
+        Too many warnings (max: 0).
+    "});
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_cache() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    let project_dir = tmp_dir.path().join("project");
+    fs::create_dir_all(&project_dir).into_diagnostic()?;
+    fs::write(project_dir.join("README.md"), "# Hello.\n").into_diagnostic()?;
+    let cache_home = tmp_dir.path().join("cache");
+    let expected = indoc! {"
+        \u{1b}[1m./README.md\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD026\u{1b}[0m Trailing punctuation in header
+
+        Found 1 error.
+    "};
+
+    // The second run reads violations from the cache
+    for _ in 0..2 {
+        let mut cmd = mado()?;
+        let assert = cmd
+            .current_dir(&project_dir)
+            .env("XDG_CACHE_HOME", &cache_home)
+            .args(["check"])
+            .assert();
+        assert.failure().stdout(expected);
+    }
+    let version_dir = cache_home.join("mado").join(env!("CARGO_PKG_VERSION"));
+    let project_cache_dirs: Vec<_> = fs::read_dir(version_dir)
+        .into_diagnostic()?
+        .collect::<Result<_, _>>()
+        .into_diagnostic()?;
+    assert_eq!(project_cache_dirs.len(), 1);
+    let num_entries = fs::read_dir(project_cache_dirs[0].path())
+        .into_diagnostic()?
+        .count();
+    assert_eq!(num_entries, 1);
+    assert!(!fs::exists(project_dir.join(".mado_cache")).into_diagnostic()?);
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_cache_unavailable() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    fs::write(tmp_dir.path().join("README.md"), "# Hello.\n").into_diagnostic()?;
+    // The cache directory cannot be created under a file
+    let cache_home = tmp_dir.path().join("README.md");
+    let mut cmd = mado()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .env("XDG_CACHE_HOME", &cache_home)
+        .env_remove("CLICOLOR_FORCE")
+        .args(["check"])
+        .assert();
+    let assert = assert.failure().stdout(indoc! {"
+        ./README.md:1:1: MD026 Trailing punctuation in header
+
+        Found 1 error.
+    "});
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(stderr.starts_with("Failed to create the cache in "));
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_no_cache() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    fs::write(tmp_dir.path().join("README.md"), "# Hello\n").into_diagnostic()?;
+    let cache_home = tmp_dir.path().join("cache");
+    let mut cmd = mado()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .env("XDG_CACHE_HOME", &cache_home)
+        .args(["check", "--no-cache"])
+        .assert();
+    assert.success().stdout("All checks passed!\n");
+    assert!(!fs::exists(cache_home).into_diagnostic()?);
+    tmp_dir.close().into_diagnostic()
+}
+
+// Runs git in the directory with a fixed identity
+fn git(dir: &Path, args: &[&str]) -> Result<()> {
+    let status = process::Command::new("git")
+        .current_dir(dir)
+        .args(["-c", "user.name=mado", "-c", "user.email=mado@example.com"])
+        .args(args)
+        .status()
+        .into_diagnostic()?;
+    assert!(status.success());
+    Ok(())
+}
+
+#[test]
+fn check_diff_base() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    fs::write(tmp_dir.path().join("README.md"), "# Hello.\n\nText\n").into_diagnostic()?;
+    fs::write(tmp_dir.path().join("LEGACY.md"), "# Legacy.\n").into_diagnostic()?;
+    git(tmp_dir.path(), &["init", "--quiet"])?;
+    git(tmp_dir.path(), &["add", "."])?;
+    git(
+        tmp_dir.path(),
+        &["commit", "--quiet", "--message", "Initial commit"],
+    )?;
+
+    fs::write(
+        tmp_dir.path().join("README.md"),
+        "# Hello.\n\nText\n\n## World.\n",
+    )
+    .into_diagnostic()?;
+    fs::write(tmp_dir.path().join("NEW.md"), "# New.\n").into_diagnostic()?;
+    let mut cmd = mado()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .args(["check", "--no-cache", "--diff-base", "HEAD"])
+        .assert();
+    let expected = indoc! {"
+        \u{1b}[1m./NEW.md\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD026\u{1b}[0m Trailing punctuation in header
+        \u{1b}[1m./README.md\u{1b}[0m\u{1b}[34m:\u{1b}[0m5\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD026\u{1b}[0m Trailing punctuation in header
+
+        Found 2 errors.
+    "};
+    assert.failure().stdout(expected);
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_diff_base_non_markdown_files() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    fs::write(tmp_dir.path().join("README.md"), "# Hello\n").into_diagnostic()?;
+    git(tmp_dir.path(), &["init", "--quiet"])?;
+    git(tmp_dir.path(), &["add", "."])?;
+    git(
+        tmp_dir.path(),
+        &["commit", "--quiet", "--message", "Initial commit"],
+    )?;
+
+    fs::write(tmp_dir.path().join("Cargo.toml"), "[package]\n").into_diagnostic()?;
+    fs::write(tmp_dir.path().join("main.rs"), "#[test]\nfn main() {}\n").into_diagnostic()?;
+    fs::write(tmp_dir.path().join("NEW.md"), "# New.\n").into_diagnostic()?;
+    let mut cmd = mado()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .env_remove("CLICOLOR_FORCE")
+        .args(["check", "--no-cache", "--diff-base", "HEAD", "."])
+        .assert();
+    let expected = indoc! {"
+        ./NEW.md:1:1: MD026 Trailing punctuation in header
+
+        Found 1 error.
+    "};
+    assert.failure().stdout(expected);
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_diff_base_non_ascii_paths() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    fs::write(tmp_dir.path().join("caf\u{e9}.md"), "# Hello\n").into_diagnostic()?;
+    git(tmp_dir.path(), &["init", "--quiet"])?;
+    git(tmp_dir.path(), &["add", "."])?;
+    git(
+        tmp_dir.path(),
+        &["commit", "--quiet", "--message", "Initial commit"],
+    )?;
+
+    fs::write(tmp_dir.path().join("caf\u{e9}.md"), "# Hello.\n").into_diagnostic()?;
+    fs::write(tmp_dir.path().join("\u{65b0}.md"), "# New.\n").into_diagnostic()?;
+    let mut cmd = mado()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .env_remove("CLICOLOR_FORCE")
+        .args([
+            "check",
+            "--no-cache",
+            "--diff-base",
+            "HEAD",
+            ".",
+            "caf\u{e9}.md",
+        ])
+        .assert();
+    let expected = indoc! {"
+        ./caf\u{e9}.md:1:1: MD026 Trailing punctuation in header
+        ./\u{65b0}.md:1:1: MD026 Trailing punctuation in header
+
+        Found 2 errors.
+    "};
+    assert.failure().stdout(expected);
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_diff_base_unchanged() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    fs::write(tmp_dir.path().join("README.md"), "# Hello.\n").into_diagnostic()?;
+    git(tmp_dir.path(), &["init", "--quiet"])?;
+    git(tmp_dir.path(), &["add", "."])?;
+    git(
+        tmp_dir.path(),
+        &["commit", "--quiet", "--message", "Initial commit"],
+    )?;
+
+    let mut cmd = mado()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .args(["check", "--no-cache", "--diff-base", "HEAD"])
+        .assert();
+    assert.success().stdout("All checks passed!\n");
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_markdownlint_config() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    let config_text = indoc! {r#"
+        {
+          "MD026": false,
+          "no-reversed-links": true
+        }
+    "#};
+    fs::write(tmp_dir.path().join(".markdownlint.json"), config_text).into_diagnostic()?;
+    fs::write(tmp_dir.path().join("README.md"), "# Hello.\n\nText  \n").into_diagnostic()?;
+
+    let mut cmd = mado()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .args(["check", "--no-cache", "."])
+        .assert();
+    let config_path = fs::canonicalize(tmp_dir.path())
+        .into_diagnostic()?
+        .join(".markdownlint.json");
+    let expected_stderr = format!(
+        "warning: {}: MD011 (no-reversed-links) is not supported yet\n",
+        config_path.display()
+    );
+    let expected = indoc! {"
+        \u{1b}[1m./README.md\u{1b}[0m\u{1b}[34m:\u{1b}[0m3\u{1b}[34m:\u{1b}[0m5\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD009\u{1b}[0m Trailing spaces
+
+        Found 1 error.
+    "};
+    assert.failure().stdout(expected).stderr(expected_stderr);
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_extensions_and_include() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    let config_text = indoc! {r#"
+        [lint]
+        extensions = ["markdown"]
+        include = ["docs/**/*.txt"]
+        rules = ["MD026"]
+    "#};
+    fs::write(tmp_dir.path().join("mado.toml"), config_text).into_diagnostic()?;
+    fs::create_dir(tmp_dir.path().join("docs")).into_diagnostic()?;
+    for path in ["README.md", "index.markdown", "notes.txt", "docs/guide.txt"] {
+        fs::write(tmp_dir.path().join(path), "# Hello.\n").into_diagnostic()?;
+    }
+
+    let mut cmd = mado()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .args(["check", "--no-cache", "."])
+        .assert();
+    let expected = indoc! {"
+        \u{1b}[1m./docs/guide.txt\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD026\u{1b}[0m Trailing punctuation in header
+        \u{1b}[1m./index.markdown\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD026\u{1b}[0m Trailing punctuation in header
+
+        Found 2 errors.
+    "};
+    assert.failure().stdout(expected);
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_explicit_file_regardless_of_extensions() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    fs::write(tmp_dir.path().join("notes.txt"), "# Hello.\n").into_diagnostic()?;
+
+    let mut cmd = mado()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .args(["check", "--no-cache", "notes.txt"])
+        .assert();
+    let expected = indoc! {"
+        \u{1b}[1mnotes.txt\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD026\u{1b}[0m Trailing punctuation in header
+
+        Found 1 error.
+    "};
+    assert.failure().stdout(expected);
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_mdl_config() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    let style_text = indoc! {"
+        all
+        exclude_rule 'MD026'
+        rule 'MD013', :line_length => 20
+    "};
+    fs::write(tmp_dir.path().join("style.rb"), style_text).into_diagnostic()?;
+    fs::write(tmp_dir.path().join(".mdlrc"), "style 'style.rb'\n").into_diagnostic()?;
+    fs::write(
+        tmp_dir.path().join("README.md"),
+        "# Hello.\n\nThis line is longer than 20.\n",
+    )
+    .into_diagnostic()?;
+
+    let mut cmd = mado()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .args(["check", "--no-cache", "."])
+        .assert();
+    let expected = indoc! {"
+        \u{1b}[1m./README.md\u{1b}[0m\u{1b}[34m:\u{1b}[0m3\u{1b}[34m:\u{1b}[0m21\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD013\u{1b}[0m Line length
+
+        Found 1 error.
+    "};
+    assert.failure().stdout(expected);
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_baseline() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    fs::write(tmp_dir.path().join("README.md"), "# Hello.\n").into_diagnostic()?;
+    let mut write_cmd = mado()?;
+    let write_assert = write_cmd
+        .current_dir(tmp_dir.path())
+        .args([
+            "check",
+            "--no-cache",
+            "--write-baseline",
+            "mado-baseline.json",
+        ])
+        .assert();
+    write_assert
+        .success()
+        .stdout("Wrote 1 violation to mado-baseline.json.\n");
+
+    fs::write(
+        tmp_dir.path().join("README.md"),
+        "# Hello.\n\nText\n\n## World.\n",
+    )
+    .into_diagnostic()?;
+    let mut cmd = mado()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .args(["check", "--no-cache", "--baseline", "mado-baseline.json"])
+        .assert();
+    let expected = indoc! {"
+        \u{1b}[1m./README.md\u{1b}[0m\u{1b}[34m:\u{1b}[0m5\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD026\u{1b}[0m Trailing punctuation in header
+
+        Found 1 error.
+    "};
+    assert.failure().stdout(expected).stderr("");
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_baseline_other_files() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    fs::write(tmp_dir.path().join("README.md"), "# Hello.\n").into_diagnostic()?;
+    fs::write(tmp_dir.path().join("GUIDE.md"), "# Guide.\n").into_diagnostic()?;
+    let mut write_cmd = mado()?;
+    write_cmd
+        .current_dir(tmp_dir.path())
+        .args([
+            "check",
+            "--no-cache",
+            "--write-baseline",
+            "mado-baseline.json",
+        ])
+        .assert()
+        .success()
+        .stdout("Wrote 2 violations to mado-baseline.json.\n");
+
+    // Entries of files not linted in this run are not fixed
+    let mut cmd = mado()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .args([
+            "check",
+            "--no-cache",
+            "--baseline",
+            "mado-baseline.json",
+            "README.md",
+        ])
+        .assert();
+    assert.success().stdout("All checks passed!\n").stderr("");
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_baseline_fixed() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    fs::write(tmp_dir.path().join("README.md"), "# Hello.\n").into_diagnostic()?;
+    let mut write_cmd = mado()?;
+    write_cmd
+        .current_dir(tmp_dir.path())
+        .args([
+            "check",
+            "--no-cache",
+            "--write-baseline",
+            "mado-baseline.json",
+        ])
+        .assert()
+        .success();
+
+    fs::write(tmp_dir.path().join("README.md"), "# Hello\n").into_diagnostic()?;
+    let mut cmd = mado()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .args(["check", "--no-cache", "--baseline", "mado-baseline.json"])
+        .assert();
+    let expected_stderr = indoc! {"
+        Fixed violations in the baseline:
+          README.md: MD026
+        Update the baseline with `--write-baseline mado-baseline.json`.
+    "};
+    assert
+        .success()
+        .stdout("All checks passed!\n")
+        .stderr(expected_stderr);
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_overrides_with_config_in_other_dir() -> Result<()> {
+    let config = indoc! {r#"
+        [[lint.overrides]]
+        files = ["blog/*.md"]
+        disable = ["MD026"]
+    "#};
+    let tmp_dir = tempdir().into_diagnostic()?;
+    let blog_dir = tmp_dir.path().join("project").join("blog");
+    fs::create_dir_all(&blog_dir).into_diagnostic()?;
+    fs::write(tmp_dir.path().join("project").join("mado.toml"), config).into_diagnostic()?;
+    fs::write(blog_dir.join("post.md"), "# Hello!\n").into_diagnostic()?;
+    fs::write(
+        tmp_dir.path().join("project").join("README.md"),
+        "# Hello!\n",
+    )
+    .into_diagnostic()?;
+
+    // Patterns are relative to the directory of the config file rather than the current one
+    let mut cmd = mado()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .args(["--config", "project/mado.toml", "check", "project"])
+        .assert();
+    assert.failure().stdout(indoc! {"
+        \u{1b}[1mproject/README.md\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD026\u{1b}[0m Trailing punctuation in header
+
+        Found 1 error.
+    "});
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_nested_config() -> Result<()> {
+    let root_config = indoc! {r#"
+        [lint]
+        rules = ["MD026"]
+    "#};
+    let package_config = indoc! {r#"
+        extends = "../mado.toml"
+
+        [lint.md026]
+        punctuation = "."
+    "#};
+    let tmp_dir = tempdir().into_diagnostic()?;
+    fs::write(tmp_dir.path().join("mado.toml"), root_config).into_diagnostic()?;
+    fs::write(tmp_dir.path().join("README.md"), "# Hello!\n").into_diagnostic()?;
+    let package_dir = tmp_dir.path().join("package");
+    fs::create_dir(&package_dir).into_diagnostic()?;
+    fs::write(package_dir.join("mado.toml"), package_config).into_diagnostic()?;
+    fs::write(package_dir.join("README.md"), "# Hello!\n").into_diagnostic()?;
+
+    let mut cmd = mado()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .args(["check", "."])
+        .assert();
+    assert.failure().stdout(indoc! {"
+        \u{1b}[1m./README.md\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD026\u{1b}[0m Trailing punctuation in header
+
+        Found 1 error.
+    "});
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_fix() -> Result<()> {
+    with_tmp_file("test.md", "#Hello   \n\n\n\nText\twith tab", |path| {
+        let mut cmd = mado()?;
+        let path_str = path.to_str().wrap_err("failed to convert string")?;
+        let assert = cmd.args(["check", "--fix", path_str]).assert();
+        assert
+            .success()
+            .stdout("Found 6 violations (6 fixed, 0 remaining).\n");
+        let actual = fs::read_to_string(&path).into_diagnostic()?;
+        assert_eq!(actual, "# Hello\n\nText    with tab\n");
+        Ok(())
+    })
+}
+
+#[test]
+fn check_fix_remaining() -> Result<()> {
+    with_tmp_file("test.md", "Hello   \n", |path| {
+        let mut cmd = mado()?;
+        let path_str = path.to_str().wrap_err("failed to convert string")?;
+        let assert = cmd.args(["check", "--fix", path_str]).assert();
+        assert.failure().stdout(
+            formatdoc! {"
+                \u{1b}[1m{path_str}\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[1;31mMD041\u{1b}[0m First line in file should be a top level header
+
+                Found 2 violations (1 fixed, 1 error remaining).
+            "}
+        );
+        let actual = fs::read_to_string(&path).into_diagnostic()?;
+        assert_eq!(actual, "Hello\n");
+        Ok(())
+    })
+}
+
+#[test]
+fn check_fix_remaining_warnings() -> Result<()> {
+    let config = indoc! {r#"
+        [lint.severity]
+        MD041 = "warning"
+    "#};
+    let tmp_dir = tempdir().into_diagnostic()?;
+    let config_path = tmp_dir.path().join("mado.toml");
+    fs::write(&config_path, config).into_diagnostic()?;
+    let config_str = config_path.to_str().wrap_err("failed to convert string")?;
+    let path = tmp_dir.path().join("test.md");
+    fs::write(&path, "Hello   \n").into_diagnostic()?;
+    let path_str = path.to_str().wrap_err("failed to convert string")?;
+
+    let mut cmd = mado()?;
+    let assert = cmd
+        .args(["--config", config_str, "check", "--fix", path_str])
+        .assert();
+    assert.success().stdout(formatdoc! {"
+        \u{1b}[1m{path_str}\u{1b}[0m\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m1\u{1b}[34m:\u{1b}[0m \u{1b}[33mwarning\u{1b}[0m \u{1b}[1;33mMD041\u{1b}[0m First line in file should be a top level header
+
+        Found 2 violations (1 fixed, 1 warning remaining).
+    "});
+    let actual = fs::read_to_string(&path).into_diagnostic()?;
+    assert_eq!(actual, "Hello\n");
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn check_fix_stdin() -> Result<()> {
+    let mut cmd = mado()?;
+    let assert = cmd
+        .write_stdin("#Hello.")
+        .args(["check", "--fix", "-"])
+        .assert();
+    assert.failure();
+    Ok(())
+}
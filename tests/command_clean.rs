@@ -0,0 +1,29 @@
+use std::fs;
+
+use assert_cmd::Command;
+use miette::IntoDiagnostic as _;
+use miette::Result;
+use tempfile::tempdir;
+
+#[test]
+fn clean() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    let cache_home = tmp_dir.path().join("cache");
+    let cache_dir = cache_home.join("mado");
+    fs::create_dir_all(cache_dir.join("0.0.0")).into_diagnostic()?;
+    // Directories in the current directory are not caches of mado
+    let other_dir = tmp_dir.path().join(".mado_cache");
+    fs::create_dir_all(&other_dir).into_diagnostic()?;
+
+    let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .env("XDG_CACHE_HOME", &cache_home)
+        .args(["clean"])
+        .assert();
+    let expected = format!("Removed {}\n", cache_dir.display());
+    assert.success().stdout(expected);
+    assert!(!fs::exists(&cache_dir).into_diagnostic()?);
+    assert!(fs::exists(&other_dir).into_diagnostic()?);
+    tmp_dir.close().into_diagnostic()
+}
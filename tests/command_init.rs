@@ -0,0 +1,81 @@
+use std::fs;
+
+use assert_cmd::Command;
+use indoc::indoc;
+use miette::IntoDiagnostic as _;
+use miette::Result;
+use tempfile::tempdir;
+
+#[test]
+fn init() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
+    let assert = cmd.current_dir(tmp_dir.path()).args(["init"]).assert();
+    assert.success().stdout("Wrote mado.toml\n");
+    let config = fs::read_to_string(tmp_dir.path().join("mado.toml")).into_diagnostic()?;
+    assert!(config.starts_with("[lint.md003]\nstyle = \"consistent\"\n"));
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn init_infer() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    let text = indoc! {"
+        # Title #
+
+        - Item
+            - Nested item
+
+        ***
+
+        Text.
+    "};
+    fs::write(tmp_dir.path().join("README.md"), text).into_diagnostic()?;
+    fs::write(
+        tmp_dir.path().join("OTHER.md"),
+        "# Other #\n\n- Item\n+ Item\n",
+    )
+    .into_diagnostic()?;
+
+    let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
+    let assert = cmd
+        .current_dir(tmp_dir.path())
+        .args(["init", "--infer"])
+        .assert();
+    let expected = indoc! {r#"
+        Inferred from 2 files:
+          MD003 style = "atx-closed"
+          MD004 style = "dash"
+          MD007 indent = 4
+          MD013 code-blocks = true
+          MD013 line-length = 80
+          MD013 tables = true
+          MD029 style = "one"
+          MD035 style = "***"
+          MD046 style = "fenced"
+
+        Wrote mado.toml
+        Found 2 errors with the configuration:
+          MD004: 1
+          MD032: 1
+    "#};
+    assert.success().stdout(expected);
+
+    let mut check_cmd = Command::cargo_bin("mado").into_diagnostic()?;
+    let check_assert = check_cmd
+        .current_dir(tmp_dir.path())
+        .args(["check", "--no-cache"])
+        .assert();
+    check_assert.failure();
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn init_exists() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    fs::write(tmp_dir.path().join("mado.toml"), "").into_diagnostic()?;
+    let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
+    let assert = cmd.current_dir(tmp_dir.path()).args(["init"]).assert();
+    assert.failure();
+    tmp_dir.close().into_diagnostic()
+}
@@ -0,0 +1,91 @@
+use std::fs;
+
+use assert_cmd::Command;
+use indoc::indoc;
+use miette::IntoDiagnostic as _;
+use miette::Result;
+use tempfile::tempdir;
+
+#[test]
+fn rules() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    let config = indoc! {r#"
+        [lint]
+        rules = ["MD013"]
+
+        [lint.md013]
+        line-length = 100
+    "#};
+    fs::write(tmp_dir.path().join("mado.toml"), config).into_diagnostic()?;
+
+    let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
+    let assert = cmd.current_dir(tmp_dir.path()).args(["rules"]).assert();
+    let output = assert.success().get_output().stdout.clone();
+    let stdout = String::from_utf8(output).into_diagnostic()?;
+    assert!(stdout.starts_with("MD001 (header-increment) [disabled]\n"));
+    assert!(stdout.contains(indoc! {"
+        MD013 (line-length)
+          Line length
+          Tags: line-length
+          Severity: error
+          Options: code-blocks = true, line-length = 100, tables = true
+    "}));
+    tmp_dir.close().into_diagnostic()
+}
+
+#[test]
+fn rules_json() -> Result<()> {
+    let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
+    let assert = cmd.args(["rules", "--output-format", "json"]).assert();
+    let output = assert.success().get_output().stdout.clone();
+    let rules: serde_json::Value = serde_json::from_slice(&output).into_diagnostic()?;
+    let names: Vec<_> = rules
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|rule| rule["name"].as_str())
+        .collect();
+    let mut sorted = names.clone();
+    sorted.sort_unstable();
+    assert_eq!(names, sorted);
+    for expected in ["MD001", "MD013", "MD026", "MD047", "MD051"] {
+        assert!(names.contains(&expected), "{expected} is not listed");
+    }
+    Ok(())
+}
+
+#[test]
+fn rule() -> Result<()> {
+    let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
+    let assert = cmd.args(["rule", "single-trailing-newline"]).assert();
+    let expected = indoc! {"
+        MD047 (single-trailing-newline): File should end with a single newline character
+
+        Files should end with a single newline character. Files without a trailing newline may not be processed correctly by some tools, and the last line shows up as changed in diffs when text is appended.
+
+        Tags: blank-lines
+        Severity: error
+
+        Bad:
+
+        ```markdown
+        This file ends without a newline
+        ```
+
+        Good:
+
+        ```markdown
+        This file ends with a newline
+        ```
+    "};
+    assert.success().stdout(expected);
+    Ok(())
+}
+
+#[test]
+fn rule_unknown() -> Result<()> {
+    let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
+    let assert = cmd.args(["rule", "MD999"]).assert();
+    assert.failure();
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use core::fmt::Write as _;
+
+use assert_cmd::Command;
+use miette::IntoDiagnostic as _;
+use miette::Result;
+use serde_json::json;
+use tempfile::tempdir;
+
+fn frame(messages: &[serde_json::Value]) -> String {
+    messages.iter().fold(String::new(), |mut acc, message| {
+        let body = message.to_string();
+        let _ = write!(acc, "Content-Length: {}\r\n\r\n{body}", body.len());
+        acc
+    })
+}
+
+#[test]
+fn server() -> Result<()> {
+    let tmp_dir = tempdir().into_diagnostic()?;
+    let uri = "file:///tmp/test.md";
+    let diagnostic = json!({
+        "range": {
+            "start": { "line": 0, "character": 0 },
+            "end": { "line": 0, "character": 8 }
+        },
+        "code": "MD026",
+        "source": "mado",
+        "message": "Trailing punctuation in header"
+    });
+    let input = frame(&[
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": { "capabilities": {} } }),
+        json!({ "jsonrpc": "2.0", "method": "initialized", "params": {} }),
+        json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": {
+                "textDocument": { "uri": uri, "languageId": "markdown", "version": 1, "text": "# Hello.\n" }
+            }
+        }),
+        json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "textDocument/codeAction",
+            "params": {
+                "textDocument": { "uri": uri },
+                "range": diagnostic["range"],
+                "context": { "diagnostics": [diagnostic] }
+            }
+        }),
+        json!({ "jsonrpc": "2.0", "id": 3, "method": "shutdown" }),
+        json!({ "jsonrpc": "2.0", "method": "exit" }),
+    ]);
+
+    let mut cmd = Command::cargo_bin("mado").into_diagnostic()?;
+    let output = cmd
+        .current_dir(tmp_dir.path())
+        .args(["server"])
+        .write_stdin(input)
+        .output()
+        .into_diagnostic()?;
+    let stdout = String::from_utf8(output.stdout).into_diagnostic()?;
+    assert!(output.status.success());
+    assert!(stdout.contains(r#""method":"textDocument/publishDiagnostics""#));
+    assert!(stdout.contains(r#""code":"MD026""#));
+    assert!(stdout.contains(r#""newText":"<!-- mado-disable-next-line MD026 -->\n""#));
+    tmp_dir.close().into_diagnostic()
+}